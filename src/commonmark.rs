@@ -0,0 +1,929 @@
+//! CommonMark (Markdown) 렌더러
+//!
+//! `DocumentNode`를 다시 Markdown 문자열로 변환합니다.
+//! 출력을 다시 `parse`하면 원문 위치를 제외하고 같은 트리가 나오므로, 노트를 한 가지 형식으로 정리하는
+//! 포매터로 쓸 수 있습니다.
+//!
+//! ## 출력 규칙
+//! - 블록 사이는 빈 줄 하나, 문서 끝은 줄바꿈 하나 (frontmatter는 `---` 구분선과 원문 그대로)
+//! - Heading은 ATX(`# `), 줄바꿈이 있는 1~2단계 Heading만 Setext(`===`, `---`)
+//! - Code Block은 항상 fenced이며, 펜스는 내용의 가장 긴 펜스 문자 연속보다 길게 (최소 3)
+//!   (info string에 백틱이 있으면 `~` 펜스)
+//! - 리스트는 `start`, 구분자(`.`, `)`), tight 여부를 유지하고 번호는 start부터 차례로 매김
+//!   (바로 이어지는 Bullet 리스트는 합쳐지지 않도록 다른 bullet 문자 사용)
+//! - List Item의 이어지는 줄은 마커 폭만큼, 각주 정의는 4칸 들여쓰고, Blockquote와 Callout은 줄마다 `> `
+//! - block id는 Paragraph와 List Item이면 첫 줄 끝의 ` ^id`, Blockquote, Callout, Table이면 빈 줄 뒤의 `^id`
+//! - 참조 링크는 인라인 링크로, 링크 목적지는 비어 있거나 공백이 있으면 `<…>`로 출력
+//! - 텍스트는 인라인 구문이나 블록 시작으로 읽힐 수 있는 문자만 백슬래시로 이스케이프하고,
+//!   줄 앞뒤 공백과 줄바꿈 문자는 문자 참조(`&#32;`, `&#9;`, `&#10;`)로 출력
+//! - 트랜스클루전으로 불러온 임베드 내용은 출력하지 않음 (`![[note]]`만 출력)
+
+use std::borrow::Cow;
+
+use crate::node::{
+    BlockNode, CalloutNode, CodeBlockNode, DocumentNode, HeadingNode, InlineNode, ListItemNode, ListNode, ListType,
+    TableAlignment, TableNode, TableRowNode,
+};
+
+/// 각주 정의의 이어지는 줄 들여쓰기
+const FOOTNOTE_INDENT: &str = "    ";
+
+/// Markdown 출력 형식
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FormatOptions {
+    /// Bullet 리스트 마커 (`-`, `+`, `*` 중 하나, 그 밖의 문자면 `-`)
+    pub bullet: char,
+    /// Code Block 펜스 문자 (`` ` `` 또는 `~`, 그 밖의 문자면 `` ` ``)
+    pub code_fence: char,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self { bullet: '-', code_fence: '`' }
+    }
+}
+
+impl FormatOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bullet 리스트 마커를 지정한 옵션 반환
+    pub fn with_bullet(self, bullet: char) -> Self {
+        Self { bullet, ..self }
+    }
+
+    /// Code Block 펜스 문자를 지정한 옵션 반환
+    pub fn with_code_fence(self, code_fence: char) -> Self {
+        Self { code_fence, ..self }
+    }
+
+    fn bullet_marker(&self) -> char {
+        match self.bullet {
+            '-' | '+' | '*' => self.bullet,
+            _ => '-',
+        }
+    }
+
+    /// 바로 앞 Bullet 리스트와 합쳐지지 않도록 번갈아 쓰는 마커
+    /// (`***`, `___` 수평선이 List Item의 첫 블록일 때 수평선 한 줄로 읽히지 않도록 `*`끼리는 피함)
+    fn other_bullet_marker(&self, bullet: char) -> char {
+        match (bullet == self.bullet_marker(), self.bullet_marker()) {
+            (false, primary) => primary,
+            (true, '+') => '-',
+            (true, '*') => '-',
+            (true, _) => '+',
+        }
+    }
+
+    /// 수평선 (bullet 마커와 다른 문자, `---`는 문서 첫 줄이면 frontmatter로 읽힐 수 있어 쓰지 않음)
+    fn thematic_break(&self) -> &'static str {
+        match self.bullet_marker() {
+            '*' => "___",
+            _ => "***",
+        }
+    }
+
+    fn fence_char(&self) -> char {
+        match self.code_fence {
+            '~' => '~',
+            _ => '`',
+        }
+    }
+}
+
+/// 문서 전체를 Markdown으로 렌더링
+pub fn render_commonmark(doc: &DocumentNode, options: &FormatOptions) -> String {
+    let mut parts = Vec::new();
+    if let Some(frontmatter) = &doc.frontmatter {
+        parts.push(match frontmatter.raw.is_empty() {
+            true => "---\n---".to_string(),
+            false => format!("---\n{}\n---", frontmatter.raw),
+        });
+    }
+    let body = render_blocks(&doc.children, "\n\n", false, options);
+    if !body.is_empty() {
+        parts.push(body);
+    }
+    match parts.is_empty() {
+        true => String::new(),
+        false => parts.join("\n\n") + "\n",
+    }
+}
+
+// =============================================================================
+// 블록
+// =============================================================================
+
+/// 블록 목록 렌더링 (separator: 블록 사이, tight 리스트 아이템 안이면 줄바꿈 하나)
+/// after_bullet: 기본 bullet 마커 바로 뒤에 오는 블록들이면 true
+/// (`- - -`가 수평선으로 읽히지 않도록 첫 Bullet 리스트는 다른 마커로)
+fn render_blocks(blocks: &[BlockNode], separator: &str, after_bullet: bool, options: &FormatOptions) -> String {
+    let mut parts = Vec::new();
+    let mut bullet = options.bullet_marker();
+    let mut after_bullet_list = after_bullet;
+    for (i, block) in blocks.iter().enumerate() {
+        let bullet_list = matches!(block, BlockNode::List(ListNode { list_type: ListType::Bullet, .. }));
+        if bullet_list {
+            bullet = match after_bullet_list {
+                true => options.other_bullet_marker(bullet),
+                false => options.bullet_marker(),
+            };
+        }
+        after_bullet_list = bullet_list;
+
+        let mut part = render_block(block, bullet, options);
+        // 빈 줄 없이 이어지는 Paragraph가 lazy continuation으로 붙지 않도록 빈 `>` 줄로 닫음
+        let lazy = matches!(
+            (block, blocks.get(i + 1)),
+            (BlockNode::Blockquote(_) | BlockNode::Callout(_), Some(BlockNode::Paragraph(_)))
+        );
+        if lazy && separator == "\n" {
+            part.push_str("\n>");
+        }
+        parts.push(part);
+    }
+    parts.join(separator)
+}
+
+/// 블록 하나 렌더링 (bullet: Bullet 리스트면 쓸 마커)
+fn render_block(block: &BlockNode, bullet: char, options: &FormatOptions) -> String {
+    match block {
+        BlockNode::ThematicBreak(_) => options.thematic_break().to_string(),
+        BlockNode::Heading(node) => render_heading(node),
+        BlockNode::CodeBlock(node) => render_code_block(node, options),
+        BlockNode::MathBlock(node) => render_math_block(&node.content),
+        BlockNode::HtmlBlock(node) => node.content.clone(),
+        BlockNode::Comment(node) => fenced_lines("%%", &node.content),
+        BlockNode::Paragraph(node) => render_paragraph(&node.children, node.block_id.as_deref()),
+        BlockNode::Blockquote(node) => {
+            let content = render_blocks(&node.children, "\n\n", false, options);
+            let quote = match content.is_empty() {
+                true => ">".to_string(),
+                false => prefix_lines(&content, "> ", "> "),
+            };
+            with_standalone_id(quote, node.block_id.as_deref())
+        }
+        BlockNode::Callout(node) => render_callout(node, options),
+        BlockNode::List(node) => render_list(node, bullet, options),
+        BlockNode::ListItem(node) => render_list_item(node, &bullet.to_string(), "\n\n", options),
+        BlockNode::LinkReferenceDefinition(node) => {
+            let mut out = format!("[{}]: ", node.label);
+            push_destination(&node.destination, false, &mut out);
+            if let Some(title) = &node.title {
+                push_title(title, false, &mut out);
+            }
+            out
+        }
+        BlockNode::Table(node) => with_standalone_id(render_table(node), node.block_id.as_deref()),
+        BlockNode::FootnoteDefinition(node) => {
+            let marker = format!("[^{}]:", node.label);
+            let content = render_blocks(&node.children, "\n\n", false, options);
+            match content.is_empty() {
+                true => marker,
+                false => prefix_lines(&content, &format!("{} ", marker), FOOTNOTE_INDENT),
+            }
+        }
+    }
+}
+
+/// Heading 렌더링 (줄바꿈이 있으면 Setext)
+fn render_heading(node: &HeadingNode) -> String {
+    let mut content = String::new();
+    render_inlines(&node.children, None, false, &mut content);
+    match (node.level, content.contains('\n'), content.is_empty()) {
+        (1, true, _) => format!("{}\n===", content),
+        (2, true, _) => format!("{}\n---", content),
+        (level, _, true) => "#".repeat(level as usize),
+        (level, _, false) => format!("{} {}", "#".repeat(level as usize), content),
+    }
+}
+
+/// Code Block 렌더링 (펜스는 내용 안의 같은 문자 연속보다 길게)
+fn render_code_block(node: &CodeBlockNode, options: &FormatOptions) -> String {
+    let info = node.info.as_deref().unwrap_or("");
+    // 백틱 펜스의 info string에는 백틱이 올 수 없음
+    let fence_char = match info.contains('`') {
+        true => '~',
+        false => options.fence_char(),
+    };
+    let fence = fence_char.to_string().repeat(longest_run(&node.content, fence_char).max(2) + 1);
+
+    let mut out = fence.clone();
+    push_escaped(info, |rest| matches!(rest, ['\\', ..]) || is_entity(rest), &mut out);
+    out.push('\n');
+    if !node.content.is_empty() {
+        out.push_str(&node.content);
+        out.push('\n');
+    }
+    out.push_str(&fence);
+    out
+}
+
+/// 수식 블록 렌더링
+/// 마지막 줄이 `$$`로 끝나면 그 줄에서 닫힌 것으로 읽히므로, 닫는 `$$`를 같은 줄에 붙임
+fn render_math_block(content: &str) -> String {
+    match content.lines().last().is_some_and(|line| line.trim_end().ends_with("$$")) {
+        true => format!("$$\n{}$$", content),
+        false => fenced_lines("$$", content),
+    }
+}
+
+/// 여는 줄과 닫는 줄 사이에 내용을 둔 블록 (수식 블록, 블록 주석)
+fn fenced_lines(fence: &str, content: &str) -> String {
+    match content.is_empty() {
+        true => format!("{}\n{}", fence, fence),
+        false => format!("{}\n{}\n{}", fence, content, fence),
+    }
+}
+
+/// Paragraph 렌더링 (block id는 끝에 ` ^id`)
+/// 내용이 공백으로 끝나면 ` ^id` 앞 공백과 함께 지워지므로 id를 다음 줄에 씀
+fn render_paragraph(children: &[InlineNode], block_id: Option<&str>) -> String {
+    let last_text = match children.last() {
+        Some(InlineNode::Text(node)) => node.as_str(),
+        _ => "",
+    };
+    let separator = match last_text.ends_with([' ', '\t']) {
+        true => '\n',
+        false => ' ',
+    };
+    let mut out = String::new();
+    render_inlines(children, block_id.map(|_| separator), false, &mut out);
+    if let Some(id) = block_id {
+        out.push_str(&format!("{}^{}", separator, id));
+    }
+    out
+}
+
+/// Callout 렌더링 (`> [!kind]±` 제목, 다음 줄부터 내용)
+fn render_callout(node: &CalloutNode, options: &FormatOptions) -> String {
+    let mut content = format!("[!{}]", node.kind);
+    match node.folded {
+        Some(true) => content.push('-'),
+        Some(false) => content.push('+'),
+        None => {}
+    }
+    if !node.title.is_empty() {
+        content.push(' ');
+        let mut title = String::new();
+        render_inlines(&node.title, None, false, &mut title);
+        content.push_str(&title);
+    }
+    let body = render_blocks(&node.children, "\n\n", false, options);
+    if !body.is_empty() {
+        content.push('\n');
+        content.push_str(&body);
+    }
+    with_standalone_id(prefix_lines(&content, "> ", "> "), node.block_id.as_deref())
+}
+
+/// List 렌더링 (tight면 아이템과 아이템 안의 블록 사이에 빈 줄 없음)
+fn render_list(node: &ListNode, bullet: char, options: &FormatOptions) -> String {
+    let separator = match node.tight {
+        true => "\n",
+        false => "\n\n",
+    };
+    node.children
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = match node.list_type {
+                ListType::Bullet => bullet.to_string(),
+                ListType::Ordered { delimiter } => format!("{}{}", node.start + i, delimiter),
+            };
+            render_list_item(item, &marker, separator, options)
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// List Item 렌더링 (이어지는 줄은 마커 폭 + 1칸 들여쓰기)
+fn render_list_item(node: &ListItemNode, marker: &str, separator: &str, options: &FormatOptions) -> String {
+    // 아이템의 block id는 첫 Paragraph 끝에 씀
+    let children = match (&node.block_id, node.children.split_first()) {
+        (Some(id), Some((BlockNode::Paragraph(first), rest))) => {
+            let first = BlockNode::Paragraph(first.clone().with_block_id(id));
+            Cow::Owned(std::iter::once(first).chain(rest.iter().cloned()).collect())
+        }
+        _ => Cow::Borrowed(node.children.as_slice()),
+    };
+
+    let mut content = match node.checked {
+        Some(true) => "[x] ".to_string(),
+        Some(false) => "[ ] ".to_string(),
+        None => String::new(),
+    };
+    let after_bullet = content.is_empty() && marker.starts_with(options.bullet_marker());
+    content.push_str(&render_blocks(&children, separator, after_bullet, options));
+
+    let indent = " ".repeat(marker.len() + 1);
+    match content.starts_with([' ', '\t']) {
+        _ if content.is_empty() => marker.to_string(),
+        // 들여쓴 내용(HTML 블록 등)은 마커 뒤 공백과 구분되도록 다음 줄부터
+        true => format!("{}\n{}", marker, prefix_lines(&content, &indent, &indent)),
+        false => prefix_lines(&content, &format!("{} ", marker), &indent),
+    }
+}
+
+fn render_table(node: &TableNode) -> String {
+    let delimiter = node
+        .alignments
+        .iter()
+        .map(|alignment| match alignment {
+            TableAlignment::None => "---",
+            TableAlignment::Left => ":--",
+            TableAlignment::Center => ":-:",
+            TableAlignment::Right => "--:",
+        })
+        .collect::<Vec<_>>()
+        .join(" | ");
+    std::iter::once(render_table_row(&node.header))
+        .chain(std::iter::once(format!("| {} |", delimiter)))
+        .chain(node.rows.iter().map(render_table_row))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 표 행 렌더링 (셀 안의 `|`는 모두 `\|`)
+fn render_table_row(row: &TableRowNode) -> String {
+    let cells = row
+        .cells
+        .iter()
+        .map(|cell| {
+            let mut out = String::new();
+            render_inlines(&cell.children, None, true, &mut out);
+            out
+        })
+        .collect::<Vec<_>>();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// 다음 블록으로 따로 쓰는 block id (`^id`)
+fn with_standalone_id(block: String, block_id: Option<&str>) -> String {
+    match block_id {
+        Some(id) => format!("{}\n\n^{}", block, id),
+        None => block,
+    }
+}
+
+/// 각 줄 앞에 접두어 추가 (첫 줄은 first, 나머지는 rest, 빈 줄에는 접두어의 뒤 공백 제외)
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    text.split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 { first } else { rest };
+            match line.is_empty() {
+                true => prefix.trim_end().to_string(),
+                false => format!("{}{}", prefix, line),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 가장 긴 c 연속의 길이
+fn longest_run(s: &str, c: char) -> usize {
+    s.split(|other| other != c).map(|run| run.len() / c.len_utf8()).max().unwrap_or(0)
+}
+
+// =============================================================================
+// 인라인
+// =============================================================================
+
+/// 인라인 목록 렌더링
+/// next: 목록 바로 뒤에 올 문자 (닫는 구분자 등, 줄 끝이면 None)
+/// table: 표 셀 안이면 true (코드, 수식 등 원문의 `|`도 `\|`로 출력)
+fn render_inlines(inlines: &[InlineNode], next: Option<char>, table: bool, out: &mut String) {
+    for (i, inline) in inlines.iter().enumerate() {
+        let next = inlines.get(i + 1).and_then(leading_char).or(next);
+        let after_url = i > 0 && matches!(&inlines[i - 1], InlineNode::Autolink(node) if node.extended);
+        match inline {
+            // extended autolink 끝에서 제외되는 구두점과 `)`는 그대로 (백슬래시를 붙이면 링크에 포함됨)
+            InlineNode::Text(node) if after_url => {
+                let content = node.as_str();
+                let rest = content.trim_start_matches(['?', '!', '.', ',', ':', '*', '_', '~', ')']);
+                out.push_str(&content[..content.len() - rest.len()]);
+                // `<`는 링크를 끝내므로 그대로 두고, HTML 태그로 읽히지 않게 뒤 문자를 이스케이프
+                let rest = match rest.strip_prefix('<') {
+                    Some(after) => {
+                        out.push('<');
+                        let mut chars = after.chars();
+                        match chars.next() {
+                            Some(c @ ('/' | '!' | '?')) => {
+                                out.push('\\');
+                                out.push(c);
+                            }
+                            Some(c) if c.is_ascii_alphabetic() => out.push_str(&format!("&#{};", c as u32)),
+                            _ => chars = after.chars(),
+                        }
+                        chars.as_str()
+                    }
+                    None => rest,
+                };
+                push_text(rest, next, out);
+            }
+            _ => render_inline(inline, next, table, out),
+        }
+    }
+}
+
+fn render_inline(inline: &InlineNode, next: Option<char>, table: bool, out: &mut String) {
+    match inline {
+        InlineNode::Text(node) => push_text(node.as_str(), next, out),
+        InlineNode::Emphasis(node) => {
+            let delimiter = emphasis_delimiter(out, next).to_string();
+            push_delimited(&node.children, &delimiter, table, out);
+        }
+        InlineNode::Strong(node) => {
+            let delimiter = emphasis_delimiter(out, next).to_string().repeat(2);
+            push_delimited(&node.children, &delimiter, table, out);
+        }
+        InlineNode::Strikethrough(node) => push_delimited(&node.children, "~~", table, out),
+        InlineNode::Highlight(node) => push_delimited(&node.children, "==", table, out),
+        InlineNode::CodeSpan(node) => push_code_span(&node.content, table, out),
+        InlineNode::InlineMath(node) => {
+            out.push('$');
+            push_raw(&node.content, table, out);
+            out.push('$');
+        }
+        InlineNode::Link(node) => {
+            out.push('[');
+            render_inlines(&node.children, Some(']'), table, out);
+            out.push_str("](");
+            push_destination(&node.destination, table, out);
+            if let Some(title) = &node.title {
+                push_title(title, table, out);
+            }
+            out.push(')');
+        }
+        InlineNode::Image(node) => {
+            out.push_str("![");
+            render_inlines(&node.children, Some(']'), table, out);
+            out.push_str("](");
+            push_destination(&node.destination, table, out);
+            if let Some(title) = &node.title {
+                push_title(title, table, out);
+            }
+            out.push(')');
+        }
+        InlineNode::WikiLink(node) => {
+            let fragment = fragment(node.heading.as_deref(), node.block_ref.as_deref());
+            push_wikilink(&node.target, &fragment, node.alias.as_deref(), table, out);
+        }
+        InlineNode::Embed(node) => {
+            let fragment = fragment(node.heading.as_deref(), node.block_ref.as_deref());
+            let size = node.width.map(|width| match node.height {
+                Some(height) => format!("{}x{}", width, height),
+                None => width.to_string(),
+            });
+            out.push('!');
+            push_wikilink(&node.target, &fragment, node.alias.as_deref().or(size.as_deref()), table, out);
+        }
+        InlineNode::Tag(node) => {
+            out.push('#');
+            out.push_str(&node.name);
+        }
+        InlineNode::Comment(node) => {
+            out.push_str("%%");
+            push_raw(&node.content, table, out);
+            out.push_str("%%");
+        }
+        InlineNode::Autolink(node) => match node.extended {
+            true => push_url(&node.url, node.email, table, out),
+            false => {
+                out.push('<');
+                push_raw(&node.url, table, out);
+                out.push('>');
+            }
+        },
+        InlineNode::RawHtml(node) => push_raw(&node.content, table, out),
+        InlineNode::FootnoteReference(node) => match &node.inline {
+            Some(children) => {
+                out.push_str("^[");
+                render_inlines(children, Some(']'), table, out);
+                out.push(']');
+            }
+            None => out.push_str(&format!("[^{}]", node.label)),
+        },
+        InlineNode::SoftBreak(_) => out.push('\n'),
+        // extended autolink 바로 뒤의 백슬래시는 URL에 포함되므로 공백 두 칸
+        InlineNode::HardBreak(_) if ends_with_url(out) => out.push_str("  \n"),
+        InlineNode::HardBreak(_) => out.push_str("\\\n"),
+    }
+}
+
+/// 인라인 노드 출력의 첫 문자 (텍스트는 이스케이프 전 문자)
+fn leading_char(inline: &InlineNode) -> Option<char> {
+    match inline {
+        InlineNode::Text(node) => node.content.chars().next(),
+        InlineNode::Emphasis(_) | InlineNode::Strong(_) => Some('*'),
+        InlineNode::Strikethrough(_) => Some('~'),
+        InlineNode::Highlight(_) => Some('='),
+        InlineNode::CodeSpan(_) => Some('`'),
+        InlineNode::InlineMath(_) => Some('$'),
+        InlineNode::Link(_) | InlineNode::WikiLink(_) => Some('['),
+        InlineNode::Image(_) | InlineNode::Embed(_) => Some('!'),
+        InlineNode::Tag(_) => Some('#'),
+        InlineNode::Comment(_) => Some('%'),
+        InlineNode::Autolink(node) if node.extended => node.url.chars().next(),
+        InlineNode::Autolink(_) | InlineNode::RawHtml(_) => Some('<'),
+        InlineNode::FootnoteReference(node) if node.inline.is_some() => Some('^'),
+        InlineNode::FootnoteReference(_) => Some('['),
+        InlineNode::SoftBreak(_) => Some('\n'),
+        InlineNode::HardBreak(_) => Some('\\'),
+    }
+}
+
+/// 구분자로 감싼 인라인 (강조, 취소선, 하이라이트)
+fn push_delimited(children: &[InlineNode], delimiter: &str, table: bool, out: &mut String) {
+    out.push_str(delimiter);
+    render_inlines(children, delimiter.chars().next(), table, out);
+    out.push_str(delimiter);
+}
+
+/// 강조 구분자: 앞이나 뒤에 `*`가 붙으면 구분자 연속이 합쳐지지 않도록 `_`
+/// (`_`는 단어 안에서 강조를 열고 닫을 수 없으므로 앞의 `*` 연속 앞이나 뒤가 문자나 숫자면 `*`)
+fn emphasis_delimiter(out: &str, next: Option<char>) -> char {
+    let adjacent = out.ends_with('*') || next == Some('*');
+    let prev = out.trim_end_matches('*').chars().next_back();
+    let intraword = prev.is_some_and(char::is_alphanumeric) || next.is_some_and(char::is_alphanumeric);
+    match adjacent && !intraword {
+        true => '_',
+        false => '*',
+    }
+}
+
+/// 코드 스팬 (내용에 없는 길이의 백틱 연속으로 감싸고, 앞뒤 백틱이나 공백이 지워지지 않도록 한 칸씩 띄움)
+fn push_code_span(content: &str, table: bool, out: &mut String) {
+    let mut len = 1;
+    while content.split(|c| c != '`').any(|run| run.len() == len) {
+        len += 1;
+    }
+    let fence = "`".repeat(len);
+    let padded = content.starts_with('`')
+        || content.ends_with('`')
+        || (content.starts_with(' ') && content.ends_with(' ') && !content.trim().is_empty());
+    let space = if padded { " " } else { "" };
+
+    out.push_str(&fence);
+    out.push_str(space);
+    push_raw(content, table, out);
+    out.push_str(space);
+    out.push_str(&fence);
+}
+
+/// 위키링크/임베드 (`[[target#fragment|alias]]`, 표 안의 alias 구분자는 `\|`)
+fn push_wikilink(target: &str, fragment: &str, alias: Option<&str>, table: bool, out: &mut String) {
+    out.push_str("[[");
+    out.push_str(target);
+    out.push_str(fragment);
+    if let Some(alias) = alias {
+        out.push_str(if table { "\\|" } else { "|" });
+        out.push_str(alias);
+    }
+    out.push_str("]]");
+}
+
+/// 위키링크 fragment (`#Heading` 또는 `#^id`)
+fn fragment(heading: Option<&str>, block_ref: Option<&str>) -> String {
+    match (heading, block_ref) {
+        (_, Some(block_ref)) => format!("#^{}", block_ref),
+        (Some(heading), None) => format!("#{}", heading),
+        (None, None) => String::new(),
+    }
+}
+
+/// 링크 목적지 (비어 있거나 공백, 제어 문자, 짝이 맞지 않는 괄호가 있으면 `<…>`)
+fn push_destination(destination: &str, table: bool, out: &mut String) {
+    let mut depth: usize = 0;
+    let balanced = destination.chars().all(|c| match c {
+        '(' => {
+            depth += 1;
+            true
+        }
+        ')' => depth.checked_sub(1).map(|d| depth = d).is_some(),
+        _ => true,
+    }) && depth == 0;
+    let pointy = destination.is_empty() || !balanced || destination.contains(|c: char| c == ' ' || c.is_control());
+
+    let mut escaped = String::new();
+    match pointy {
+        true => {
+            escaped.push('<');
+            push_escaped(destination, |rest| matches!(rest, ['<' | '>' | '\\', ..]) || is_entity(rest), &mut escaped);
+            escaped.push('>');
+        }
+        false => push_escaped(destination, |rest| matches!(rest, ['<' | '\\', ..]) || is_entity(rest), &mut escaped),
+    }
+    push_raw(&escaped, table, out);
+}
+
+/// 링크 제목 (`"…"`)
+fn push_title(title: &str, table: bool, out: &mut String) {
+    let mut escaped = String::from(" \"");
+    push_escaped(title, |rest| matches!(rest, ['"' | '\\', ..]) || is_entity(rest), &mut escaped);
+    escaped.push('"');
+    push_raw(&escaped, table, out);
+}
+
+/// extended autolink의 URL
+/// 백슬래시와 문자 참조로 읽히는 `&`는 이스케이프, 공백과 `<`, 링크 끝에서 제외될 마지막 문자는 문자 참조로
+/// 원문에서 이스케이프해 도메인에서 떼어 냈던 문자도 다시 이스케이프 (`www.\_-`)
+fn push_url(url: &str, email: bool, table: bool, out: &mut String) {
+    let chars: Vec<char> = url.chars().collect();
+    let domain_end = (!email).then(|| domain_break(&chars)).flatten();
+    let unbalanced = chars.iter().filter(|&&c| c == ')').count() > chars.iter().filter(|&&c| c == '(').count();
+    let mut escaped = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let last = i + 1 == chars.len();
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '&' if is_entity(&chars[i..]) => escaped.push_str("&amp;"),
+            '?' | '!' | '.' | ',' | ':' | '*' | '_' | '~' if last => escaped.push_str(&format!("&#{};", c as u32)),
+            ')' if last && unbalanced => escaped.push_str("&#41;"),
+            '<' => escaped.push_str("&#60;"),
+            c if c.is_whitespace() => escaped.push_str(&format!("&#{};", c as u32)),
+            c if domain_end == Some(i) && c.is_ascii_punctuation() => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if domain_end == Some(i) => escaped.push_str(&format!("&#{};", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    push_raw(&escaped, table, out);
+}
+
+/// URL의 도메인 문자가 이어진 전체가 유효한 도메인이 아니면, 유효한 가장 긴 도메인 바로 뒤 문자의 위치
+/// (그 문자를 이스케이프해야 도메인이 거기서 끝남)
+fn domain_break(chars: &[char]) -> Option<usize> {
+    let (start, allow_short) = match chars {
+        ['h', 't', 't', 'p', 's', ':', '/', '/', ..] => (8, true),
+        ['h', 't', 't', 'p', ':', '/', '/', ..] => (7, true),
+        _ => (0, false),
+    };
+    let domain = &chars[start..];
+    let len = domain.iter().take_while(|&&c| c.is_alphanumeric() || matches!(c, '.' | '_' | '-')).count();
+    let valid = |len: usize| {
+        let domain: String = domain[..len].iter().collect();
+        let underscore_in_last_two = domain.trim_end_matches('.').rsplit('.').take(2).any(|s| s.contains('_'));
+        len > 0 && !underscore_in_last_two && (allow_short || domain.contains('.'))
+    };
+    match valid(len) {
+        true => None,
+        false => (1..len).rev().find(|&len| valid(len)).map(|len| start + len),
+    }
+}
+
+/// 줄 끝이 extended autolink로 읽힐 수 있는 URL인지 (마지막 단어가 `www.`, `http://`, `https://`로 시작)
+fn ends_with_url(out: &str) -> bool {
+    let word = out.rsplit(char::is_whitespace).next().unwrap_or(out).trim_start_matches(['*', '_', '~', '(']);
+    ["www.", "http://", "https://"].iter().any(|prefix| word.starts_with(prefix)) && !word.contains('<')
+}
+
+/// 원문 그대로 출력 (표 셀 안이면 `|`를 `\|`로)
+fn push_raw(s: &str, table: bool, out: &mut String) {
+    match table {
+        true => out.push_str(&s.replace('|', "\\|")),
+        false => out.push_str(s),
+    }
+}
+
+/// escape(남은 문자들)가 true인 위치의 문자 앞에 백슬래시를 붙여 출력
+fn push_escaped(s: &str, escape: impl Fn(&[char]) -> bool, out: &mut String) {
+    let chars: Vec<char> = s.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        if escape(&chars[i..]) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
+
+/// 텍스트 출력
+/// 문맥상 구문이 될 수 있는 문자만 이스케이프 (next: 텍스트 바로 뒤에 올 문자, 줄 끝이면 None 또는 `\n`)
+fn push_text(content: &str, next: Option<char>, out: &mut String) {
+    let chars: Vec<char> = content.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = out.chars().next_back();
+        let following = chars.get(i + 1).copied().or(next);
+        let line_start = prev.is_none_or(|prev| prev == '\n');
+        let line_end = following.is_none_or(|following| following == '\n');
+        match c {
+            '\n' => out.push_str("&#10;"),
+            // 줄 앞뒤 공백은 파싱할 때 지워지므로 문자 참조로
+            ' ' if line_start || line_end => out.push_str("&#32;"),
+            '\t' if line_start || line_end => out.push_str("&#9;"),
+            _ => {
+                // 줄 끝까지의 나머지 (텍스트 뒤에 같은 줄의 다른 인라인이 오면 None)
+                let line_rest = line_end_is_known(next).then_some(&chars[i..]);
+                if needs_escape(&chars[i..], prev, following, line_start, line_rest, out) {
+                    out.push('\\');
+                }
+                out.push(c);
+            }
+        }
+    }
+}
+
+fn line_end_is_known(next: Option<char>) -> bool {
+    next.is_none_or(|next| next == '\n')
+}
+
+/// rest[0] 문자를 이스케이프해야 하는지
+/// prev: 바로 앞에 출력된 문자, following: 바로 뒤 문자, line_rest: 줄 끝까지의 문자들 (알 수 없으면 None)
+fn needs_escape(
+    rest: &[char],
+    prev: Option<char>,
+    following: Option<char>,
+    line_start: bool,
+    line_rest: Option<&[char]>,
+    out: &str,
+) -> bool {
+    let followed_by_space = following.is_none_or(char::is_whitespace);
+    match rest[0] {
+        // 어디서나 인라인 구문을 시작하거나 끝낼 수 있는 문자
+        '\\' | '`' | '*' | '~' | '[' | ']' | '$' | '|' => true,
+        '_' => !(prev.is_some_and(char::is_alphanumeric) && following.is_some_and(char::is_alphanumeric)),
+        // 하이라이트 `==`, 주석 `%%`, Setext 밑줄 `===`
+        '=' => {
+            prev == Some('=')
+                || following == Some('=')
+                || (line_start && line_rest.is_some_and(|line| line.iter().all(|&c| matches!(c, '=' | ' ' | '\t'))))
+        }
+        '%' => prev == Some('%') || following == Some('%'),
+        // 이미지 `![`, 인라인 각주 `^[`
+        '!' | '^' => following == Some('['),
+        // HTML 태그, autolink
+        '<' => following.is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?')),
+        '#' => is_tag_boundary(out),
+        '&' => is_entity(rest),
+        // 블록 시작: Blockquote, List Item, Thematic Break, Setext 밑줄, 표 구분 행
+        '>' => line_start,
+        '+' => line_start && followed_by_space,
+        '-' => line_start && (followed_by_space || line_rest.is_some_and(is_delimiter_row)),
+        ':' => {
+            (line_start && line_rest.is_some_and(is_delimiter_row)) || is_url_prefix(out, rest) || is_footnote_line(out)
+        }
+        // Ordered List Item (`1. `, `1) `)
+        '.' => (is_ordered_number(out) && followed_by_space) || is_url_prefix(out, rest),
+        ')' => is_ordered_number(out) && followed_by_space,
+        // extended autolink email
+        '@' => prev.is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-' | '_')),
+        _ => false,
+    }
+}
+
+/// 태그나 ATX Heading이 시작될 수 있는 `#` 위치
+/// 줄 시작이나 공백 뒤, 강조 구분자와 `(`는 이스케이프되었어도 건너뜀
+fn is_tag_boundary(out: &str) -> bool {
+    let mut before = out;
+    while let Some(rest) = before.strip_suffix(['*', '_', '~', '=', '(']) {
+        let backslashes = rest.len() - rest.trim_end_matches('\\').len();
+        before = &rest[..rest.len() - backslashes % 2];
+    }
+    before.chars().next_back().is_none_or(char::is_whitespace)
+}
+
+/// extended autolink로 읽힐 수 있는 `www.`의 `.`이나 `http://`, `https://`의 `:` 위치
+fn is_url_prefix(out: &str, rest: &[char]) -> bool {
+    let prefix = match rest {
+        ['.', ..] => "www",
+        [':', '/', '/', ..] if out.ends_with("https") => "https",
+        [':', '/', '/', ..] => "http",
+        _ => return false,
+    };
+    out.strip_suffix(prefix)
+        .is_some_and(|before| before.chars().next_back().is_none_or(|c| c.is_whitespace() || "*_~(".contains(c)))
+}
+
+/// 현재 줄이 각주 참조 `[^label]`뿐인지 (뒤에 `:`가 오면 각주 정의로 읽힘)
+fn is_footnote_line(out: &str) -> bool {
+    let line = out.rsplit('\n').next().unwrap_or(out);
+    line.strip_prefix("[^")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .is_some_and(|label| !label.is_empty() && !label.contains(['[', ']']))
+}
+
+/// 현재 줄이 Ordered List Item 번호(숫자 1~9자)로만 이루어졌는지
+fn is_ordered_number(out: &str) -> bool {
+    let line = out.rsplit('\n').next().unwrap_or(out);
+    (1..=9).contains(&line.len()) && line.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Setext 밑줄, Thematic Break, 표 구분 행으로 읽힐 수 있는 줄
+fn is_delimiter_row(line: &[char]) -> bool {
+    line.contains(&'-') && line.iter().all(|&c| matches!(c, '-' | ':' | '|' | ' ' | '\t'))
+}
+
+/// 문자 참조로 읽히는 `&…;`로 시작하는지
+fn is_entity(rest: &[char]) -> bool {
+    let body = match rest {
+        ['&', '#', 'x' | 'X', body @ ..] => body,
+        ['&', '#', body @ ..] => body,
+        ['&', body @ ..] => body,
+        _ => return false,
+    };
+    let len = body.iter().take_while(|c| c.is_ascii_alphanumeric()).count();
+    len > 0 && body.get(len) == Some(&';')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{ListItemNode, ParagraphNode};
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    fn render(input: &str) -> String {
+        render_commonmark(&parse(input), &FormatOptions::default())
+    }
+
+    #[rstest]
+    // 블록
+    #[case("# Title\n\nSetext\n---\n\n***\n\n    indented\n\n~~~ rust\nfn main() {}\n~~~")]
+    #[case("> quote\n> > nested\n\n> [!warning]- Title *em*\n> body\n\n> [!note]")]
+    #[case("| a | b \\| c |\n| :-- | --: |\n| `x|y` | [[P\\|alias]] |\n| | $a|b$ |")]
+    #[case("<div>\n  html\n</div>\n\n%%\nhidden\n\n%%\n\n$$\n\\frac{a}{b}\n$$")]
+    #[case("---\ntitle: note\ntags: [a]\n---\n# Body")]
+    #[case("[foo]: </my url> \"ti\\\"tle\"\n\n[foo]\n\n[^1]: note\n\n    more\n\ntext[^1]")]
+    // 리스트
+    #[case("- a\n- b\n  - c\n\n+ d\n\n* e")]
+    #[case("3) a\n4) b\n\n   c\n\n1. x\n1. y")]
+    #[case("- [ ] todo\n- [x] done ^id\n-\n- ```\n  code\n  ```\n- > quote\n  >\n  after")]
+    #[case("10. ten\n    more\n\n    para\n11. eleven")]
+    // 인라인
+    #[case("*a* **b** ***c*** _d_ __e__ *a **b***, **a *b***, ~~s~~ ==h== `c` ``a`b`` $x^2$")]
+    #[case("[link](/url \"title\") [e](<>) ![img](/a%20b) <https://x.com> www.x.com [[Page#Head|alias]]")]
+    #[case("![[image.png|300x200]] ![[note#^block]] #tag/nested %%c%% <span>h</span> ^[inline *fn*]")]
+    #[case("line  \nbreak\\\nsoft\nline")]
+    // 이스케이프
+    #[case("\\# not heading\n\\- not list\n1\\. not ordered\n\\> not quote\n\\=\\=\\=\n\\---")]
+    #[case("\\*a\\* \\_b\\_ snake_case \\`c\\` \\[d\\](e) \\<span\\> &amp;amp; \\$5 a\\|b \\~s\\~ \\==h\\==")]
+    #[case("&#32; leading and trailing &#32;\n&#9;tab &#10; newline \\%%c\\%% \\![x] \\^[y] C# \\#tag")]
+    #[case("www\\.x.com http\\://x.com foo\\@bar.com www.x.com* (https://x.com)_")]
+    #[case("a ^id\n\n> quote\n\n^quote\n\n| t |\n| - |\n\n^table")]
+    #[case("x _#tag todo: ~#later (*#a) \\\\_#b")]
+    #[case("http://x.y#<!-- www.x.y<b https://a.b/?x=1&amp;y=2 https://a.b/x\\\\_y&#46; http://a.b/x\\)")]
+    #[case("See https://example.com/a  \nnext")]
+    #[case("[^1]\\: text\n\n[^1]: note")]
+    #[case("www.\\_- www.a\\.b_c http://a.b\\.c_d")]
+    #[case("%\t\n^1")]
+    #[case("-\t- *\n\n1. - - a")]
+    #[case("$$a$$$$\n\n$$\nx\na$$$$")]
+    fn test_round_trip(#[case] input: &str) {
+        let doc = parse(input).without_spans();
+        let output = render_commonmark(&doc, &FormatOptions::default());
+        assert_eq!(parse(&output).without_spans(), doc, "output: {:?}", output);
+    }
+
+    #[rstest]
+    #[case("Title\n=====\n\ntext  \n", "# Title\n\ntext\n")]
+    #[case("~~~\n```\n`````\n~~~", "``````\n```\n`````\n``````\n")]
+    #[case("~~~ a`b\nx\n~~~", "~~~a`b\nx\n~~~\n")]
+    #[case("7) a\n8) b", "7) a\n8) b\n")]
+    #[case("* a\n\n* b\n\n  c", "- a\n\n- b\n\n  c\n")]
+    #[case("- a\n\n+ b", "- a\n\n+ b\n")]
+    #[case("[a][ref]\n\n[ref]: /url", "[a](/url)\n\n[ref]: /url\n")]
+    #[case("`` ` ``", "`` ` ``\n")]
+    #[case("x _#tag", "x \\_#tag\n")]
+    #[case("http://x.y#<!--", "http://x.y#<\\!--\n")]
+    #[case("", "")]
+    fn test_render_commonmark(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(render(input), expected);
+    }
+
+    #[rstest]
+    #[case(BlockNode::paragraph(vec![InlineNode::text("# a")]), "\\# a\n")]
+    #[case(BlockNode::paragraph(vec![InlineNode::text("- a")]), "\\- a\n")]
+    #[case(BlockNode::paragraph(vec![InlineNode::text("+ a")]), "\\+ a\n")]
+    #[case(BlockNode::paragraph(vec![InlineNode::text("1. a")]), "1\\. a\n")]
+    #[case(BlockNode::paragraph(vec![InlineNode::text("> a")]), "\\> a\n")]
+    #[case(BlockNode::paragraph(vec![InlineNode::text("===")]), "\\=\\=\\=\n")]
+    #[case(BlockNode::paragraph(vec![InlineNode::text("a *b* [c]")]), "a \\*b\\* \\[c\\]\n")]
+    #[case(BlockNode::paragraph(vec![InlineNode::text("    code")]), "&#32;   code\n")]
+    #[case(BlockNode::code_block(Some("rust"), "a\n\n```b"), "````rust\na\n\n```b\n````\n")]
+    fn test_escape_block_start(#[case] block: BlockNode, #[case] expected: &str) {
+        let output = render_commonmark(&DocumentNode::new(vec![block.clone()]), &FormatOptions::default());
+        assert_eq!(output, expected);
+        assert_eq!(parse(&output).without_spans().children, vec![block]);
+    }
+
+    #[test]
+    fn test_list_item_block_id() {
+        let item = ListItemNode::new(vec![BlockNode::Paragraph(ParagraphNode::new(vec![InlineNode::text("a")]))]);
+        let doc = DocumentNode::new(vec![BlockNode::ordered_list('.', 5, true, vec![item.with_block_id("x")])]);
+        let output = render_commonmark(&doc, &FormatOptions::default());
+        assert_eq!(output, "5. a ^x\n");
+        assert_eq!(parse(&output).without_spans(), doc);
+    }
+
+    #[test]
+    fn test_format_options() {
+        let doc = parse("- a\n\n+ b\n\n---\n\n```\ncode\n```");
+        let options = FormatOptions::new().with_bullet('*').with_code_fence('~');
+        assert_eq!(render_commonmark(&doc, &options), "* a\n\n- b\n\n___\n\n~~~\ncode\n~~~\n");
+    }
+}
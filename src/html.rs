@@ -0,0 +1,868 @@
+//! HTML 렌더러
+//!
+//! `DocumentNode`를 CommonMark 명세 예제와 같은 형식의 HTML 문자열로 변환합니다.
+//!
+//! ## 출력 규칙
+//! - 블록 요소는 앞뒤로 줄바꿈 (이미 줄바꿈 뒤라면 생략)
+//! - tight 리스트 아이템의 Paragraph는 `<p>` 태그 없이 내용만 출력
+//! - ordered 리스트의 시작 번호가 1이 아니면 `start` 속성 추가
+//! - Code Block의 info string 첫 단어를 `class="language-…"`로 출력
+//! - Task list 아이템은 첫 Paragraph 내용 앞에 비활성 체크박스 출력
+//! - Table 셀은 열 정렬이 있으면 `align` 속성 추가, 본문 행이 없으면 `<tbody>` 생략
+//! - 각주는 GitHub처럼 참조 자리에 번호 링크를, 문서 끝의 `<section class="footnotes">`에 번호 순으로
+//!   내용과 되돌아가기 링크(backref)를 출력 (정의되지 않은 참조는 원문 그대로, 참조되지 않은 정의는 생략)
+//! - Callout은 `<div class="callout" data-callout="종류">`, 접을 수 있으면 `<details>`/`<summary>`로 출력
+//!   (제목이 없으면 종류 이름을 첫 글자만 대문자로 바꿔 제목으로 사용)
+//! - 위키링크는 `LinkResolver`가 돌려준 URL로 `<a class="wikilink">`를 출력 (기본: 대상 그대로 + `#fragment`)
+//! - 임베드는 이미지면 크기 힌트를 붙인 `<img>`, 노트면 `<a class="embed">`를 출력하고, 트랜스클루전으로 불러온
+//!   내용이 있는 임베드가 Paragraph에 혼자 있으면 그 블록들을 `<div class="embed">`로 감싸 출력
+//! - 하이라이트는 `<mark>`, Obsidian 주석(`%%…%%`)은 인라인과 블록 모두 출력하지 않음
+//!   (주석만 있는 Paragraph도 생략)
+//! - 수식은 TeX 원문을 이스케이프해 인라인은 `<span class="math">`, 블록은 `<div class="math">`로 출력
+//! - block id(`^id`)가 있는 Paragraph, List Item, Blockquote, Callout, Table은 `id="^id"` 속성 추가
+//! - 태그는 `LinkResolver`가 돌려준 URL로 `<a class="tag">#이름</a>`을 출력 (기본: `#이름`)
+//! - 텍스트와 속성 값의 `&`, `<`, `>`, `"`는 이스케이프, URL은 퍼센트 인코딩
+
+use crate::node::{
+    BlockNode, CalloutNode, CodeBlockNode, DocumentNode, EmbedNode, FootnoteReferenceNode, InlineNode, ListItemNode, ListNode, ListType,
+    TableAlignment, TableNode, TableRowNode, TagNode, WikiLinkNode,
+};
+
+/// 위키링크/임베드/태그 대상을 URL로 바꾸는 방법
+/// 페이지 이름을 실제 경로로 연결하려면 직접 구현해 `render_html_with`에 넘김
+pub trait LinkResolver {
+    /// 위키링크의 href (퍼센트 인코딩과 이스케이프는 렌더러가 처리)
+    fn resolve_wikilink(&self, link: &WikiLinkNode) -> String;
+
+    /// 임베드의 src (이미지) 또는 href (노트), 기본은 대상 이름 그대로 + `#fragment`
+    fn resolve_embed(&self, embed: &EmbedNode) -> String {
+        with_fragment(&embed.target, embed.heading.as_deref(), embed.block_ref.as_deref())
+    }
+
+    /// 태그의 href (태그 페이지 등), 기본은 `#이름`
+    fn resolve_tag(&self, tag: &TagNode) -> String {
+        format!("#{}", tag.name)
+    }
+}
+
+/// 기본 resolver: 대상 이름 그대로, heading은 `#Heading`, 블록 id는 `#^id`를 붙임
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultLinkResolver;
+
+impl LinkResolver for DefaultLinkResolver {
+    fn resolve_wikilink(&self, link: &WikiLinkNode) -> String {
+        with_fragment(&link.target, link.heading.as_deref(), link.block_ref.as_deref())
+    }
+}
+
+/// 대상 뒤에 `#Heading` 또는 `#^id` 붙이기
+fn with_fragment(target: &str, heading: Option<&str>, block_ref: Option<&str>) -> String {
+    match (heading, block_ref) {
+        (_, Some(block_ref)) => format!("{}#^{}", target, block_ref),
+        (Some(heading), None) => format!("{}#{}", target, heading),
+        (None, None) => target.to_string(),
+    }
+}
+
+/// 문서 전체를 HTML로 렌더링 (위키링크는 `DefaultLinkResolver`로 연결)
+pub fn render_html(doc: &DocumentNode) -> String {
+    render_html_with(doc, &DefaultLinkResolver)
+}
+
+/// 문서 전체를 HTML로 렌더링 (위키링크는 주어진 resolver로 연결)
+pub fn render_html_with(doc: &DocumentNode, resolver: &dyn LinkResolver) -> String {
+    let mut out = String::new();
+    render_blocks(&doc.children, false, resolver, &mut out);
+    render_footnotes(&doc.children, resolver, &mut out);
+    out
+}
+
+// =============================================================================
+// 블록
+// =============================================================================
+
+/// 블록 목록 렌더링
+/// tight가 true면 (tight 리스트 아이템 안) Paragraph를 태그 없이 출력
+fn render_blocks(blocks: &[BlockNode], tight: bool, resolver: &dyn LinkResolver, out: &mut String) {
+    for block in blocks {
+        render_block(block, tight, resolver, out);
+    }
+}
+
+fn render_block(block: &BlockNode, tight: bool, resolver: &dyn LinkResolver, out: &mut String) {
+    match block {
+        BlockNode::ThematicBreak(_) => {
+            cr(out);
+            out.push_str("<hr />");
+            cr(out);
+        }
+        BlockNode::Heading(node) => {
+            cr(out);
+            out.push_str(&format!("<h{}>", node.level));
+            render_inlines(&node.children, resolver, out);
+            out.push_str(&format!("</h{}>", node.level));
+            cr(out);
+        }
+        BlockNode::CodeBlock(node) => render_code_block(node, out),
+        // 수식은 TeX 원문 그대로 (클라이언트의 KaTeX/MathJax가 `.math` 요소를 렌더링)
+        BlockNode::MathBlock(node) => {
+            cr(out);
+            out.push_str(&format!("<div class=\"math\">{}</div>", escape_html(&node.content)));
+            cr(out);
+        }
+        BlockNode::HtmlBlock(node) => {
+            cr(out);
+            out.push_str(&node.content);
+            cr(out);
+        }
+        BlockNode::Comment(_) => {}
+        BlockNode::Paragraph(node) => match node.children.as_slice() {
+            [InlineNode::Embed(embed @ EmbedNode { content: Some(content), .. })] => {
+                render_transclusion(embed, content, resolver, out)
+            }
+            // 주석만 있는 Paragraph는 빈 `<p>`를 남기지 않음
+            children if is_comment_only(children) => {}
+            children => render_paragraph(children, node.block_id.as_deref(), tight, None, resolver, out),
+        },
+        BlockNode::Blockquote(node) => {
+            cr(out);
+            out.push_str(&format!("<blockquote{}>", id_attribute(node.block_id.as_deref())));
+            cr(out);
+            render_blocks(&node.children, false, resolver, out);
+            cr(out);
+            out.push_str("</blockquote>");
+            cr(out);
+        }
+        BlockNode::Callout(node) => render_callout(node, resolver, out),
+        BlockNode::List(node) => render_list(node, resolver, out),
+        BlockNode::ListItem(node) => render_list_item(node, tight, resolver, out),
+        // 참조 정의는 출력하지 않음, 각주 정의는 문서 끝에 모아서 출력
+        BlockNode::LinkReferenceDefinition(_) | BlockNode::FootnoteDefinition(_) => {}
+        BlockNode::Table(node) => render_table(node, resolver, out),
+    }
+}
+
+/// Code Block 렌더링 (내용 끝에 줄바꿈 추가)
+fn render_code_block(node: &CodeBlockNode, out: &mut String) {
+    cr(out);
+    out.push_str("<pre><code");
+    let language = node.info.as_deref().and_then(|info| info.split_whitespace().next());
+    if let Some(language) = language {
+        out.push_str(&format!(" class=\"language-{}\"", escape_html(language)));
+    }
+    out.push('>');
+    if !node.content.is_empty() {
+        out.push_str(&escape_html(&node.content));
+        out.push('\n');
+    }
+    out.push_str("</code></pre>");
+    cr(out);
+}
+
+/// Callout 렌더링 (접을 수 있으면 `<details>`, `-`면 닫힌 상태)
+fn render_callout(node: &CalloutNode, resolver: &dyn LinkResolver, out: &mut String) {
+    let (tag, title_tag) = match node.folded {
+        Some(_) => ("details", "summary"),
+        None => ("div", "div"),
+    };
+    let open = match node.folded {
+        Some(false) => " open",
+        _ => "",
+    };
+
+    cr(out);
+    out.push_str(&format!(
+        "<{} class=\"callout\" data-callout=\"{}\"{}{}>",
+        tag,
+        escape_html(&node.kind),
+        id_attribute(node.block_id.as_deref()),
+        open
+    ));
+    cr(out);
+    out.push_str(&format!("<{} class=\"callout-title\">", title_tag));
+    match node.title.is_empty() {
+        true => out.push_str(&escape_html(&default_callout_title(&node.kind))),
+        false => render_inlines(&node.title, resolver, out),
+    }
+    out.push_str(&format!("</{}>", title_tag));
+    cr(out);
+    if !node.children.is_empty() {
+        out.push_str("<div class=\"callout-content\">");
+        cr(out);
+        render_blocks(&node.children, false, resolver, out);
+        cr(out);
+        out.push_str("</div>");
+        cr(out);
+    }
+    out.push_str(&format!("</{}>", tag));
+    cr(out);
+}
+
+/// 제목 없는 Callout의 제목 (`warning` → `Warning`)
+fn default_callout_title(kind: &str) -> String {
+    let mut chars = kind.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn render_list(node: &ListNode, resolver: &dyn LinkResolver, out: &mut String) {
+    let tag = match node.list_type {
+        ListType::Bullet => "ul",
+        ListType::Ordered { .. } => "ol",
+    };
+
+    cr(out);
+    match node.list_type {
+        ListType::Ordered { .. } if node.start != 1 => {
+            out.push_str(&format!("<{} start=\"{}\">", tag, node.start));
+        }
+        _ => out.push_str(&format!("<{}>", tag)),
+    }
+    cr(out);
+    for item in &node.children {
+        render_list_item(item, node.tight, resolver, out);
+    }
+    cr(out);
+    out.push_str(&format!("</{}>", tag));
+    cr(out);
+}
+
+/// Paragraph 렌더링 (checkbox: Task list 아이템의 첫 Paragraph면 체크 상태)
+/// tight하면 `<p>` 없이 내용만 출력하므로 block id도 출력하지 않음
+fn render_paragraph(
+    children: &[InlineNode],
+    block_id: Option<&str>,
+    tight: bool,
+    checkbox: Option<bool>,
+    resolver: &dyn LinkResolver,
+    out: &mut String,
+) {
+    if !tight {
+        cr(out);
+        out.push_str(&format!("<p{}>", id_attribute(block_id)));
+    }
+    if let Some(checked) = checkbox {
+        render_checkbox(checked, out);
+    }
+    render_inlines(children, resolver, out);
+    if !tight {
+        out.push_str("</p>");
+        cr(out);
+    }
+}
+
+fn render_list_item(node: &ListItemNode, tight: bool, resolver: &dyn LinkResolver, out: &mut String) {
+    cr(out);
+    out.push_str(&format!("<li{}>", id_attribute(node.block_id.as_deref())));
+    match (node.checked, node.children.split_first()) {
+        (Some(checked), Some((BlockNode::Paragraph(first), rest))) => {
+            render_paragraph(&first.children, first.block_id.as_deref(), tight, Some(checked), resolver, out);
+            render_blocks(rest, tight, resolver, out);
+        }
+        (Some(checked), _) => {
+            render_checkbox(checked, out);
+            render_blocks(&node.children, tight, resolver, out);
+        }
+        (None, _) => render_blocks(&node.children, tight, resolver, out),
+    }
+    out.push_str("</li>");
+    cr(out);
+}
+
+/// Task list 체크박스 (GitHub처럼 비활성, 뒤에 공백 한 칸)
+fn render_checkbox(checked: bool, out: &mut String) {
+    match checked {
+        true => out.push_str("<input type=\"checkbox\" checked=\"\" disabled=\"\" /> "),
+        false => out.push_str("<input type=\"checkbox\" disabled=\"\" /> "),
+    }
+}
+
+fn render_table(node: &TableNode, resolver: &dyn LinkResolver, out: &mut String) {
+    cr(out);
+    out.push_str(&format!("<table{}>\n<thead>\n", id_attribute(node.block_id.as_deref())));
+    render_table_row(&node.header, &node.alignments, "th", resolver, out);
+    out.push_str("</thead>\n");
+    if !node.rows.is_empty() {
+        out.push_str("<tbody>\n");
+        for row in &node.rows {
+            render_table_row(row, &node.alignments, "td", resolver, out);
+        }
+        out.push_str("</tbody>\n");
+    }
+    out.push_str("</table>\n");
+}
+
+fn render_table_row(
+    row: &TableRowNode,
+    alignments: &[TableAlignment],
+    tag: &str,
+    resolver: &dyn LinkResolver,
+    out: &mut String,
+) {
+    out.push_str("<tr>\n");
+    for (cell, alignment) in row.cells.iter().zip(alignments) {
+        let align = match alignment {
+            TableAlignment::None => "",
+            TableAlignment::Left => " align=\"left\"",
+            TableAlignment::Center => " align=\"center\"",
+            TableAlignment::Right => " align=\"right\"",
+        };
+        out.push_str(&format!("<{}{}>", tag, align));
+        render_inlines(&cell.children, resolver, out);
+        out.push_str(&format!("</{}>\n", tag));
+    }
+    out.push_str("</tr>\n");
+}
+
+/// 트랜스클루전으로 불러온 노트 내용
+fn render_transclusion(node: &EmbedNode, content: &[BlockNode], resolver: &dyn LinkResolver, out: &mut String) {
+    cr(out);
+    out.push_str(&format!("<div class=\"embed\" data-embed=\"{}\">", escape_html(&node.target)));
+    cr(out);
+    render_blocks(content, false, resolver, out);
+    cr(out);
+    out.push_str("</div>");
+    cr(out);
+}
+
+/// block id의 `id` 속성 (`^` 포함, `[[Page#^id]]`의 fragment와 같음)
+fn id_attribute(block_id: Option<&str>) -> String {
+    match block_id {
+        Some(id) => format!(" id=\"^{}\"", escape_html(id)),
+        None => String::new(),
+    }
+}
+
+/// 출력이 줄바꿈으로 끝나지 않으면 줄바꿈 추가
+fn cr(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+// =============================================================================
+// 인라인
+// =============================================================================
+
+fn render_inlines(inlines: &[InlineNode], resolver: &dyn LinkResolver, out: &mut String) {
+    for inline in inlines {
+        render_inline(inline, resolver, out);
+    }
+}
+
+fn render_inline(inline: &InlineNode, resolver: &dyn LinkResolver, out: &mut String) {
+    match inline {
+        InlineNode::Text(node) => out.push_str(&escape_html(node.as_str())),
+        InlineNode::Emphasis(node) => {
+            out.push_str("<em>");
+            render_inlines(&node.children, resolver, out);
+            out.push_str("</em>");
+        }
+        InlineNode::Strong(node) => {
+            out.push_str("<strong>");
+            render_inlines(&node.children, resolver, out);
+            out.push_str("</strong>");
+        }
+        InlineNode::Strikethrough(node) => {
+            out.push_str("<del>");
+            render_inlines(&node.children, resolver, out);
+            out.push_str("</del>");
+        }
+        InlineNode::Highlight(node) => {
+            out.push_str("<mark>");
+            render_inlines(&node.children, resolver, out);
+            out.push_str("</mark>");
+        }
+        InlineNode::CodeSpan(node) => {
+            out.push_str("<code>");
+            out.push_str(&escape_html(&node.content));
+            out.push_str("</code>");
+        }
+        InlineNode::InlineMath(node) => {
+            out.push_str(&format!("<span class=\"math\">{}</span>", escape_html(&node.content)));
+        }
+        InlineNode::Link(node) => {
+            out.push_str(&format!("<a href=\"{}\"", escape_html(&normalize_url(&node.destination))));
+            if let Some(title) = &node.title {
+                out.push_str(&format!(" title=\"{}\"", escape_html(title)));
+            }
+            out.push('>');
+            render_inlines(&node.children, resolver, out);
+            out.push_str("</a>");
+        }
+        InlineNode::Image(node) => {
+            out.push_str(&format!(
+                "<img src=\"{}\" alt=\"{}\"",
+                escape_html(&normalize_url(&node.destination)),
+                escape_html(&plain_text(&node.children)),
+            ));
+            if let Some(title) = &node.title {
+                out.push_str(&format!(" title=\"{}\"", escape_html(title)));
+            }
+            out.push_str(" />");
+        }
+        InlineNode::WikiLink(node) => {
+            out.push_str(&format!(
+                "<a href=\"{}\" class=\"wikilink\">{}</a>",
+                escape_html(&normalize_url(&resolver.resolve_wikilink(node))),
+                escape_html(&node.display_text()),
+            ));
+        }
+        InlineNode::Embed(node) => render_embed(node, resolver, out),
+        InlineNode::Tag(node) => {
+            out.push_str(&format!(
+                "<a href=\"{}\" class=\"tag\">#{}</a>",
+                escape_html(&normalize_url(&resolver.resolve_tag(node))),
+                escape_html(&node.name),
+            ));
+        }
+        InlineNode::Autolink(node) => {
+            out.push_str(&format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(&normalize_url(&node.destination())),
+                escape_html(&node.url),
+            ));
+        }
+        InlineNode::RawHtml(node) => out.push_str(&node.content),
+        InlineNode::Comment(_) => {}
+        InlineNode::FootnoteReference(node) => render_footnote_reference(node, out),
+        InlineNode::SoftBreak(_) => out.push('\n'),
+        InlineNode::HardBreak(_) => out.push_str("<br />\n"),
+    }
+}
+
+/// 주석과 줄바꿈만 있는 인라인 목록인지
+fn is_comment_only(inlines: &[InlineNode]) -> bool {
+    inlines.iter().any(|inline| matches!(inline, InlineNode::Comment(_)))
+        && inlines.iter().all(|inline| {
+            matches!(inline, InlineNode::Comment(_) | InlineNode::SoftBreak(_) | InlineNode::HardBreak(_))
+        })
+}
+
+/// 인라인 임베드: 이미지는 크기 힌트를 붙인 `<img>`, 노트는 링크
+fn render_embed(node: &EmbedNode, resolver: &dyn LinkResolver, out: &mut String) {
+    let url = escape_html(&normalize_url(&resolver.resolve_embed(node)));
+    if !node.is_image() {
+        out.push_str(&format!("<a href=\"{}\" class=\"embed\">{}</a>", url, escape_html(&node.display_text())));
+        return;
+    }
+    out.push_str(&format!("<img src=\"{}\" alt=\"{}\"", url, escape_html(&node.display_text())));
+    if let Some(width) = node.width {
+        out.push_str(&format!(" width=\"{}\"", width));
+    }
+    if let Some(height) = node.height {
+        out.push_str(&format!(" height=\"{}\"", height));
+    }
+    out.push_str(" />");
+}
+
+/// 이미지 alt 속성용 평문 (태그 없이 텍스트만)
+fn plain_text(inlines: &[InlineNode]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            InlineNode::Text(node) => node.as_str().to_string(),
+            InlineNode::CodeSpan(node) => node.content.clone(),
+            InlineNode::InlineMath(node) => node.content.clone(),
+            InlineNode::Emphasis(node) => plain_text(&node.children),
+            InlineNode::Strong(node) => plain_text(&node.children),
+            InlineNode::Strikethrough(node) => plain_text(&node.children),
+            InlineNode::Highlight(node) => plain_text(&node.children),
+            InlineNode::Link(node) => plain_text(&node.children),
+            InlineNode::Image(node) => plain_text(&node.children),
+            InlineNode::WikiLink(node) => node.display_text(),
+            InlineNode::Embed(node) => node.display_text(),
+            InlineNode::Tag(node) => format!("#{}", node.name),
+            InlineNode::Comment(_) => String::new(),
+            InlineNode::Autolink(node) => node.url.clone(),
+            InlineNode::RawHtml(node) => node.content.clone(),
+            InlineNode::FootnoteReference(node) => match node.index {
+                Some(index) => index.to_string(),
+                None => format!("[^{}]", node.label),
+            },
+            InlineNode::SoftBreak(_) | InlineNode::HardBreak(_) => "\n".to_string(),
+        })
+        .collect()
+}
+
+// =============================================================================
+// 각주
+// =============================================================================
+
+/// 각주 section에 출력할 내용
+enum Footnote<'a> {
+    /// `[^label]: ...` 정의 (블록들, 참조 횟수)
+    Definition(&'a [BlockNode], usize),
+    /// `^[...]` 인라인 각주
+    Inline(&'a [InlineNode]),
+}
+
+/// 각주 참조: 번호가 있으면 section으로 가는 링크, 정의되지 않은 참조는 원문 그대로
+fn render_footnote_reference(node: &FootnoteReferenceNode, out: &mut String) {
+    let Some(index) = node.index else {
+        out.push_str(&escape_html(&format!("[^{}]", node.label)));
+        return;
+    };
+    out.push_str(&format!(
+        "<sup class=\"footnote-ref\"><a href=\"#fn-{index}\" id=\"fnref-{}\" data-footnote-ref>{index}</a></sup>",
+        reference_id(index, node.occurrence),
+    ));
+}
+
+/// 참조 위치 id 접미사 (두 번째 참조부터 `-2`, `-3`, …)
+fn reference_id(index: usize, occurrence: usize) -> String {
+    match occurrence {
+        0 | 1 => index.to_string(),
+        _ => format!("{}-{}", index, occurrence),
+    }
+}
+
+/// 번호가 매겨진 각주를 번호 순으로 문서 끝에 출력
+fn render_footnotes(blocks: &[BlockNode], resolver: &dyn LinkResolver, out: &mut String) {
+    let mut footnotes = Vec::new();
+    collect_footnotes(blocks, &mut footnotes);
+    if footnotes.is_empty() {
+        return;
+    }
+    footnotes.sort_by_key(|(index, _)| *index);
+
+    cr(out);
+    out.push_str("<section class=\"footnotes\" data-footnotes>\n<ol>\n");
+    for (index, footnote) in footnotes {
+        out.push_str(&format!("<li id=\"fn-{}\">\n", index));
+        match footnote {
+            Footnote::Definition(children, ref_count) => {
+                let backrefs = backrefs(index, ref_count);
+                match children.split_last() {
+                    // 마지막 Paragraph 안에 backref를 붙임
+                    Some((BlockNode::Paragraph(last), rest)) => {
+                        render_blocks(rest, false, resolver, out);
+                        render_paragraph_with_backrefs(&last.children, &backrefs, resolver, out);
+                    }
+                    _ => {
+                        render_blocks(children, false, resolver, out);
+                        cr(out);
+                        out.push_str(&backrefs);
+                        out.push('\n');
+                    }
+                }
+            }
+            Footnote::Inline(children) => render_paragraph_with_backrefs(children, &backrefs(index, 1), resolver, out),
+        }
+        out.push_str("</li>\n");
+    }
+    out.push_str("</ol>\n</section>\n");
+}
+
+fn render_paragraph_with_backrefs(
+    children: &[InlineNode],
+    backrefs: &str,
+    resolver: &dyn LinkResolver,
+    out: &mut String,
+) {
+    cr(out);
+    out.push_str("<p>");
+    render_inlines(children, resolver, out);
+    out.push(' ');
+    out.push_str(backrefs);
+    out.push_str("</p>\n");
+}
+
+/// 참조 위치로 되돌아가는 링크들 (참조마다 하나, 두 번째부터 참조 순번 표시)
+fn backrefs(index: usize, ref_count: usize) -> String {
+    (1..=ref_count)
+        .map(|occurrence| {
+            let id = reference_id(index, occurrence);
+            let sup = match occurrence {
+                1 => String::new(),
+                _ => format!("<sup class=\"footnote-ref\">{}</sup>", occurrence),
+            };
+            format!(
+                "<a href=\"#fnref-{id}\" class=\"footnote-backref\" data-footnote-backref \
+                 data-footnote-backref-idx=\"{id}\" aria-label=\"Back to reference {id}\">↩{sup}</a>"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 블록 트리에서 번호가 매겨진 각주 정의와 인라인 각주 수집
+fn collect_footnotes<'a>(blocks: &'a [BlockNode], footnotes: &mut Vec<(usize, Footnote<'a>)>) {
+    for block in blocks {
+        match block {
+            BlockNode::Paragraph(node) => collect_inline_footnotes(&node.children, footnotes),
+            BlockNode::Heading(node) => collect_inline_footnotes(&node.children, footnotes),
+            BlockNode::Table(node) => {
+                for row in std::iter::once(&node.header).chain(&node.rows) {
+                    for cell in &row.cells {
+                        collect_inline_footnotes(&cell.children, footnotes);
+                    }
+                }
+            }
+            BlockNode::Blockquote(node) => collect_footnotes(&node.children, footnotes),
+            BlockNode::Callout(node) => {
+                collect_inline_footnotes(&node.title, footnotes);
+                collect_footnotes(&node.children, footnotes);
+            }
+            BlockNode::List(node) => {
+                for item in &node.children {
+                    collect_footnotes(&item.children, footnotes);
+                }
+            }
+            BlockNode::ListItem(node) => collect_footnotes(&node.children, footnotes),
+            BlockNode::FootnoteDefinition(node) => {
+                if let Some(index) = node.index {
+                    footnotes.push((index, Footnote::Definition(&node.children, node.ref_count)));
+                }
+                collect_footnotes(&node.children, footnotes);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_inline_footnotes<'a>(inlines: &'a [InlineNode], footnotes: &mut Vec<(usize, Footnote<'a>)>) {
+    for inline in inlines {
+        match inline {
+            InlineNode::FootnoteReference(node) => {
+                if let (Some(index), Some(children)) = (node.index, &node.inline) {
+                    footnotes.push((index, Footnote::Inline(children)));
+                    collect_inline_footnotes(children, footnotes);
+                }
+            }
+            InlineNode::Emphasis(node) => collect_inline_footnotes(&node.children, footnotes),
+            InlineNode::Strong(node) => collect_inline_footnotes(&node.children, footnotes),
+            InlineNode::Strikethrough(node) => collect_inline_footnotes(&node.children, footnotes),
+            InlineNode::Highlight(node) => collect_inline_footnotes(&node.children, footnotes),
+            InlineNode::Link(node) => collect_inline_footnotes(&node.children, footnotes),
+            InlineNode::Image(node) => collect_inline_footnotes(&node.children, footnotes),
+            _ => {}
+        }
+    }
+}
+
+// =============================================================================
+// 이스케이프
+// =============================================================================
+
+/// HTML 특수 문자 이스케이프
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// URL 퍼센트 인코딩
+/// URL에 쓸 수 있는 ASCII 문자와 이미 인코딩된 `%XX`는 그대로 두고, 나머지는 UTF-8 바이트 단위로 인코딩
+fn normalize_url(url: &str) -> String {
+    let bytes = url.as_bytes();
+    let mut normalized = String::with_capacity(url.len());
+    for (i, &byte) in bytes.iter().enumerate() {
+        let is_encoded = byte == b'%'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_hexdigit)
+            && bytes.get(i + 2).is_some_and(u8::is_ascii_hexdigit);
+        if is_encoded || byte.is_ascii_alphanumeric() || b";/?:@&=+$,-_.!~*'()#".contains(&byte) {
+            normalized.push(byte as char);
+        } else {
+            normalized.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    fn render(input: &str) -> String {
+        render_html(&parse(input))
+    }
+
+    #[rstest]
+    #[case("foo", "foo")]
+    #[case("a < b & \"c\" > d", "a &lt; b &amp; &quot;c&quot; &gt; d")]
+    fn test_escape_html(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(escape_html(input), expected);
+    }
+
+    #[rstest]
+    #[case("/url", "/url")]
+    #[case("/f\u{f6}\u{f6}", "/f%C3%B6%C3%B6")]
+    #[case("foo%20b\u{e4}", "foo%20b%C3%A4")]
+    #[case("/my uri", "/my%20uri")]
+    #[case("a%zz\\b", "a%25zz%5Cb")]
+    fn test_normalize_url(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(normalize_url(input), expected);
+    }
+
+    #[rstest]
+    // Example 43: thematic break
+    #[case("***\n---\n___", "<hr />\n<hr />\n<hr />\n")]
+    // Example 62: ATX heading
+    #[case("# foo\n## foo\n### foo\n#### foo\n##### foo\n###### foo", "<h1>foo</h1>\n<h2>foo</h2>\n<h3>foo</h3>\n<h4>foo</h4>\n<h5>foo</h5>\n<h6>foo</h6>\n")]
+    // Example 80: setext heading와 인라인
+    #[case("Foo *bar*\n=========\n\nFoo *bar*\n---------", "<h1>Foo <em>bar</em></h1>\n<h2>Foo <em>bar</em></h2>\n")]
+    // Example 107: indented code block
+    #[case("    a simple\n      indented code block", "<pre><code>a simple\n  indented code block\n</code></pre>\n")]
+    // Example 119: 코드 내용 이스케이프
+    #[case("```\n<\n >\n```", "<pre><code>&lt;\n &gt;\n</code></pre>\n")]
+    // Example 126: 빈 코드 블록
+    #[case("```", "<pre><code></code></pre>\n")]
+    // Example 142: info string으로 language class
+    #[case("```ruby\ndef foo(x)\n  return 3\nend\n```", "<pre><code class=\"language-ruby\">def foo(x)\n  return 3\nend\n</code></pre>\n")]
+    // 추가 케이스: info string 첫 단어만 사용
+    #[case("``` rust ignore\nfn main() {}\n```", "<pre><code class=\"language-rust\">fn main() {}\n</code></pre>\n")]
+    // Example 152: HTML 블록은 그대로
+    #[case("<DIV CLASS=\"foo\">\n\n*Markdown*\n\n</DIV>", "<DIV CLASS=\"foo\">\n<p><em>Markdown</em></p>\n</DIV>\n")]
+    // Example 192: 참조 정의는 출력하지 않음
+    #[case("[foo]: /url \"title\"\n\n[foo]", "<p><a href=\"/url\" title=\"title\">foo</a></p>\n")]
+    // Example 219: paragraph
+    #[case("aaa\n\nbbb", "<p>aaa</p>\n<p>bbb</p>\n")]
+    // 추가 케이스: blockquote
+    #[case("> foo\n> bar\n\n> > baz", "<blockquote>\n<p>foo\nbar</p>\n</blockquote>\n<blockquote>\n<blockquote>\n<p>baz</p>\n</blockquote>\n</blockquote>\n")]
+    // Example 265: ordered list start 속성
+    #[case("123456789. ok", "<ol start=\"123456789\">\n<li>ok</li>\n</ol>\n")]
+    // Example 281: 빈 아이템
+    #[case("- foo\n-\n- bar", "<ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>\n")]
+    // Example 294: tight 리스트 안의 중첩 리스트
+    #[case("- foo\n  - bar\n    - baz\n      - boo", "<ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n")]
+    // Example 301: tight bullet list
+    #[case("- foo\n- bar\n+ baz", "<ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul>\n<li>baz</li>\n</ul>\n")]
+    // Example 306: loose list
+    #[case("- foo\n\n- bar\n\n\n- baz", "<ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>\n")]
+    // GFM Example 279: task list
+    #[case("- [ ] foo\n- [x] bar", "<ul>\n<li><input type=\"checkbox\" disabled=\"\" /> foo</li>\n<li><input type=\"checkbox\" checked=\"\" disabled=\"\" /> bar</li>\n</ul>\n")]
+    // 추가 케이스: loose task list는 체크박스가 <p> 안에
+    #[case("- [x] foo\n\n- [ ] bar", "<ul>\n<li>\n<p><input type=\"checkbox\" checked=\"\" disabled=\"\" /> foo</p>\n</li>\n<li>\n<p><input type=\"checkbox\" disabled=\"\" /> bar</p>\n</li>\n</ul>\n")]
+    // Example 328: code span
+    #[case("`foo`", "<p><code>foo</code></p>\n")]
+    // Example 350: emphasis
+    #[case("*foo bar*", "<p><em>foo bar</em></p>\n")]
+    // Example 482: 링크 title
+    #[case("[link](/uri \"title\")", "<p><a href=\"/uri\" title=\"title\">link</a></p>\n")]
+    // Example 503: 링크 destination 퍼센트 인코딩
+    #[case("[link](foo%20b&auml;)", "<p><a href=\"foo%20b%C3%A4\">link</a></p>\n")]
+    // Example 573: 이미지 alt는 평문
+    #[case("![foo *bar*]\n\n[foo *bar*]: train.jpg \"train & tracks\"", "<p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>\n")]
+    // Example 594: autolink
+    #[case("<http://foo.bar.baz>", "<p><a href=\"http://foo.bar.baz\">http://foo.bar.baz</a></p>\n")]
+    // Example 604: email autolink
+    #[case("<foo@bar.example.com>", "<p><a href=\"mailto:foo@bar.example.com\">foo@bar.example.com</a></p>\n")]
+    // Example 613: raw HTML
+    #[case("<a><bab><c2c>", "<p><a><bab><c2c></p>\n")]
+    // Example 633: hard line break
+    #[case("foo  \nbaz", "<p>foo<br />\nbaz</p>\n")]
+    // Example 649: soft line break
+    #[case("foo \n baz", "<p>foo\nbaz</p>\n")]
+    // GFM Example 199: 표 열 정렬
+    #[case("| abc | defghi |\n:-: | -----------:\nbar | baz", "<table>\n<thead>\n<tr>\n<th align=\"center\">abc</th>\n<th align=\"right\">defghi</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td align=\"center\">bar</td>\n<td align=\"right\">baz</td>\n</tr>\n</tbody>\n</table>\n")]
+    // GFM Example 205: 본문 행이 없으면 tbody 생략
+    #[case("| abc | def |\n| --- | --- |", "<table>\n<thead>\n<tr>\n<th>abc</th>\n<th>def</th>\n</tr>\n</thead>\n</table>\n")]
+    // 각주: 참조 순서대로 번호, 여러 번 참조하면 backref도 여러 개
+    #[case(
+        "a[^x] b[^y] c[^x]\n\n[^y]: Y\n\n[^x]: X",
+        "<p>a<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\" data-footnote-ref>1</a></sup> b<sup class=\"footnote-ref\"><a href=\"#fn-2\" id=\"fnref-2\" data-footnote-ref>2</a></sup> c<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1-2\" data-footnote-ref>1</a></sup></p>\n\
+         <section class=\"footnotes\" data-footnotes>\n<ol>\n\
+         <li id=\"fn-1\">\n<p>X <a href=\"#fnref-1\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1\" aria-label=\"Back to reference 1\">↩</a> <a href=\"#fnref-1-2\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1-2\" aria-label=\"Back to reference 1-2\">↩<sup class=\"footnote-ref\">2</sup></a></p>\n</li>\n\
+         <li id=\"fn-2\">\n<p>Y <a href=\"#fnref-2\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"2\" aria-label=\"Back to reference 2\">↩</a></p>\n</li>\n\
+         </ol>\n</section>\n"
+    )]
+    // 마지막 블록이 Paragraph가 아니면 backref는 따로, 인라인 각주도 같은 section
+    #[case(
+        "a[^x] ^[*b*]\n\n[^x]:\n    ```\n    code\n    ```",
+        "<p>a<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\" data-footnote-ref>1</a></sup> <sup class=\"footnote-ref\"><a href=\"#fn-2\" id=\"fnref-2\" data-footnote-ref>2</a></sup></p>\n\
+         <section class=\"footnotes\" data-footnotes>\n<ol>\n\
+         <li id=\"fn-1\">\n<pre><code>code\n</code></pre>\n<a href=\"#fnref-1\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"1\" aria-label=\"Back to reference 1\">↩</a>\n</li>\n\
+         <li id=\"fn-2\">\n<p><em>b</em> <a href=\"#fnref-2\" class=\"footnote-backref\" data-footnote-backref data-footnote-backref-idx=\"2\" aria-label=\"Back to reference 2\">↩</a></p>\n</li>\n\
+         </ol>\n</section>\n"
+    )]
+    // 정의되지 않은 참조는 원문 그대로, 참조되지 않은 정의는 생략
+    #[case("a[^missing]\n\n[^unused]: U", "<p>a[^missing]</p>\n")]
+    // GFM Example 491: 취소선
+    #[case("~~Hi~~ Hello, ~there~ world!", "<p><del>Hi</del> Hello, <del>there</del> world!</p>\n")]
+    // GFM Example 622, 630-631: extended autolink (`www.`는 http://, email은 mailto:)
+    #[case("Visit www.commonmark.org/help for more information.", "<p>Visit <a href=\"http://www.commonmark.org/help\">www.commonmark.org/help</a> for more information.</p>\n")]
+    #[case("https://encrypted.google.com/search?q=a&b", "<p><a href=\"https://encrypted.google.com/search?q=a&amp;b\">https://encrypted.google.com/search?q=a&amp;b</a></p>\n")]
+    #[case("https://a.b/?x=1&amp;y=2", "<p><a href=\"https://a.b/?x=1&amp;y=2\">https://a.b/?x=1&amp;y=2</a></p>\n")]
+    #[case("foo@bar.baz", "<p><a href=\"mailto:foo@bar.baz\">foo@bar.baz</a></p>\n")]
+    // Callout: 제목이 없으면 종류 이름, 접을 수 있으면 details
+    #[case("> [!note]\n> body", "<div class=\"callout\" data-callout=\"note\">\n<div class=\"callout-title\">Note</div>\n<div class=\"callout-content\">\n<p>body</p>\n</div>\n</div>\n")]
+    #[case("> [!tip]+ *Read* me\n> body", "<details class=\"callout\" data-callout=\"tip\" open>\n<summary class=\"callout-title\"><em>Read</em> me</summary>\n<div class=\"callout-content\">\n<p>body</p>\n</div>\n</details>\n")]
+    #[case("> [!faq]- Why?", "<details class=\"callout\" data-callout=\"faq\">\n<summary class=\"callout-title\">Why?</summary>\n</details>\n")]
+    // 위키링크: 기본 resolver는 대상 + fragment, 표시 텍스트는 alias 또는 대상
+    #[case("[[My Page]]", "<p><a href=\"My%20Page\" class=\"wikilink\">My Page</a></p>\n")]
+    #[case("[[Page#^abc|see <this>]]", "<p><a href=\"Page#%5Eabc\" class=\"wikilink\">see &lt;this&gt;</a></p>\n")]
+    #[case("[[#Intro]]", "<p><a href=\"#Intro\" class=\"wikilink\">Intro</a></p>\n")]
+    // 임베드: 이미지는 크기 힌트, 불러오지 않은 노트는 링크
+    #[case("![[cat.png|300x200]]", "<p><img src=\"cat.png\" alt=\"cat.png\" width=\"300\" height=\"200\" /></p>\n")]
+    #[case("![[my cat.jpg|A cat]]", "<p><img src=\"my%20cat.jpg\" alt=\"A cat\" /></p>\n")]
+    #[case("![[Note#Usage]]", "<p><a href=\"Note#Usage\" class=\"embed\">Note &gt; Usage</a></p>\n")]
+    // block id: `[[Page#^id]]`의 fragment와 같은 `id` 속성
+    #[case("text ^p1\n\n> quote\n\n^q1", "<p id=\"^p1\">text</p>\n<blockquote id=\"^q1\">\n<p>quote</p>\n</blockquote>\n")]
+    #[case("- [ ] task ^t1\n- item", "<ul>\n<li id=\"^t1\"><input type=\"checkbox\" disabled=\"\" /> task</li>\n<li>item</li>\n</ul>\n")]
+    #[case("| a |\n| - |\n\n^tbl", "<table id=\"^tbl\">\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n</table>\n")]
+    #[case("> [!tip]-\n> body\n\n^c1", "<details class=\"callout\" data-callout=\"tip\" id=\"^c1\">\n<summary class=\"callout-title\">Tip</summary>\n<div class=\"callout-content\">\n<p>body</p>\n</div>\n</details>\n")]
+    // 태그: 기본 resolver는 `#이름`
+    #[case("see #project/태그", "<p>see <a href=\"#project/%ED%83%9C%EA%B7%B8\" class=\"tag\">#project/태그</a></p>\n")]
+    // 하이라이트
+    #[case("==marked== text", "<p><mark>marked</mark> text</p>\n")]
+    // 주석은 출력하지 않음 (주석만 있는 Paragraph는 통째로 생략)
+    #[case("a %%private%% b", "<p>a  b</p>\n")]
+    #[case("%%private%%\n\nafter", "<p>after</p>\n")]
+    #[case("before\n\n%%\nsecret\n\n**more**\n%%\nafter", "<p>before</p>\n<p>after</p>\n")]
+    // 수식: TeX 원문을 이스케이프해 `.math` 요소로
+    #[case("$a<b$ costs $5", "<p><span class=\"math\">a&lt;b</span> costs $5</p>\n")]
+    #[case("$$\n\\sum_{i=1}^n i\n$$", "<div class=\"math\">\\sum_{i=1}^n i</div>\n")]
+    fn test_render_html(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(render(input), expected);
+    }
+
+    struct WikiResolver;
+
+    impl LinkResolver for WikiResolver {
+        fn resolve_wikilink(&self, link: &WikiLinkNode) -> String {
+            let page = format!("/wiki/{}.html", link.target.to_lowercase().replace(' ', "-"));
+            match &link.heading {
+                Some(heading) => format!("{}#{}", page, heading.to_lowercase()),
+                None => page,
+            }
+        }
+
+        fn resolve_tag(&self, tag: &TagNode) -> String {
+            format!("/tags/{}.html", tag.name)
+        }
+    }
+
+    #[test]
+    fn test_render_transclusion() {
+        let loader = |name: &str| (name == "Note").then(|| "# Note\n\nbody ![[Missing]]".to_string());
+        let doc = crate::parser::transclude(parse("- ![[Note]]\n\ntext ![[Note]]"), &loader);
+        assert_eq!(
+            render_html(&doc),
+            "<ul>\n<li>\n<div class=\"embed\" data-embed=\"Note\">\n<h1>Note</h1>\n<p>body <a href=\"Missing\" class=\"embed\">Missing</a></p>\n</div>\n</li>\n</ul>\n\
+             <p>text <a href=\"Note\" class=\"embed\">Note</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_html_with_resolver() {
+        let doc = parse("[[My Page#Usage|usage]] and [link](/url) #a/b");
+        assert_eq!(
+            render_html_with(&doc, &WikiResolver),
+            "<p><a href=\"/wiki/my-page.html#usage\" class=\"wikilink\">usage</a> and <a href=\"/url\">link</a> \
+             <a href=\"/tags/a/b.html\" class=\"tag\">#a/b</a></p>\n"
+        );
+    }
+}
@@ -1,5 +1,9 @@
-mod node;
+pub mod commonmark;
+pub mod html;
+pub mod node;
 mod parser;
 
+pub use commonmark::{render_commonmark, FormatOptions};
+pub use html::{render_html, render_html_with, DefaultLinkResolver, LinkResolver};
 pub use node::Node;
-pub use parser::parse;
+pub use parser::{collect_tags, parse, transclude, transclude_with_depth};
@@ -2,52 +2,804 @@
 //!
 //! ## 구조
 //! - `Node`: 모든 노드의 공통 trait
-//! - `InlineNode`: 인라인 노드 enum (Text, 향후 Emphasis, Strong 등)
+//! - `InlineNode`: 인라인 노드 enum (Text, Emphasis, Strong, Strikethrough, Highlight, CodeSpan, InlineMath, Link,
+//!   Image, WikiLink, Embed, Tag, Comment, FootnoteReference, SoftBreak, HardBreak 등)
 //! - `BlockNode`: 블록 노드 enum (ThematicBreak, Heading, Paragraph 등)
 //!
+//! 외부 크레이트(렌더러 등)에서 AST를 순회할 수 있도록 공개 모듈로 제공합니다.
+//! 노드는 `BlockNode::paragraph` 같은 빌더 메서드나 각 struct의 `new`로 생성합니다.
+//!
+//! ## 원문 위치
+//! 모든 노드는 `span` 필드에 원문 범위(`Span`)를 가집니다.
+//! 파서가 만든 노드만 위치가 채워지며, 직접 생성한 노드는 `Span::default()`입니다.
+//!
 //! ## Block 분류
-//! - **Container Blocks**: DocumentNode, BlockquoteNode, ListNode, ListItemNode
-//! - **Leaf Blocks**: ThematicBreakNode, HeadingNode, CodeBlockNode, ParagraphNode
+//! - **Container Blocks**: DocumentNode, BlockquoteNode, CalloutNode, ListNode, ListItemNode, FootnoteDefinitionNode
+//! - **Leaf Blocks**: ThematicBreakNode, HeadingNode, CodeBlockNode, MathBlockNode, HtmlBlockNode, CommentNode,
+//!   ParagraphNode, LinkReferenceDefinitionNode, TableNode
+//!
+//! 문서 맨 앞의 YAML frontmatter는 블록이 아니라 `DocumentNode.frontmatter`(`Frontmatter`)에 담깁니다.
+
+#[cfg(feature = "properties")]
+mod properties;
+pub(crate) mod visit;
 
 use std::fmt::Debug;
 
 /// 모든 노드의 공통 trait
 pub trait Node: Debug {}
 
+// =============================================================================
+// Source Position
+// =============================================================================
+
+/// 원문 안의 한 위치
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    /// 입력 시작부터의 byte offset
+    pub offset: usize,
+    /// 줄 번호 (1부터)
+    pub line: usize,
+    /// 열 번호 (1부터, 문자 단위)
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(offset: usize, line: usize, column: usize) -> Self {
+        Self { offset, line, column }
+    }
+}
+
+/// 노드가 차지하는 원문 범위 (`start` 포함, `end` 미포함)
+///
+/// 블록은 첫 줄의 첫 글자(컨테이너 마커 포함)부터 마지막 줄의 마지막 글자까지,
+/// 인라인은 구분자와 괄호를 포함한 원문 전체를 가리킵니다.
+///
+/// 위치를 빼고 트리 구조만 비교하려면 `without_spans`로 위치를 지운 노드끼리 비교하세요.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// byte offset만으로 생성 (줄/열은 파싱 마지막 단계에서 채움)
+    pub(crate) fn from_offsets(start: usize, end: usize) -> Self {
+        Self {
+            start: Position { offset: start, ..Position::default() },
+            end: Position { offset: end, ..Position::default() },
+        }
+    }
+}
+
 // =============================================================================
 // Inline Nodes
 // =============================================================================
 
 /// 텍스트 노드
 #[derive(Debug, Clone, PartialEq)]
-pub struct TextNode(pub String);
+#[non_exhaustive]
+pub struct TextNode {
+    pub content: String,
+    pub span: Span,
+}
 
 impl Node for TextNode {}
 
 impl TextNode {
     pub fn new(s: &str) -> Self {
-        TextNode(s.to_string())
+        Self {
+            content: s.to_string(),
+            span: Span::default(),
+        }
     }
 
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.content
+    }
+}
+
+/// 강조 노드 (`*foo*`, `_foo_`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct EmphasisNode {
+    pub children: Vec<InlineNode>,
+    pub span: Span,
+}
+
+impl Node for EmphasisNode {}
+
+impl EmphasisNode {
+    pub fn new(children: Vec<InlineNode>) -> Self {
+        Self { children, span: Span::default() }
+    }
+}
+
+/// 강한 강조 노드 (`**foo**`, `__foo__`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct StrongNode {
+    pub children: Vec<InlineNode>,
+    pub span: Span,
+}
+
+impl Node for StrongNode {}
+
+impl StrongNode {
+    pub fn new(children: Vec<InlineNode>) -> Self {
+        Self { children, span: Span::default() }
+    }
+}
+
+/// 취소선 노드 (GFM `~foo~`, `~~foo~~`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct StrikethroughNode {
+    pub children: Vec<InlineNode>,
+    pub span: Span,
+}
+
+impl Node for StrikethroughNode {}
+
+impl StrikethroughNode {
+    pub fn new(children: Vec<InlineNode>) -> Self {
+        Self { children, span: Span::default() }
+    }
+}
+
+/// 하이라이트 노드 (Obsidian `==foo==`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct HighlightNode {
+    pub children: Vec<InlineNode>,
+    pub span: Span,
+}
+
+impl Node for HighlightNode {}
+
+impl HighlightNode {
+    pub fn new(children: Vec<InlineNode>) -> Self {
+        Self { children, span: Span::default() }
+    }
+}
+
+/// 코드 스팬 노드 (`` `code` ``)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CodeSpanNode {
+    /// 백틱 사이 내용 (줄바꿈은 공백으로 정규화됨)
+    pub content: String,
+    pub span: Span,
+}
+
+impl Node for CodeSpanNode {}
+
+impl CodeSpanNode {
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            span: Span::default(),
+        }
+    }
+}
+
+/// 인라인 수식 노드 (`$x^2$`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct InlineMathNode {
+    /// `$` 사이의 TeX 원문 (백슬래시 포함 그대로)
+    pub content: String,
+    pub span: Span,
+}
+
+impl Node for InlineMathNode {}
+
+impl InlineMathNode {
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            span: Span::default(),
+        }
+    }
+}
+
+/// 링크 노드 (`[text](/url "title")`, `[text][label]`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LinkNode {
+    pub destination: String,
+    pub title: Option<String>,
+    /// 링크 텍스트
+    pub children: Vec<InlineNode>,
+    pub span: Span,
+}
+
+impl Node for LinkNode {}
+
+impl LinkNode {
+    pub fn new(destination: &str, title: Option<&str>, children: Vec<InlineNode>) -> Self {
+        Self {
+            destination: destination.to_string(),
+            title: title.map(|s| s.to_string()),
+            children,
+            span: Span::default(),
+        }
+    }
+}
+
+/// 이미지 노드 (`![alt](/src "title")`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ImageNode {
+    pub destination: String,
+    pub title: Option<String>,
+    /// 대체 텍스트 (렌더러는 평문으로 변환해 사용)
+    pub children: Vec<InlineNode>,
+    pub span: Span,
+}
+
+impl Node for ImageNode {}
+
+impl ImageNode {
+    pub fn new(destination: &str, title: Option<&str>, children: Vec<InlineNode>) -> Self {
+        Self {
+            destination: destination.to_string(),
+            title: title.map(|s| s.to_string()),
+            children,
+            span: Span::default(),
+        }
+    }
+}
+
+/// Autolink 노드 (`<https://example.com>`, `<foo@example.com>`)
+///
+/// GFM extended autolink(꺾쇠 없는 `www.example.com`, `https://example.com`, `foo@example.com`)도
+/// 같은 노드이며 `extended`로 구분합니다.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct AutolinkNode {
+    /// 꺾쇠 사이 원문 또는 링크가 된 원문 (email이면 `mailto:` 없이 주소만, `www.`이면 scheme 없이)
+    /// extended autolink는 원문의 백슬래시 이스케이프와 문자 참조를 해석한 값
+    pub url: String,
+    /// email autolink 여부
+    pub email: bool,
+    /// 꺾쇠 없는 GFM extended autolink 여부
+    pub extended: bool,
+    pub span: Span,
+}
+
+impl Node for AutolinkNode {}
+
+impl AutolinkNode {
+    pub fn new(url: &str, email: bool) -> Self {
+        Self {
+            url: url.to_string(),
+            email,
+            extended: false,
+            span: Span::default(),
+        }
+    }
+
+    /// GFM extended autolink 생성
+    pub fn extended(url: &str, email: bool) -> Self {
+        Self {
+            extended: true,
+            ..Self::new(url, email)
+        }
+    }
+
+    /// 링크 대상 (email이면 `mailto:`, `www.`로 시작하면 `http://`를 붙임)
+    pub fn destination(&self) -> String {
+        match (self.email, self.extended && self.url.starts_with("www.")) {
+            (true, _) => format!("mailto:{}", self.url),
+            (false, true) => format!("http://{}", self.url),
+            (false, false) => self.url.clone(),
+        }
+    }
+}
+
+/// Raw HTML 노드 (인라인 태그, 주석 등 원문 그대로)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RawHtmlNode {
+    pub content: String,
+    pub span: Span,
+}
+
+impl Node for RawHtmlNode {}
+
+impl RawHtmlNode {
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            span: Span::default(),
+        }
+    }
+}
+
+/// Obsidian 위키링크 노드 (`[[Page]]`, `[[Page|alias]]`, `[[Page#Heading]]`, `[[Page#^block]]`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct WikiLinkNode {
+    /// 대상 페이지 (`[[#Heading]]`처럼 같은 페이지를 가리키면 빈 문자열)
+    pub target: String,
+    /// `#` 뒤의 heading
+    pub heading: Option<String>,
+    /// `#^` 뒤의 block id
+    pub block_ref: Option<String>,
+    /// `|` 뒤의 표시 텍스트
+    pub alias: Option<String>,
+    pub span: Span,
+}
+
+impl Node for WikiLinkNode {}
+
+impl WikiLinkNode {
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+            heading: None,
+            block_ref: None,
+            alias: None,
+            span: Span::default(),
+        }
+    }
+
+    /// heading을 지정한 노드 반환
+    pub fn with_heading(self, heading: &str) -> Self {
+        Self {
+            heading: Some(heading.to_string()),
+            ..self
+        }
+    }
+
+    /// block id를 지정한 노드 반환
+    pub fn with_block_ref(self, block_ref: &str) -> Self {
+        Self {
+            block_ref: Some(block_ref.to_string()),
+            ..self
+        }
+    }
+
+    /// 표시 텍스트를 지정한 노드 반환
+    pub fn with_alias(self, alias: &str) -> Self {
+        Self {
+            alias: Some(alias.to_string()),
+            ..self
+        }
+    }
+
+    /// 표시 텍스트: alias가 없으면 Obsidian처럼 `Page > Heading`, `Page > ^block`
+    pub fn display_text(&self) -> String {
+        display_text(&self.target, self.heading.as_deref(), self.block_ref.as_deref(), self.alias.as_deref())
+    }
+}
+
+/// 위키링크/임베드 표시 텍스트 (alias, 없으면 대상과 fragment를 ` > `로 연결)
+fn display_text(target: &str, heading: Option<&str>, block_ref: Option<&str>, alias: Option<&str>) -> String {
+    if let Some(alias) = alias {
+        return alias.to_string();
+    }
+    let block_ref = block_ref.map(|id| format!("^{}", id));
+    [Some(target), heading, block_ref.as_deref()]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" > ")
+}
+
+/// 임베드로 바로 보여 줄 이미지 확장자
+const IMAGE_EXTENSIONS: [&str; 8] = ["png", "jpg", "jpeg", "gif", "bmp", "svg", "webp", "avif"];
+
+/// Obsidian 임베드 노드 (`![[note]]`, `![[note#Heading]]`, `![[image.png|300]]`, `![[image.png|300x200]]`)
+///
+/// `content`는 파싱 직후에는 비어 있고, 트랜스클루전 단계(`transclude`)에서 불러온 노트의 블록으로 채워집니다.
+/// 불러온 블록의 위치(span)는 그 노트 원문 기준입니다.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct EmbedNode {
+    /// 대상 노트 또는 파일 (`![[#Heading]]`처럼 같은 노트를 가리키면 빈 문자열)
+    pub target: String,
+    /// `#` 뒤의 heading
+    pub heading: Option<String>,
+    /// `#^` 뒤의 block id
+    pub block_ref: Option<String>,
+    /// `|` 뒤가 크기가 아닐 때의 표시 텍스트
+    pub alias: Option<String>,
+    /// `|300` 또는 `|300x200`의 너비
+    pub width: Option<u32>,
+    /// `|300x200`의 높이
+    pub height: Option<u32>,
+    /// 트랜스클루전으로 불러온 블록 (노트 전체, heading 아래 구역, 또는 블록 하나)
+    pub content: Option<Vec<BlockNode>>,
+    pub span: Span,
+}
+
+impl Node for EmbedNode {}
+
+impl EmbedNode {
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+            heading: None,
+            block_ref: None,
+            alias: None,
+            width: None,
+            height: None,
+            content: None,
+            span: Span::default(),
+        }
+    }
+
+    /// heading을 지정한 노드 반환
+    pub fn with_heading(self, heading: &str) -> Self {
+        Self {
+            heading: Some(heading.to_string()),
+            ..self
+        }
+    }
+
+    /// block id를 지정한 노드 반환
+    pub fn with_block_ref(self, block_ref: &str) -> Self {
+        Self {
+            block_ref: Some(block_ref.to_string()),
+            ..self
+        }
+    }
+
+    /// 표시 텍스트를 지정한 노드 반환
+    pub fn with_alias(self, alias: &str) -> Self {
+        Self {
+            alias: Some(alias.to_string()),
+            ..self
+        }
+    }
+
+    /// 크기를 지정한 노드 반환
+    pub fn with_size(self, width: u32, height: Option<u32>) -> Self {
+        Self {
+            width: Some(width),
+            height,
+            ..self
+        }
+    }
+
+    /// 불러온 블록을 채운 노드 반환
+    pub fn with_content(self, content: Vec<BlockNode>) -> Self {
+        Self {
+            content: Some(content),
+            ..self
+        }
+    }
+
+    /// 표시 텍스트: 위키링크와 같은 규칙
+    pub fn display_text(&self) -> String {
+        display_text(&self.target, self.heading.as_deref(), self.block_ref.as_deref(), self.alias.as_deref())
+    }
+
+    /// 대상이 이미지 파일인지 (확장자로 판단, 대소문자 무시)
+    pub fn is_image(&self) -> bool {
+        self.target
+            .rsplit_once('.')
+            .is_some_and(|(_, extension)| IMAGE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str()))
+    }
+}
+
+/// Obsidian 태그 노드 (`#tag`, `#nested/tag`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TagNode {
+    /// 태그 이름 (`#` 제외, 계층은 `/`로 구분)
+    pub name: String,
+    pub span: Span,
+}
+
+impl Node for TagNode {}
+
+impl TagNode {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            span: Span::default(),
+        }
+    }
+}
+
+/// Obsidian 주석 노드 (인라인 `%%note%%`, 블록 `%%` … `%%`)
+///
+/// 인라인(`InlineNode::Comment`)과 블록(`BlockNode::Comment`)이 함께 씁니다.
+/// 내용은 파싱하지 않은 원문이며, 렌더러는 기본적으로 출력하지 않습니다.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CommentNode {
+    /// `%%` 사이의 원문 (블록이면 줄 사이는 `\n`)
+    pub content: String,
+    pub span: Span,
+}
+
+impl Node for CommentNode {}
+
+impl CommentNode {
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            span: Span::default(),
+        }
+    }
+}
+
+/// 각주 참조 노드 (`[^label]`, Obsidian 인라인 각주 `^[text]`)
+///
+/// `index`와 `occurrence`는 파싱이 끝난 뒤 문서 전체의 각주 번호 매기기 단계에서 채워집니다.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FootnoteReferenceNode {
+    /// 참조 label (`^` 제외, 인라인 각주는 빈 문자열)
+    pub label: String,
+    /// 인라인 각주 내용 (`[^label]` 참조면 None)
+    pub inline: Option<Vec<InlineNode>>,
+    /// 각주 번호 (처음 참조된 순서로 1부터), 정의되지 않은 각주면 None
+    pub index: Option<usize>,
+    /// 같은 각주에 대한 몇 번째 참조인지 (1부터, 정의되지 않은 각주면 0)
+    pub occurrence: usize,
+    pub span: Span,
+}
+
+impl Node for FootnoteReferenceNode {}
+
+impl FootnoteReferenceNode {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            inline: None,
+            index: None,
+            occurrence: 0,
+            span: Span::default(),
+        }
+    }
+
+    /// 인라인 각주 생성
+    pub fn inline(children: Vec<InlineNode>) -> Self {
+        Self {
+            inline: Some(children),
+            ..Self::new("")
+        }
+    }
+
+    /// 번호를 지정한 노드 반환
+    pub fn numbered(self, index: usize, occurrence: usize) -> Self {
+        Self {
+            index: Some(index),
+            occurrence,
+            ..self
+        }
+    }
+}
+
+/// Soft line break 노드 (일반 줄바꿈)
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct SoftBreakNode {
+    pub span: Span,
+}
+
+impl Node for SoftBreakNode {}
+
+impl SoftBreakNode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Hard line break 노드 (줄 끝 공백 2개 이상 또는 백슬래시)
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct HardBreakNode {
+    pub span: Span,
+}
+
+impl Node for HardBreakNode {}
+
+impl HardBreakNode {
+    pub fn new() -> Self {
+        Self::default()
     }
 }
 
 /// 인라인 노드 enum
+///
+/// 새 인라인 요소가 계속 추가되므로 `#[non_exhaustive]`로 선언합니다.
+/// 외부 크레이트에서 match할 때는 `_` 분기가 필요합니다.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum InlineNode {
     Text(TextNode),
-    // 향후: Emphasis, Strong, CodeSpan, Link, Image 등
+    Emphasis(EmphasisNode),
+    Strong(StrongNode),
+    Strikethrough(StrikethroughNode),
+    Highlight(HighlightNode),
+    CodeSpan(CodeSpanNode),
+    InlineMath(InlineMathNode),
+    Link(LinkNode),
+    Image(ImageNode),
+    WikiLink(WikiLinkNode),
+    Embed(EmbedNode),
+    Tag(TagNode),
+    Comment(CommentNode),
+    Autolink(AutolinkNode),
+    RawHtml(RawHtmlNode),
+    FootnoteReference(FootnoteReferenceNode),
+    SoftBreak(SoftBreakNode),
+    HardBreak(HardBreakNode),
 }
 
 impl Node for InlineNode {}
 
 impl InlineNode {
-    #[cfg(test)]
+    /// Text 노드 생성
     pub fn text(s: &str) -> Self {
         InlineNode::Text(TextNode::new(s))
     }
+
+    /// Emphasis 노드 생성
+    pub fn emphasis(children: Vec<InlineNode>) -> Self {
+        InlineNode::Emphasis(EmphasisNode::new(children))
+    }
+
+    /// Strong 노드 생성
+    pub fn strong(children: Vec<InlineNode>) -> Self {
+        InlineNode::Strong(StrongNode::new(children))
+    }
+
+    /// Strikethrough 노드 생성
+    pub fn strikethrough(children: Vec<InlineNode>) -> Self {
+        InlineNode::Strikethrough(StrikethroughNode::new(children))
+    }
+
+    /// Highlight 노드 생성
+    pub fn highlight(children: Vec<InlineNode>) -> Self {
+        InlineNode::Highlight(HighlightNode::new(children))
+    }
+
+    /// CodeSpan 노드 생성
+    pub fn code_span(content: &str) -> Self {
+        InlineNode::CodeSpan(CodeSpanNode::new(content))
+    }
+
+    /// InlineMath 노드 생성 (`$content$`)
+    pub fn inline_math(content: &str) -> Self {
+        InlineNode::InlineMath(InlineMathNode::new(content))
+    }
+
+    /// Link 노드 생성
+    pub fn link(destination: &str, title: Option<&str>, children: Vec<InlineNode>) -> Self {
+        InlineNode::Link(LinkNode::new(destination, title, children))
+    }
+
+    /// Image 노드 생성
+    pub fn image(destination: &str, title: Option<&str>, children: Vec<InlineNode>) -> Self {
+        InlineNode::Image(ImageNode::new(destination, title, children))
+    }
+
+    /// Autolink 노드 생성
+    pub fn autolink(url: &str, email: bool) -> Self {
+        InlineNode::Autolink(AutolinkNode::new(url, email))
+    }
+
+    /// GFM extended autolink 노드 생성
+    pub fn extended_autolink(url: &str, email: bool) -> Self {
+        InlineNode::Autolink(AutolinkNode::extended(url, email))
+    }
+
+    /// RawHtml 노드 생성
+    pub fn raw_html(content: &str) -> Self {
+        InlineNode::RawHtml(RawHtmlNode::new(content))
+    }
+
+    /// WikiLink 노드 생성 (`[[target]]`)
+    pub fn wikilink(target: &str) -> Self {
+        InlineNode::WikiLink(WikiLinkNode::new(target))
+    }
+
+    /// Embed 노드 생성 (`![[target]]`)
+    pub fn embed(target: &str) -> Self {
+        InlineNode::Embed(EmbedNode::new(target))
+    }
+
+    /// Tag 노드 생성 (`#name`)
+    pub fn tag(name: &str) -> Self {
+        InlineNode::Tag(TagNode::new(name))
+    }
+
+    /// Comment 노드 생성 (`%%content%%`)
+    pub fn comment(content: &str) -> Self {
+        InlineNode::Comment(CommentNode::new(content))
+    }
+
+    /// FootnoteReference 노드 생성 (`[^label]`)
+    pub fn footnote_reference(label: &str) -> Self {
+        InlineNode::FootnoteReference(FootnoteReferenceNode::new(label))
+    }
+
+    /// 인라인 각주 노드 생성 (`^[text]`)
+    pub fn inline_footnote(children: Vec<InlineNode>) -> Self {
+        InlineNode::FootnoteReference(FootnoteReferenceNode::inline(children))
+    }
+
+    /// SoftBreak 노드 생성
+    pub fn soft_break() -> Self {
+        InlineNode::SoftBreak(SoftBreakNode::new())
+    }
+
+    /// HardBreak 노드 생성
+    pub fn hard_break() -> Self {
+        InlineNode::HardBreak(HardBreakNode::new())
+    }
+
+    /// 원문 범위
+    pub fn span(&self) -> Span {
+        match self {
+            InlineNode::Text(node) => node.span,
+            InlineNode::Emphasis(node) => node.span,
+            InlineNode::Strong(node) => node.span,
+            InlineNode::Strikethrough(node) => node.span,
+            InlineNode::Highlight(node) => node.span,
+            InlineNode::CodeSpan(node) => node.span,
+            InlineNode::InlineMath(node) => node.span,
+            InlineNode::Link(node) => node.span,
+            InlineNode::Image(node) => node.span,
+            InlineNode::WikiLink(node) => node.span,
+            InlineNode::Embed(node) => node.span,
+            InlineNode::Tag(node) => node.span,
+            InlineNode::Comment(node) => node.span,
+            InlineNode::Autolink(node) => node.span,
+            InlineNode::RawHtml(node) => node.span,
+            InlineNode::FootnoteReference(node) => node.span,
+            InlineNode::SoftBreak(node) => node.span,
+            InlineNode::HardBreak(node) => node.span,
+        }
+    }
+
+    /// 원문 범위 (수정용)
+    pub fn span_mut(&mut self) -> &mut Span {
+        match self {
+            InlineNode::Text(node) => &mut node.span,
+            InlineNode::Emphasis(node) => &mut node.span,
+            InlineNode::Strong(node) => &mut node.span,
+            InlineNode::Strikethrough(node) => &mut node.span,
+            InlineNode::Highlight(node) => &mut node.span,
+            InlineNode::CodeSpan(node) => &mut node.span,
+            InlineNode::InlineMath(node) => &mut node.span,
+            InlineNode::Link(node) => &mut node.span,
+            InlineNode::Image(node) => &mut node.span,
+            InlineNode::WikiLink(node) => &mut node.span,
+            InlineNode::Embed(node) => &mut node.span,
+            InlineNode::Tag(node) => &mut node.span,
+            InlineNode::Comment(node) => &mut node.span,
+            InlineNode::Autolink(node) => &mut node.span,
+            InlineNode::RawHtml(node) => &mut node.span,
+            InlineNode::FootnoteReference(node) => &mut node.span,
+            InlineNode::SoftBreak(node) => &mut node.span,
+            InlineNode::HardBreak(node) => &mut node.span,
+        }
+    }
+
+    /// 원문 범위를 지정한 노드 반환
+    pub fn with_span(mut self, span: Span) -> Self {
+        *self.span_mut() = span;
+        self
+    }
+
+    /// 하위 노드까지 원문 위치를 지운 노드 반환 (위치를 빼고 트리 구조만 비교할 때)
+    pub fn without_spans(mut self) -> Self {
+        visit::visit_inline(&mut self, &mut |span| *span = Span::default());
+        self
+    }
 }
 
 // =============================================================================
@@ -55,44 +807,58 @@ impl InlineNode {
 // =============================================================================
 
 /// Thematic Break 노드 (수평선)
-#[derive(Debug, Clone, PartialEq)]
-pub struct ThematicBreakNode;
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ThematicBreakNode {
+    pub span: Span,
+}
 
 impl Node for ThematicBreakNode {}
 
+impl ThematicBreakNode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 /// ATX Heading 노드
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct HeadingNode {
     pub level: u8,
     pub children: Vec<InlineNode>,
+    pub span: Span,
 }
 
 impl Node for HeadingNode {}
 
 impl HeadingNode {
     pub fn new(level: u8, children: Vec<InlineNode>) -> Self {
-        Self { level, children }
+        Self { level, children, span: Span::default() }
     }
 }
 
 /// Code Block 노드 (fenced 또는 indented)
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct CodeBlockNode {
     pub info: Option<String>,
     pub content: String,
+    pub span: Span,
 }
 
 impl Node for CodeBlockNode {}
 
 impl CodeBlockNode {
     pub fn new(info: Option<String>, content: String) -> Self {
-        Self { info, content }
+        Self { info, content, span: Span::default() }
     }
 
     pub fn fenced(info: Option<&str>, content: &str) -> Self {
         Self {
             info: info.map(|s| s.to_string()),
             content: content.to_string(),
+            span: Span::default(),
         }
     }
 
@@ -100,21 +866,179 @@ impl CodeBlockNode {
         Self {
             info: None,
             content: content.to_string(),
+            span: Span::default(),
+        }
+    }
+}
+
+/// 수식 블록 노드 (`$$` … `$$`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct MathBlockNode {
+    /// `$$` 사이의 TeX 원문 (줄 사이는 `\n`)
+    pub content: String,
+    pub span: Span,
+}
+
+impl Node for MathBlockNode {}
+
+impl MathBlockNode {
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            span: Span::default(),
+        }
+    }
+}
+
+/// HTML Block 노드 (원문 그대로 출력되는 HTML 줄들)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct HtmlBlockNode {
+    /// 블록 원문 (들여쓰기 포함, 줄 사이는 `\n`)
+    pub content: String,
+    pub span: Span,
+}
+
+impl Node for HtmlBlockNode {}
+
+impl HtmlBlockNode {
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            span: Span::default(),
         }
     }
 }
 
 /// Paragraph 노드
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct ParagraphNode {
     pub children: Vec<InlineNode>,
+    /// Obsidian block id (`^id`의 `^` 뒤)
+    pub block_id: Option<String>,
+    pub span: Span,
 }
 
 impl Node for ParagraphNode {}
 
 impl ParagraphNode {
     pub fn new(children: Vec<InlineNode>) -> Self {
-        Self { children }
+        Self { children, block_id: None, span: Span::default() }
+    }
+
+    /// block id를 지정한 노드 반환
+    pub fn with_block_id(self, block_id: &str) -> Self {
+        Self {
+            block_id: Some(block_id.to_string()),
+            ..self
+        }
+    }
+}
+
+/// Link Reference Definition 노드 (`[label]: /url "title"`)
+///
+/// 출력되는 요소는 아니지만 원문 보존을 위해 트리에 남깁니다.
+/// 참조 링크는 인라인 파싱 시 문서 전체의 정의로 해석됩니다.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LinkReferenceDefinitionNode {
+    /// 원문 label (정규화 전)
+    pub label: String,
+    pub destination: String,
+    pub title: Option<String>,
+    pub span: Span,
+}
+
+impl Node for LinkReferenceDefinitionNode {}
+
+impl LinkReferenceDefinitionNode {
+    pub fn new(label: &str, destination: &str, title: Option<&str>) -> Self {
+        Self {
+            label: label.to_string(),
+            destination: destination.to_string(),
+            title: title.map(|s| s.to_string()),
+            span: Span::default(),
+        }
+    }
+}
+
+/// 표 열 정렬 (구분 행의 `:` 위치)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TableAlignment {
+    /// 정렬 지정 없음 (`---`)
+    #[default]
+    None,
+    /// 왼쪽 정렬 (`:---`)
+    Left,
+    /// 가운데 정렬 (`:---:`)
+    Center,
+    /// 오른쪽 정렬 (`---:`)
+    Right,
+}
+
+/// 표 셀 노드
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TableCellNode {
+    pub children: Vec<InlineNode>,
+    pub span: Span,
+}
+
+impl Node for TableCellNode {}
+
+impl TableCellNode {
+    pub fn new(children: Vec<InlineNode>) -> Self {
+        Self { children, span: Span::default() }
+    }
+}
+
+/// 표 행 노드
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TableRowNode {
+    pub cells: Vec<TableCellNode>,
+    pub span: Span,
+}
+
+impl Node for TableRowNode {}
+
+impl TableRowNode {
+    pub fn new(cells: Vec<TableCellNode>) -> Self {
+        Self { cells, span: Span::default() }
+    }
+}
+
+/// 표 노드 (GFM)
+///
+/// 모든 행의 셀 수는 열 수(`alignments`의 길이)와 같습니다.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TableNode {
+    /// 열마다 정렬
+    pub alignments: Vec<TableAlignment>,
+    pub header: TableRowNode,
+    /// 본문 행 (없을 수 있음)
+    pub rows: Vec<TableRowNode>,
+    /// Obsidian block id (`^id`의 `^` 뒤)
+    pub block_id: Option<String>,
+    pub span: Span,
+}
+
+impl Node for TableNode {}
+
+impl TableNode {
+    pub fn new(alignments: Vec<TableAlignment>, header: TableRowNode, rows: Vec<TableRowNode>) -> Self {
+        Self { alignments, header, rows, block_id: None, span: Span::default() }
+    }
+
+    /// block id를 지정한 노드 반환
+    pub fn with_block_id(self, block_id: &str) -> Self {
+        Self {
+            block_id: Some(block_id.to_string()),
+            ..self
+        }
     }
 }
 
@@ -124,20 +1048,80 @@ impl ParagraphNode {
 
 /// Blockquote 노드
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct BlockquoteNode {
     pub children: Vec<BlockNode>,
+    /// Obsidian block id (`^id`의 `^` 뒤)
+    pub block_id: Option<String>,
+    pub span: Span,
 }
 
 impl Node for BlockquoteNode {}
 
 impl BlockquoteNode {
     pub fn new(children: Vec<BlockNode>) -> Self {
-        Self { children }
+        Self { children, block_id: None, span: Span::default() }
+    }
+
+    /// block id를 지정한 노드 반환
+    pub fn with_block_id(self, block_id: &str) -> Self {
+        Self {
+            block_id: Some(block_id.to_string()),
+            ..self
+        }
+    }
+}
+
+/// Obsidian Callout 노드 (첫 줄이 `[!type]`인 Blockquote, `> [!warning]- Title`)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CalloutNode {
+    /// callout 종류 (`[!` `]` 사이, 소문자)
+    pub kind: String,
+    /// 제목 (비어 있으면 렌더러가 종류 이름을 제목으로 씀)
+    pub title: Vec<InlineNode>,
+    /// 접기 상태: None이면 접을 수 없음, `+`면 Some(false) (펼침), `-`면 Some(true) (접힘)
+    pub folded: Option<bool>,
+    pub children: Vec<BlockNode>,
+    /// Obsidian block id (`^id`의 `^` 뒤)
+    pub block_id: Option<String>,
+    pub span: Span,
+}
+
+impl Node for CalloutNode {}
+
+impl CalloutNode {
+    pub fn new(kind: &str, title: Vec<InlineNode>, children: Vec<BlockNode>) -> Self {
+        Self {
+            kind: kind.to_string(),
+            title,
+            folded: None,
+            children,
+            block_id: None,
+            span: Span::default(),
+        }
+    }
+
+    /// 접기 상태를 지정한 노드 반환
+    pub fn with_folded(self, folded: bool) -> Self {
+        Self {
+            folded: Some(folded),
+            ..self
+        }
+    }
+
+    /// block id를 지정한 노드 반환
+    pub fn with_block_id(self, block_id: &str) -> Self {
+        Self {
+            block_id: Some(block_id.to_string()),
+            ..self
+        }
     }
 }
 
 /// 리스트 타입
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ListType {
     /// Bullet 리스트 (-, +, *)
     Bullet,
@@ -150,18 +1134,20 @@ pub enum ListType {
 
 /// List 노드
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct ListNode {
     pub list_type: ListType,
     pub start: usize,
     pub tight: bool,
     pub children: Vec<ListItemNode>,
+    pub span: Span,
 }
 
 impl Node for ListNode {}
 
 impl ListNode {
     pub fn new(list_type: ListType, start: usize, tight: bool, children: Vec<ListItemNode>) -> Self {
-        Self { list_type, start, tight, children }
+        Self { list_type, start, tight, children, span: Span::default() }
     }
 
     pub fn bullet(tight: bool, children: Vec<ListItemNode>) -> Self {
@@ -170,6 +1156,7 @@ impl ListNode {
             start: 1,
             tight,
             children,
+            span: Span::default(),
         }
     }
 
@@ -179,35 +1166,224 @@ impl ListNode {
             start,
             tight,
             children,
+            span: Span::default(),
         }
     }
 }
 
 /// List Item 노드
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct ListItemNode {
     pub children: Vec<BlockNode>,
+    /// Task list 체크 상태 (GFM `[ ]`, `[x]`), task 아이템이 아니면 None
+    pub checked: Option<bool>,
+    /// Obsidian block id (`^id`의 `^` 뒤)
+    pub block_id: Option<String>,
+    pub span: Span,
 }
 
 impl Node for ListItemNode {}
 
 impl ListItemNode {
     pub fn new(children: Vec<BlockNode>) -> Self {
-        Self { children }
+        Self { children, checked: None, block_id: None, span: Span::default() }
+    }
+
+    /// Task list 아이템 생성
+    pub fn task(checked: bool, children: Vec<BlockNode>) -> Self {
+        Self { children, checked: Some(checked), block_id: None, span: Span::default() }
+    }
+
+    /// block id를 지정한 노드 반환
+    pub fn with_block_id(self, block_id: &str) -> Self {
+        Self {
+            block_id: Some(block_id.to_string()),
+            ..self
+        }
+    }
+}
+
+/// 각주 정의 노드 (`[^label]: text`, 4칸 들여쓴 줄이 이어짐)
+///
+/// `index`와 `ref_count`는 파싱이 끝난 뒤 문서 전체의 각주 번호 매기기 단계에서 채워집니다.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FootnoteDefinitionNode {
+    /// 정의 label (`^` 제외)
+    pub label: String,
+    pub children: Vec<BlockNode>,
+    /// 각주 번호, 참조되지 않았거나 같은 label의 앞선 정의가 있으면 None
+    pub index: Option<usize>,
+    /// 이 각주를 참조한 횟수
+    pub ref_count: usize,
+    pub span: Span,
+}
+
+impl Node for FootnoteDefinitionNode {}
+
+impl FootnoteDefinitionNode {
+    pub fn new(label: &str, children: Vec<BlockNode>) -> Self {
+        Self {
+            label: label.to_string(),
+            children,
+            index: None,
+            ref_count: 0,
+            span: Span::default(),
+        }
+    }
+
+    /// 번호를 지정한 노드 반환
+    pub fn numbered(self, index: usize, ref_count: usize) -> Self {
+        Self {
+            index: Some(index),
+            ref_count,
+            ..self
+        }
     }
 }
 
 /// Document 노드 (최상위 컨테이너)
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct DocumentNode {
     pub children: Vec<BlockNode>,
+    /// 문서 맨 앞의 YAML frontmatter
+    pub frontmatter: Option<Frontmatter>,
+    pub span: Span,
 }
 
 impl Node for DocumentNode {}
 
 impl DocumentNode {
     pub fn new(children: Vec<BlockNode>) -> Self {
-        Self { children }
+        Self {
+            children,
+            frontmatter: None,
+            span: Span::default(),
+        }
+    }
+
+    /// frontmatter를 지정한 노드 반환
+    pub fn with_frontmatter(self, frontmatter: Frontmatter) -> Self {
+        Self {
+            frontmatter: Some(frontmatter),
+            ..self
+        }
+    }
+
+    /// 모든 노드의 원문 위치를 지운 문서 반환 (위치를 빼고 트리 구조만 비교할 때)
+    pub fn without_spans(mut self) -> Self {
+        visit::visit_document(&mut self, &mut |span| *span = Span::default());
+        self
+    }
+}
+
+/// YAML frontmatter (문서 첫 줄의 `---`부터 닫는 `---` 또는 `...`까지)
+///
+/// 블록 트리에는 들어가지 않으며 렌더러도 출력하지 않습니다.
+/// span은 여는 구분선부터 닫는 구분선까지입니다.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Frontmatter {
+    /// 구분선 사이의 원문 (마지막 줄 끝 제외)
+    pub raw: String,
+    /// key/value 트리로 파싱한 속성 (`properties` feature)
+    #[cfg(feature = "properties")]
+    pub properties: Properties,
+    pub span: Span,
+}
+
+impl Frontmatter {
+    pub fn new(raw: &str) -> Self {
+        Self {
+            raw: raw.to_string(),
+            #[cfg(feature = "properties")]
+            properties: properties::parse(raw),
+            span: Span::default(),
+        }
+    }
+}
+
+/// frontmatter 속성 (최상위 key/value, 원문 순서)
+///
+/// Obsidian이 쓰는 `tags`, `aliases`, `cssclasses`는 목록(`[a, b]`, `- a`)과
+/// 구분자로 나눈 문자열(`a, b`) 형식을 모두 받아 문자열 목록으로 돌려줍니다.
+#[cfg(feature = "properties")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Properties {
+    pub entries: Vec<(String, PropertyValue)>,
+}
+
+#[cfg(feature = "properties")]
+impl Properties {
+    pub fn new(entries: Vec<(String, PropertyValue)>) -> Self {
+        Self { entries }
+    }
+
+    /// key의 값 (같은 key가 여럿이면 마지막 값)
+    pub fn get(&self, key: &str) -> Option<&PropertyValue> {
+        self.entries.iter().rev().find(|(k, _)| k == key).map(|(_, value)| value)
+    }
+
+    /// `tags` (예전 이름 `tag` 포함), 앞의 `#`은 제거
+    pub fn tags(&self) -> Vec<String> {
+        self.strings(&["tags", "tag"], &[',', ' '])
+            .into_iter()
+            .map(|tag| tag.trim_start_matches('#').to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect()
+    }
+
+    /// `aliases` (예전 이름 `alias` 포함), 문자열이면 쉼표로 나눔
+    pub fn aliases(&self) -> Vec<String> {
+        self.strings(&["aliases", "alias"], &[','])
+    }
+
+    /// `cssclasses` (예전 이름 `cssclass` 포함)
+    pub fn cssclasses(&self) -> Vec<String> {
+        self.strings(&["cssclasses", "cssclass"], &[',', ' '])
+    }
+
+    /// 여러 key의 값을 문자열 목록으로 (목록은 문자열 항목만, 문자열은 separators로 나눔)
+    fn strings(&self, keys: &[&str], separators: &[char]) -> Vec<String> {
+        keys.iter()
+            .filter_map(|key| self.get(key))
+            .flat_map(|value| match value {
+                PropertyValue::List(items) => {
+                    items.iter().filter_map(PropertyValue::as_str).map(str::to_string).collect()
+                }
+                PropertyValue::String(s) => s.split(separators).map(|part| part.trim().to_string()).collect(),
+                _ => Vec::new(),
+            })
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+}
+
+/// frontmatter 속성 값 (YAML 부분 집합)
+#[cfg(feature = "properties")]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PropertyValue {
+    /// 빈 값, `null`, `~`
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    List(Vec<PropertyValue>),
+    /// 들여쓴 하위 key/value (원문 순서)
+    Map(Vec<(String, PropertyValue)>),
+}
+
+#[cfg(feature = "properties")]
+impl PropertyValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::String(s) => Some(s),
+            _ => None,
+        }
     }
 }
 
@@ -216,60 +1392,157 @@ impl DocumentNode {
 // =============================================================================
 
 /// 블록 노드 enum
+///
+/// 새 블록 요소가 계속 추가되므로 `#[non_exhaustive]`로 선언합니다.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum BlockNode {
     ThematicBreak(ThematicBreakNode),
     Heading(HeadingNode),
     CodeBlock(CodeBlockNode),
+    MathBlock(MathBlockNode),
+    HtmlBlock(HtmlBlockNode),
+    Comment(CommentNode),
     Paragraph(ParagraphNode),
     Blockquote(BlockquoteNode),
+    Callout(CalloutNode),
     List(ListNode),
     ListItem(ListItemNode),
+    LinkReferenceDefinition(LinkReferenceDefinitionNode),
+    Table(TableNode),
+    FootnoteDefinition(FootnoteDefinitionNode),
 }
 
 impl Node for BlockNode {}
 
 impl BlockNode {
-    // 테스트용 빌더 메서드
-    #[cfg(test)]
+    // 빌더 메서드: 노드 struct를 생성해 enum variant로 감싸 반환
+    /// Thematic Break 블록 생성
     pub fn thematic_break() -> Self {
-        BlockNode::ThematicBreak(ThematicBreakNode)
+        BlockNode::ThematicBreak(ThematicBreakNode::new())
     }
 
-    #[cfg(test)]
+    /// Heading 블록 생성
     pub fn heading(level: u8, children: Vec<InlineNode>) -> Self {
         BlockNode::Heading(HeadingNode::new(level, children))
     }
 
-    #[cfg(test)]
+    /// Code Block 블록 생성 (info가 없으면 indented와 동일)
     pub fn code_block(info: Option<&str>, content: &str) -> Self {
         BlockNode::CodeBlock(CodeBlockNode::fenced(info, content))
     }
 
-    #[cfg(test)]
+    /// Math Block 블록 생성 (`$$` … `$$`)
+    pub fn math_block(content: &str) -> Self {
+        BlockNode::MathBlock(MathBlockNode::new(content))
+    }
+
+    /// HTML Block 블록 생성
+    pub fn html_block(content: &str) -> Self {
+        BlockNode::HtmlBlock(HtmlBlockNode::new(content))
+    }
+
+    /// Comment 블록 생성 (`%%` … `%%`)
+    pub fn comment(content: &str) -> Self {
+        BlockNode::Comment(CommentNode::new(content))
+    }
+
+    /// Paragraph 블록 생성
     pub fn paragraph(children: Vec<InlineNode>) -> Self {
         BlockNode::Paragraph(ParagraphNode::new(children))
     }
 
-    #[cfg(test)]
+    /// Blockquote 블록 생성
     pub fn blockquote(children: Vec<BlockNode>) -> Self {
         BlockNode::Blockquote(BlockquoteNode::new(children))
     }
 
-    #[cfg(test)]
+    /// Callout 블록 생성
+    pub fn callout(kind: &str, title: Vec<InlineNode>, children: Vec<BlockNode>) -> Self {
+        BlockNode::Callout(CalloutNode::new(kind, title, children))
+    }
+
+    /// Bullet List 블록 생성
     pub fn bullet_list(tight: bool, children: Vec<ListItemNode>) -> Self {
         BlockNode::List(ListNode::bullet(tight, children))
     }
 
-    #[cfg(test)]
+    /// Ordered List 블록 생성
     pub fn ordered_list(delimiter: char, start: usize, tight: bool, children: Vec<ListItemNode>) -> Self {
         BlockNode::List(ListNode::ordered(delimiter, start, tight, children))
     }
 
-    #[cfg(test)]
+    /// List Item 블록 생성
     pub fn list_item(children: Vec<BlockNode>) -> Self {
         BlockNode::ListItem(ListItemNode::new(children))
     }
+
+    /// Link Reference Definition 블록 생성
+    pub fn link_reference_definition(label: &str, destination: &str, title: Option<&str>) -> Self {
+        BlockNode::LinkReferenceDefinition(LinkReferenceDefinitionNode::new(label, destination, title))
+    }
+
+    /// Table 블록 생성
+    pub fn table(alignments: Vec<TableAlignment>, header: TableRowNode, rows: Vec<TableRowNode>) -> Self {
+        BlockNode::Table(TableNode::new(alignments, header, rows))
+    }
+
+    /// Footnote Definition 블록 생성
+    pub fn footnote_definition(label: &str, children: Vec<BlockNode>) -> Self {
+        BlockNode::FootnoteDefinition(FootnoteDefinitionNode::new(label, children))
+    }
+
+    /// 원문 범위
+    pub fn span(&self) -> Span {
+        match self {
+            BlockNode::ThematicBreak(node) => node.span,
+            BlockNode::Heading(node) => node.span,
+            BlockNode::CodeBlock(node) => node.span,
+            BlockNode::MathBlock(node) => node.span,
+            BlockNode::HtmlBlock(node) => node.span,
+            BlockNode::Comment(node) => node.span,
+            BlockNode::Paragraph(node) => node.span,
+            BlockNode::Blockquote(node) => node.span,
+            BlockNode::Callout(node) => node.span,
+            BlockNode::List(node) => node.span,
+            BlockNode::ListItem(node) => node.span,
+            BlockNode::LinkReferenceDefinition(node) => node.span,
+            BlockNode::Table(node) => node.span,
+            BlockNode::FootnoteDefinition(node) => node.span,
+        }
+    }
+
+    /// 원문 범위 (수정용)
+    pub fn span_mut(&mut self) -> &mut Span {
+        match self {
+            BlockNode::ThematicBreak(node) => &mut node.span,
+            BlockNode::Heading(node) => &mut node.span,
+            BlockNode::CodeBlock(node) => &mut node.span,
+            BlockNode::MathBlock(node) => &mut node.span,
+            BlockNode::HtmlBlock(node) => &mut node.span,
+            BlockNode::Comment(node) => &mut node.span,
+            BlockNode::Paragraph(node) => &mut node.span,
+            BlockNode::Blockquote(node) => &mut node.span,
+            BlockNode::Callout(node) => &mut node.span,
+            BlockNode::List(node) => &mut node.span,
+            BlockNode::ListItem(node) => &mut node.span,
+            BlockNode::LinkReferenceDefinition(node) => &mut node.span,
+            BlockNode::Table(node) => &mut node.span,
+            BlockNode::FootnoteDefinition(node) => &mut node.span,
+        }
+    }
+
+    /// 원문 범위를 지정한 노드 반환
+    pub fn with_span(mut self, span: Span) -> Self {
+        *self.span_mut() = span;
+        self
+    }
+
+    /// 하위 노드까지 원문 위치를 지운 노드 반환 (위치를 빼고 트리 구조만 비교할 때)
+    pub fn without_spans(mut self) -> Self {
+        visit::visit_block(&mut self, &mut |span| *span = Span::default());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -278,8 +1551,18 @@ mod tests {
 
     #[test]
     fn create_thematic_break() {
-        let node = ThematicBreakNode;
-        assert_eq!(format!("{:?}", node), "ThematicBreakNode");
+        let node = ThematicBreakNode::new();
+        assert_eq!(node.span.start, Position::default());
+        assert_eq!(node.span.end, Position::default());
+    }
+
+    #[test]
+    fn span_compared_in_node_comparison() {
+        let span = Span::new(Position::new(0, 1, 1), Position::new(3, 1, 4));
+        let node = InlineNode::text("foo").with_span(span);
+        assert_ne!(node, InlineNode::text("foo"));
+        assert_eq!(node.span().end, Position::new(3, 1, 4));
+        assert_eq!(node.without_spans(), InlineNode::text("foo"));
     }
 
     #[test]
@@ -0,0 +1,279 @@
+//! frontmatter 속성 파싱 (`properties` feature)
+//!
+//! Obsidian properties가 쓰는 YAML 부분 집합만 처리합니다.
+//! - `key: value` (스칼라: 문자열, 따옴표 문자열, 숫자, `true`/`false`, `null`/`~`/빈 값)
+//! - 흐름 목록 `key: [a, "b, c"]`
+//! - 블록 목록 (`key:` 다음 줄들의 `- item`, key와 같은 들여쓰기도 허용)
+//! - 들여쓴 하위 key/value (`key:` 다음 줄부터 더 들여쓴 `sub: value`)
+//!
+//! 빈 줄과 `#` 주석 줄은 건너뛰고, 해석할 수 없는 줄은 무시합니다 (오류 없음).
+//! 블록 스칼라(`|`, `>`), 흐름 map(`{}`), 목록 안의 map은 지원하지 않으며 문자열로 남습니다.
+
+use super::{Properties, PropertyValue};
+
+/// 들여쓰기와 앞뒤 공백을 뺀 내용
+struct YamlLine<'a> {
+    indent: usize,
+    text: &'a str,
+}
+
+/// frontmatter 원문을 key/value 트리로 파싱
+pub fn parse(raw: &str) -> Properties {
+    let lines: Vec<YamlLine> = raw
+        .lines()
+        .filter_map(|line| {
+            let text = line.trim_start_matches(' ');
+            let indent = line.len() - text.len();
+            let text = text.trim();
+            (!text.is_empty() && !text.starts_with('#')).then_some(YamlLine { indent, text })
+        })
+        .collect();
+    let mut pos = 0;
+    Properties::new(parse_map(&lines, &mut pos, 0))
+}
+
+/// indent 들여쓰기의 key/value들 (더 얕은 줄에서 멈춤)
+fn parse_map(lines: &[YamlLine], pos: &mut usize, indent: usize) -> Vec<(String, PropertyValue)> {
+    let mut entries = Vec::new();
+    while let Some(line) = lines.get(*pos) {
+        if line.indent < indent {
+            break;
+        }
+        *pos += 1;
+        // 어긋난 들여쓰기나 key가 없는 줄은 무시
+        if line.indent > indent {
+            continue;
+        }
+        let Some((key, rest)) = split_key(line.text) else {
+            continue;
+        };
+        let value = match rest.is_empty() {
+            true => parse_nested(lines, pos, indent),
+            false => parse_value(rest),
+        };
+        entries.push((key, value));
+    }
+    entries
+}
+
+/// `key:` 다음 줄들의 블록 목록이나 하위 map (없으면 Null)
+fn parse_nested(lines: &[YamlLine], pos: &mut usize, indent: usize) -> PropertyValue {
+    match lines.get(*pos) {
+        Some(next) if is_list_item(next.text) && next.indent >= indent => parse_list(lines, pos, next.indent),
+        Some(next) if next.indent > indent => PropertyValue::Map(parse_map(lines, pos, next.indent)),
+        _ => PropertyValue::Null,
+    }
+}
+
+/// indent 들여쓰기의 `- item`들
+fn parse_list(lines: &[YamlLine], pos: &mut usize, indent: usize) -> PropertyValue {
+    let mut items = Vec::new();
+    while let Some(line) = lines.get(*pos) {
+        if line.indent < indent || (line.indent == indent && !is_list_item(line.text)) {
+            break;
+        }
+        *pos += 1;
+        // 항목 아래 더 들여쓴 줄은 지원하지 않으므로 무시
+        if line.indent > indent {
+            continue;
+        }
+        items.push(parse_value(line.text[1..].trim_start()));
+    }
+    PropertyValue::List(items)
+}
+
+fn is_list_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// `key: value`를 key와 value 원문으로 (`key:`면 빈 value)
+fn split_key(text: &str) -> Option<(String, &str)> {
+    if is_list_item(text) {
+        return None;
+    }
+    let (key, rest) = match text.strip_suffix(':') {
+        Some(key) if !key.contains(": ") => (key, ""),
+        _ => text.split_once(": ")?,
+    };
+    let key = key.trim();
+    if key.is_empty() {
+        return None;
+    }
+    let key = match parse_quoted(key) {
+        Some((key, _)) => key,
+        None => key.to_string(),
+    };
+    Some((key, rest.trim()))
+}
+
+/// 한 줄 값 (흐름 목록 또는 스칼라)
+fn parse_value(text: &str) -> PropertyValue {
+    let text = strip_comment(text);
+    match text.strip_prefix('[').and_then(|inner| inner.strip_suffix(']')) {
+        Some(inner) => PropertyValue::List(split_flow(inner).into_iter().map(parse_scalar).collect()),
+        None => parse_scalar(text),
+    }
+}
+
+/// 스칼라 값
+fn parse_scalar(text: &str) -> PropertyValue {
+    if let Some((s, _)) = parse_quoted(text) {
+        return PropertyValue::String(s);
+    }
+    match text {
+        "" | "~" | "null" | "Null" | "NULL" => PropertyValue::Null,
+        "true" | "True" | "TRUE" => PropertyValue::Bool(true),
+        "false" | "False" | "FALSE" => PropertyValue::Bool(false),
+        _ => match parse_number(text) {
+            Some(n) => PropertyValue::Number(n),
+            None => PropertyValue::String(text.to_string()),
+        },
+    }
+}
+
+/// 숫자 (`inf`, `nan` 같은 단어는 문자열로 남김)
+fn parse_number(text: &str) -> Option<f64> {
+    let numeric = text.chars().any(|c| c.is_ascii_digit())
+        && text.chars().all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E'));
+    numeric.then(|| text.parse().ok()).flatten()
+}
+
+/// 따옴표 문자열과 닫는 따옴표 뒤 나머지
+/// 큰따옴표는 `\"`, `\\`, `\n`, `\t` 이스케이프, 작은따옴표는 `''`만 처리
+fn parse_quoted(text: &str) -> Option<(String, &str)> {
+    let quote = text.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let mut value = String::new();
+    let mut chars = text.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            ('"', '\\') => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, escaped)) => value.push(escaped),
+                None => return None,
+            },
+            ('\'', '\'') if text[i + 1..].starts_with('\'') => {
+                value.push('\'');
+                chars.next();
+            }
+            _ if c == quote => return Some((value, &text[i + 1..])),
+            _ => value.push(c),
+        }
+    }
+    None
+}
+
+/// 따옴표 밖의 ` #` 주석 제거
+fn strip_comment(text: &str) -> &str {
+    if let Some((_, rest)) = parse_quoted(text) {
+        return text[..text.len() - rest.len()].trim_end();
+    }
+    match text.find(" #") {
+        Some(i) if !text.starts_with('[') || text[..i].contains(']') => text[..i].trim_end(),
+        _ => text,
+    }
+}
+
+/// 흐름 목록 안쪽을 따옴표 밖의 쉼표로 나눔
+fn split_flow(inner: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, ',') => {
+                items.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(inner[start..].trim());
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn string(s: &str) -> PropertyValue {
+        PropertyValue::String(s.to_string())
+    }
+
+    fn list(items: &[&str]) -> PropertyValue {
+        PropertyValue::List(items.iter().map(|s| string(s)).collect())
+    }
+
+    #[rstest]
+    #[case("title: Hello world", string("Hello world"))]
+    #[case("title: \"a: b # c\"", string("a: b # c"))]
+    #[case("title: 'it''s'", string("it's"))]
+    #[case("title: \"tab\\there\"", string("tab\there"))]
+    #[case("title: plain # comment", string("plain"))]
+    #[case("title: url#anchor", string("url#anchor"))]
+    #[case("title:", PropertyValue::Null)]
+    #[case("title: ~", PropertyValue::Null)]
+    #[case("title: true", PropertyValue::Bool(true))]
+    #[case("title: 42", PropertyValue::Number(42.0))]
+    #[case("title: -1.5", PropertyValue::Number(-1.5))]
+    #[case("title: 2024-01-14", string("2024-01-14"))]
+    #[case("title: nan", string("nan"))]
+    #[case("title: [a, \"b, c\", 'd']", list(&["a", "b, c", "d"]))]
+    #[case("title: []", list(&[]))]
+    #[case("title: [a, b] # comment", list(&["a", "b"]))]
+    #[case("title:\n  - a\n  - \"b\"\n", list(&["a", "b"]))]
+    #[case("title:\n- a\n- b", list(&["a", "b"]))]
+    #[case("title:\n  - a\n    nested\n  - b", list(&["a", "b"]))]
+    fn test_parse_value(#[case] raw: &str, #[case] expected: PropertyValue) {
+        assert_eq!(parse(raw).get("title"), Some(&expected));
+    }
+
+    #[test]
+    fn test_parse_tree() {
+        let raw = "# comment\ntitle: Note\n\"quoted key\": 1\n\
+                   author:\n  name: Kim\n  links:\n    - a\nnext: x\n- stray";
+        let expected = vec![
+            ("title".to_string(), string("Note")),
+            ("quoted key".to_string(), PropertyValue::Number(1.0)),
+            (
+                "author".to_string(),
+                PropertyValue::Map(vec![
+                    ("name".to_string(), string("Kim")),
+                    ("links".to_string(), list(&["a"])),
+                ]),
+            ),
+            ("next".to_string(), string("x")),
+        ];
+        assert_eq!(parse(raw).entries, expected);
+    }
+
+    #[rstest]
+    #[case("tags: [a, '#b/c']", &["a", "b/c"])]
+    #[case("tags:\n  - a\n  - b", &["a", "b"])]
+    #[case("tags: a, b c", &["a", "b", "c"])]
+    #[case("tag: a", &["a"])]
+    #[case("tags: 1", &[])]
+    #[case("title: a", &[])]
+    fn test_tags(#[case] raw: &str, #[case] expected: &[&str]) {
+        assert_eq!(parse(raw).tags(), expected);
+    }
+
+    #[rstest]
+    #[case("aliases: [First Name, Other]", &["First Name", "Other"])]
+    #[case("aliases: First Name, Other", &["First Name", "Other"])]
+    #[case("alias: Only", &["Only"])]
+    fn test_aliases(#[case] raw: &str, #[case] expected: &[&str]) {
+        assert_eq!(parse(raw).aliases(), expected);
+    }
+
+    #[rstest]
+    #[case("cssclasses:\n  - wide\n  - dark", &["wide", "dark"])]
+    #[case("cssclass: wide dark", &["wide", "dark"])]
+    fn test_cssclasses(#[case] raw: &str, #[case] expected: &[&str]) {
+        assert_eq!(parse(raw).cssclasses(), expected);
+    }
+}
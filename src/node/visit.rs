@@ -0,0 +1,91 @@
+//! 노드 트리 순회
+//!
+//! 문서의 모든 노드(frontmatter, 트랜스클루전으로 불러온 내용 포함)를 돌며 `Span`마다 콜백을 호출합니다.
+//! 위치를 지우는 `without_spans`와 파서의 위치 보정(`source_map`)이 함께 씁니다.
+
+use super::{BlockNode, DocumentNode, InlineNode, Span, TableRowNode};
+
+pub(crate) fn visit_document(doc: &mut DocumentNode, f: &mut impl FnMut(&mut Span)) {
+    f(&mut doc.span);
+    if let Some(frontmatter) = &mut doc.frontmatter {
+        f(&mut frontmatter.span);
+    }
+    doc.children.iter_mut().for_each(|block| visit_block(block, f));
+}
+
+pub(crate) fn visit_block(block: &mut BlockNode, f: &mut impl FnMut(&mut Span)) {
+    f(block.span_mut());
+    match block {
+        BlockNode::Heading(node) => visit_inlines(&mut node.children, f),
+        BlockNode::Paragraph(node) => visit_inlines(&mut node.children, f),
+        BlockNode::Blockquote(node) => node.children.iter_mut().for_each(|child| visit_block(child, f)),
+        BlockNode::Callout(node) => {
+            visit_inlines(&mut node.title, f);
+            node.children.iter_mut().for_each(|child| visit_block(child, f));
+        }
+        BlockNode::List(node) => {
+            for item in &mut node.children {
+                f(&mut item.span);
+                item.children.iter_mut().for_each(|child| visit_block(child, f));
+            }
+        }
+        BlockNode::ListItem(node) => node.children.iter_mut().for_each(|child| visit_block(child, f)),
+        BlockNode::FootnoteDefinition(node) => node.children.iter_mut().for_each(|child| visit_block(child, f)),
+        BlockNode::Table(node) => {
+            visit_table_row(&mut node.header, f);
+            node.rows.iter_mut().for_each(|row| visit_table_row(row, f));
+        }
+        BlockNode::ThematicBreak(_)
+        | BlockNode::CodeBlock(_)
+        | BlockNode::MathBlock(_)
+        | BlockNode::HtmlBlock(_)
+        | BlockNode::Comment(_)
+        | BlockNode::LinkReferenceDefinition(_) => {}
+    }
+}
+
+fn visit_table_row(row: &mut TableRowNode, f: &mut impl FnMut(&mut Span)) {
+    f(&mut row.span);
+    for cell in &mut row.cells {
+        f(&mut cell.span);
+        visit_inlines(&mut cell.children, f);
+    }
+}
+
+fn visit_inlines(nodes: &mut [InlineNode], f: &mut impl FnMut(&mut Span)) {
+    for node in nodes {
+        visit_inline(node, f);
+    }
+}
+
+pub(crate) fn visit_inline(node: &mut InlineNode, f: &mut impl FnMut(&mut Span)) {
+    f(node.span_mut());
+    match node {
+        InlineNode::Emphasis(node) => visit_inlines(&mut node.children, f),
+        InlineNode::Strong(node) => visit_inlines(&mut node.children, f),
+        InlineNode::Strikethrough(node) => visit_inlines(&mut node.children, f),
+        InlineNode::Highlight(node) => visit_inlines(&mut node.children, f),
+        InlineNode::Link(node) => visit_inlines(&mut node.children, f),
+        InlineNode::Image(node) => visit_inlines(&mut node.children, f),
+        InlineNode::FootnoteReference(node) => {
+            if let Some(children) = &mut node.inline {
+                visit_inlines(children, f);
+            }
+        }
+        InlineNode::Embed(node) => {
+            if let Some(content) = &mut node.content {
+                content.iter_mut().for_each(|block| visit_block(block, f));
+            }
+        }
+        InlineNode::Text(_)
+        | InlineNode::CodeSpan(_)
+        | InlineNode::InlineMath(_)
+        | InlineNode::WikiLink(_)
+        | InlineNode::Tag(_)
+        | InlineNode::Comment(_)
+        | InlineNode::Autolink(_)
+        | InlineNode::RawHtml(_)
+        | InlineNode::SoftBreak(_)
+        | InlineNode::HardBreak(_) => {}
+    }
+}
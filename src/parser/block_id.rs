@@ -0,0 +1,226 @@
+//! Obsidian block id (`^id`)
+//!
+//! 인라인 파싱이 끝난 블록 트리에서 block id 표시를 떼어 블록의 `block_id`에 붙입니다.
+//! - Paragraph 끝의 ` ^id` (마지막 줄에 혼자 있어도 됨) → Paragraph
+//! - List Item 첫 Paragraph의 id → List Item
+//! - Table, Blockquote, Callout 바로 다음 블록이 `^id`만 있는 Paragraph → 그 블록 (Paragraph는 사라짐)
+//!
+//! id는 영문자, 숫자, `-`로 이루어져야 하며 앞 내용과 공백이나 줄바꿈으로 떨어져 있어야 합니다 (`a^b`는 id 아님).
+//! https://help.obsidian.md/links#Link+to+a+block+in+a+note
+
+use crate::node::{BlockNode, InlineNode, ListItemNode, ParagraphNode, Span};
+
+/// 블록 트리 전체의 block id 지정
+pub(super) fn assign(blocks: &mut Vec<BlockNode>) {
+    let mut i = 0;
+    while i < blocks.len() {
+        if i > 0
+            && let Some(id) = standalone_id(&blocks[i])
+            && let Some(target) = standalone_target(&mut blocks[i - 1])
+        {
+            *target = Some(id);
+            blocks.remove(i);
+            continue;
+        }
+        match &mut blocks[i] {
+            BlockNode::Paragraph(node) => strip_trailing_id(node),
+            BlockNode::Blockquote(node) => assign(&mut node.children),
+            BlockNode::Callout(node) => assign(&mut node.children),
+            BlockNode::List(node) => node.children.iter_mut().for_each(assign_item),
+            BlockNode::ListItem(node) => assign_item(node),
+            BlockNode::FootnoteDefinition(node) => assign(&mut node.children),
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+/// 아이템 첫 줄의 id는 아이템의 id
+fn assign_item(item: &mut ListItemNode) {
+    assign(&mut item.children);
+    if let Some(BlockNode::Paragraph(first)) = item.children.first_mut()
+        && first.block_id.is_some()
+    {
+        item.block_id = first.block_id.take();
+    }
+}
+
+/// 따로 쓴 `^id`를 받을 수 있는 블록의 block_id (이미 id가 있으면 None)
+fn standalone_target(block: &mut BlockNode) -> Option<&mut Option<String>> {
+    let block_id = match block {
+        BlockNode::Table(node) => &mut node.block_id,
+        BlockNode::Blockquote(node) => &mut node.block_id,
+        BlockNode::Callout(node) => &mut node.block_id,
+        _ => return None,
+    };
+    block_id.is_none().then_some(block_id)
+}
+
+/// `^id`만 있는 Paragraph의 id
+fn standalone_id(block: &BlockNode) -> Option<String> {
+    let BlockNode::Paragraph(node) = block else {
+        return None;
+    };
+    match node.children.as_slice() {
+        [InlineNode::Text(text)] => text.as_str().strip_prefix('^').filter(|id| is_valid_id(id)).map(str::to_string),
+        _ => None,
+    }
+}
+
+/// Paragraph 끝의 ` ^id`를 떼어 block_id로
+fn strip_trailing_id(node: &mut ParagraphNode) {
+    let len = node.children.len();
+    let after_break = len >= 2 && matches!(node.children[len - 2], InlineNode::SoftBreak(_));
+    let Some(InlineNode::Text(text)) = node.children.last_mut() else {
+        return;
+    };
+    let Some((before, id)) = text.as_str().rsplit_once('^') else {
+        return;
+    };
+    let content_len = before.trim_end_matches([' ', '\t']).len();
+    // 줄바꿈 뒤에 혼자 있는 id
+    let own_line = before.is_empty() && after_break;
+    let separated = content_len < before.len() || own_line;
+    // `^id`만 있는 Paragraph는 앞 블록의 id일 수 있으므로 그대로 둠
+    if !is_valid_id(id) || !separated || (content_len == 0 && len == 1) {
+        return;
+    }
+
+    node.block_id = Some(id.to_string());
+    if content_len > 0 {
+        let removed = text.content.len() - content_len;
+        text.content.truncate(content_len);
+        text.span = Span::from_offsets(text.span.start.offset, text.span.end.offset - removed);
+        return;
+    }
+    // 텍스트 노드 전체가 id: 그 노드(와 앞 줄바꿈)를 지움
+    node.children.pop();
+    if own_line {
+        node.children.pop();
+    }
+}
+
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{
+        BlockNode, BlockquoteNode, CalloutNode, InlineNode, ListItemNode, ParagraphNode, TableAlignment,
+        TableCellNode, TableNode, TableRowNode,
+    };
+    use crate::parser::parse;
+    use crate::parser::source_map::{LineColumns, line_columns};
+    use rstest::rstest;
+
+    fn list_children(block: &BlockNode) -> &[ListItemNode] {
+        let BlockNode::List(node) = block else {
+            panic!("List여야 함");
+        };
+        &node.children
+    }
+
+    #[rstest]
+    #[case(
+        "text ^abc-1",
+        vec![BlockNode::Paragraph(ParagraphNode::new(vec![InlineNode::text("text")]).with_block_id("abc-1"))]
+    )]
+    #[case(
+        "line one\nline two ^id",
+        vec![BlockNode::Paragraph(
+            ParagraphNode::new(vec![
+                InlineNode::text("line one"),
+                InlineNode::soft_break(),
+                InlineNode::text("line two"),
+            ])
+            .with_block_id("id"),
+        )]
+    )]
+    #[case(
+        "line one\n^id",
+        vec![BlockNode::Paragraph(ParagraphNode::new(vec![InlineNode::text("line one")]).with_block_id("id"))]
+    )]
+    #[case(
+        "*em* ^id",
+        vec![BlockNode::Paragraph(
+            ParagraphNode::new(vec![InlineNode::emphasis(vec![InlineNode::text("em")])]).with_block_id("id"),
+        )]
+    )]
+    // id 아님
+    #[case("a^id", vec![BlockNode::paragraph(vec![InlineNode::text("a^id")])])]
+    #[case("text ^bad_id", vec![BlockNode::paragraph(vec![InlineNode::text("text ^bad_id")])])]
+    #[case("text ^", vec![BlockNode::paragraph(vec![InlineNode::text("text ^")])])]
+    #[case("`code ^id`", vec![BlockNode::paragraph(vec![InlineNode::code_span("code ^id")])])]
+    // 따로 쓴 id는 Table, Blockquote, Callout에만 붙음
+    #[case("^id", vec![BlockNode::paragraph(vec![InlineNode::text("^id")])])]
+    #[case(
+        "# Heading\n\n^id",
+        vec![
+            BlockNode::heading(1, vec![InlineNode::text("Heading")]),
+            BlockNode::paragraph(vec![InlineNode::text("^id")]),
+        ]
+    )]
+    #[case(
+        "> quote\n\n^quote-id",
+        vec![BlockNode::Blockquote(
+            BlockquoteNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("quote")])]).with_block_id("quote-id"),
+        )]
+    )]
+    #[case(
+        "> [!note]\n> body\n\n^c",
+        vec![BlockNode::Callout(
+            CalloutNode::new("note", vec![], vec![BlockNode::paragraph(vec![InlineNode::text("body")])])
+                .with_block_id("c"),
+        )]
+    )]
+    #[case(
+        "| a |\n| - |\n\n^table",
+        vec![BlockNode::Table(
+            TableNode::new(
+                vec![TableAlignment::None],
+                TableRowNode::new(vec![TableCellNode::new(vec![InlineNode::text("a")])]),
+                vec![],
+            )
+            .with_block_id("table")
+        )]
+    )]
+    // 이미 id가 있으면 따로 쓴 id는 일반 Paragraph
+    #[case(
+        "> quote\n\n^one\n\n^two",
+        vec![
+            BlockNode::Blockquote(
+                BlockquoteNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("quote")])]).with_block_id("one"),
+            ),
+            BlockNode::paragraph(vec![InlineNode::text("^two")]),
+        ]
+    )]
+    fn test_block_id(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        assert_eq!(parse(input).without_spans().children, expected);
+    }
+
+    #[test]
+    fn test_block_id_list_item() {
+        let doc = parse("- first ^one\n- second\n\n  more ^two\n- > quoted ^three").without_spans();
+        let items = list_children(&doc.children[0]);
+        assert_eq!(items[0].block_id.as_deref(), Some("one"));
+        assert_eq!(items[0].children, vec![BlockNode::paragraph(vec![InlineNode::text("first")])]);
+        assert_eq!(items[1].block_id, None);
+        assert_eq!(
+            items[1].children[1],
+            BlockNode::Paragraph(ParagraphNode::new(vec![InlineNode::text("more")]).with_block_id("two")),
+        );
+        // 아이템 안 Blockquote의 Paragraph id는 그 Paragraph의 id
+        assert_eq!(items[2].block_id, None);
+    }
+
+    #[test]
+    fn test_block_id_span() {
+        let doc = parse("text ^id");
+        let BlockNode::Paragraph(node) = &doc.children[0] else {
+            panic!("Paragraph여야 함");
+        };
+        let expected: LineColumns = ((1, 1), (1, 5));
+        assert_eq!(line_columns(node.children[0].span()), expected);
+    }
+}
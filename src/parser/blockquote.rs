@@ -1,7 +1,10 @@
 //! https://spec.commonmark.org/0.31.2/#block-quotes
+//!
+//! 첫 줄이 `[!type]`인 Blockquote는 Obsidian Callout입니다 (https://help.obsidian.md/callouts).
+//! `[!type]` 뒤에 접기 표시(`+` 펼침, `-` 접힘)와 제목이 올 수 있으며, 이 줄은 내용이 아닙니다.
 
 use super::helpers::calculate_indent;
-use crate::node::{BlockNode, BlockquoteNode};
+use crate::node::{BlockNode, CalloutNode, InlineNode, Span};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum BlockquoteErr {
@@ -11,86 +14,108 @@ pub enum BlockquoteErr {
     NotBlockquoteMarker,
 }
 
-pub fn parse(line: &str) -> Result<String, BlockquoteErr> {
-    let indent = calculate_indent(line);
-    let trimmed = line.trim();
+/// `>` 마커 위치
+/// 마커 뒤 공백 한 칸은 마커의 일부로, 탭이면 한 칸만 소비하고 남은 칸은 내용의 들여쓰기 (Example 6)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockquoteMarker {
+    /// `>` 앞 들여쓰기 (byte)
+    pub indent: usize,
+}
 
+/// 줄 앞의 `>` 마커 인식
+/// 시작 줄과 이어지는 줄 모두 같은 규칙
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+pub fn parse(line: &str, column: usize) -> Result<BlockquoteMarker, BlockquoteErr> {
     // 들여쓰기 3칸 초과면 Blockquote 아님
-    if indent > 3 {
+    if calculate_indent(line, column) > 3 {
         return Err(BlockquoteErr::CodeBlockIndented);
     }
 
     // >로 시작하지 않으면 Blockquote 아님
-    if !trimmed.starts_with('>') {
+    let rest = line.trim_start_matches([' ', '\t']);
+    if !rest.starts_with('>') {
         return Err(BlockquoteErr::NotBlockquoteMarker);
     }
 
-    // > 마커 제거 후 내용 반환
-    let rest = &trimmed[1..];
-    let content = if rest.starts_with(' ') || rest.starts_with('\t') {
-        &rest[1..]
-    } else {
-        rest
-    };
-
-    Ok(content.to_string())
+    Ok(BlockquoteMarker {
+        indent: line.len() - rest.len(),
+    })
 }
 
-pub fn finalize<F>(contents: Vec<String>, parse_block: F) -> BlockNode
-where
-    F: Fn(&str) -> BlockNode,
-{
-    let text = contents.join("\n");
-
-    // \n\n으로 분리하여 각 블록 파싱
-    let children: Vec<BlockNode> = text
-        .split("\n\n")
-        .filter(|s| !s.is_empty())
-        .map(parse_block)
-        .collect();
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalloutErr {
+    /// `[!`로 시작하지 않음
+    NotCalloutMarker,
+    /// 종류가 비었거나 영숫자, `-`, `_` 외의 문자가 있거나 `]`로 닫히지 않음
+    InvalidKind,
+}
 
-    BlockNode::Blockquote(BlockquoteNode::new(children))
+/// Callout 첫 줄 (`[!type]`, 접기 표시, 제목)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalloutHeader {
+    /// callout 종류 (소문자)
+    pub kind: String,
+    /// 접기 상태 (`+`: Some(false), `-`: Some(true))
+    pub folded: Option<bool>,
+    /// 제목 원문 (앞뒤 공백 제외)
+    pub title: String,
+    /// 제목 시작 위치 (입력 기준 offset)
+    pub title_start: usize,
 }
 
-pub fn parse_text<F>(text: &str, parse_block: F) -> Option<BlockNode>
-where
-    F: Fn(&str) -> BlockNode,
-{
-    let mut contents: Vec<String> = Vec::new();
-
-    for line in text.lines() {
-        match parse(line) {
-            Ok(content) => contents.push(content),
-            Err(BlockquoteErr::NotBlockquoteMarker) => {
-                // Lazy continuation: > 없는 줄은 그대로 유지
-                if contents.is_empty() {
-                    // 첫 줄이 blockquote가 아니면 None
-                    return None;
-                }
-                contents.push(line.trim().to_string());
-            }
-            Err(BlockquoteErr::CodeBlockIndented) => {
-                // 4칸 이상 들여쓰기면 blockquote 아님
-                if contents.is_empty() {
-                    return None;
-                }
-                // 이미 시작된 blockquote 안에서는 lazy continuation으로 처리
-                contents.push(line.trim().to_string());
-            }
-        }
-    }
+/// Blockquote 첫 줄 내용(`>` 마커 다음)에서 Callout 헤더 인식
+/// offset: line의 입력 기준 시작 위치
+pub fn parse_callout(line: &str, offset: usize) -> Result<CalloutHeader, CalloutErr> {
+    let rest = line.trim_start_matches([' ', '\t']);
+    let Some(after_marker) = rest.strip_prefix("[!") else {
+        return Err(CalloutErr::NotCalloutMarker);
+    };
 
-    if contents.is_empty() {
-        return None;
+    let kind_len = after_marker.find(']').ok_or(CalloutErr::InvalidKind)?;
+    let kind = &after_marker[..kind_len];
+    if kind.is_empty() || !kind.chars().all(|c| c.is_alphanumeric() || matches!(c, '-' | '_')) {
+        return Err(CalloutErr::InvalidKind);
     }
 
-    Some(finalize(contents, parse_block))
+    let after_kind = &after_marker[kind_len + 1..];
+    let (folded, after_fold) = match after_kind.chars().next() {
+        Some('+') => (Some(false), &after_kind[1..]),
+        Some('-') => (Some(true), &after_kind[1..]),
+        _ => (None, after_kind),
+    };
+    let title = after_fold.trim();
+    let title_start = offset + line.len() - after_fold.trim_start().len();
+
+    Ok(CalloutHeader {
+        kind: kind.to_lowercase(),
+        folded,
+        title: title.to_string(),
+        title_start,
+    })
+}
+
+/// Callout 노드 생성 (제목은 인라인 파싱 전 원문 Text)
+pub fn finalize_callout(header: CalloutHeader, children: Vec<BlockNode>) -> BlockNode {
+    let title = match header.title.is_empty() {
+        true => Vec::new(),
+        false => {
+            let span = Span::from_offsets(header.title_start, header.title_start + header.title.len());
+            vec![InlineNode::text(&header.title).with_span(span)]
+        }
+    };
+    let node = CalloutNode::new(&header.kind, title, children);
+    BlockNode::Callout(match header.folded {
+        Some(folded) => node.with_folded(folded),
+        None => node,
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::node::{BlockNode, InlineNode, ListItemNode};
+    use super::*;
+    use crate::node::{BlockNode, CalloutNode, InlineNode, ListItemNode};
     use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
     use rstest::rstest;
 
     fn bq(depth: usize, inner: BlockNode) -> BlockNode {
@@ -119,12 +144,12 @@ mod tests {
     // Example 231: 4칸 들여쓰기는 code block
     #[case("    > # Foo", vec![BlockNode::code_block(None, "> # Foo")])]
     // Example 232-233: Lazy continuation
-    #[case("> bar\nbaz", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar\nbaz")])])])]
-    #[case("> bar\nbaz\n> foo", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar\nbaz\nfoo")])])])]
+    #[case("> bar\nbaz", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar"), InlineNode::soft_break(), InlineNode::text("baz")])])])]
+    #[case("> bar\nbaz\n> foo", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar"), InlineNode::soft_break(), InlineNode::text("baz"), InlineNode::soft_break(), InlineNode::text("foo")])])])]
     // Example 234: Laziness 한계 - thematic break
     #[case("> foo\n---", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("foo")])]), BlockNode::thematic_break()])]
     // Example 238: 4칸 들여쓰기 lazy continuation
-    #[case("> foo\n    - bar", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("foo\n- bar")])])])]
+    #[case("> foo\n    - bar", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("foo"), InlineNode::soft_break(), InlineNode::text("- bar")])])])]
     // Example 239: 빈 blockquote
     #[case(">", vec![BlockNode::blockquote(vec![])])]
     // Example 240: 공백만 있는 빈 blockquote
@@ -134,7 +159,7 @@ mod tests {
     // Example 242: 빈 줄로 분리된 두 blockquote
     #[case("> foo\n\n> bar", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("foo")])]), BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar")])])])]
     // Example 243: 여러 줄 하나의 paragraph
-    #[case("> foo\n> bar", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("foo\nbar")])])])]
+    #[case("> foo\n> bar", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("foo"), InlineNode::soft_break(), InlineNode::text("bar")])])])]
     // Example 244: Blockquote 내 복수 단락
     #[case("> foo\n>\n> bar", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("foo")]), BlockNode::paragraph(vec![InlineNode::text("bar")])])])]
     #[case("> line1\n>\n> line2", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("line1")]), BlockNode::paragraph(vec![InlineNode::text("line2")])])])]
@@ -144,39 +169,38 @@ mod tests {
     // Example 246: blockquote/thematic break/blockquote
     #[case("> aaa\n***\n> bbb", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("aaa")])]), BlockNode::thematic_break(), BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bbb")])])])]
     // Example 247: Lazy continuation
-    #[case("> bar\nbaz", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar\nbaz")])])])]
+    #[case("> bar\nbaz", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar"), InlineNode::soft_break(), InlineNode::text("baz")])])])]
     // Example 248: Blockquote 후 빈 줄 + paragraph
     #[case("> bar\n\nbaz", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar")])]), BlockNode::paragraph(vec![InlineNode::text("baz")])])]
     // 추가 케이스
     #[case("> hello", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("hello")])])])]
     #[case(">  hello", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("hello")])])])]
     #[case("> 안녕하세요", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("안녕하세요")])])])]
-    #[case("> a\n> b\n> c", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("a\nb\nc")])])])]
-    #[case(">line1\n>line2", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("line1\nline2")])])])]
-    #[case("> a\nb\nc", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("a\nb\nc")])])])]
-    #[case("> start\n> middle\nend", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("start\nmiddle\nend")])])])]
+    #[case("> a\n> b\n> c", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("a"), InlineNode::soft_break(), InlineNode::text("b"), InlineNode::soft_break(), InlineNode::text("c")])])])]
+    #[case(">line1\n>line2", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("line1"), InlineNode::soft_break(), InlineNode::text("line2")])])])]
+    #[case("> a\nb\nc", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("a"), InlineNode::soft_break(), InlineNode::text("b"), InlineNode::soft_break(), InlineNode::text("c")])])])]
+    #[case("> start\n> middle\nend", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("start"), InlineNode::soft_break(), InlineNode::text("middle"), InlineNode::soft_break(), InlineNode::text("end")])])])]
     fn test_blockquote(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
-        let doc = parse(input);
+        let doc = parse(input).without_spans();
         assert_eq!(doc.children, expected);
     }
 
     #[rstest]
     // Example 250: 중첩 blockquote + lazy continuation
-    #[case("> > > foo\nbar", 3, "foo\nbar")]
+    #[case("> > > foo\nbar", 3, vec![InlineNode::text("foo"), InlineNode::soft_break(), InlineNode::text("bar")])]
     // Example 251: 최소 마커 중첩
-    #[case(">>> foo", 3, "foo")]
+    #[case(">>> foo", 3, vec![InlineNode::text("foo")])]
     // 추가 케이스
-    #[case("> > nested", 2, "nested")]
-    #[case("> > > deep", 3, "deep")]
-    #[case("> > > > 4단계", 4, "4단계")]
-    #[case("> > a\n> > b", 2, "a\nb")]
-    fn test_nested_blockquote(#[case] input: &str, #[case] depth: usize, #[case] text: &str) {
-        let doc = parse(input);
-        let expected = vec![bq(depth, BlockNode::paragraph(vec![InlineNode::text(text)]))];
+    #[case("> > nested", 2, vec![InlineNode::text("nested")])]
+    #[case("> > > deep", 3, vec![InlineNode::text("deep")])]
+    #[case("> > > > 4단계", 4, vec![InlineNode::text("4단계")])]
+    #[case("> > a\n> > b", 2, vec![InlineNode::text("a"), InlineNode::soft_break(), InlineNode::text("b")])]
+    fn test_nested_blockquote(#[case] input: &str, #[case] depth: usize, #[case] inlines: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        let expected = vec![bq(depth, BlockNode::paragraph(inlines))];
         assert_eq!(doc.children, expected);
     }
 
-    // TODO: 현재 파서 미지원 케이스
     #[rstest]
     // Example 235: Laziness 한계 - list가 blockquote 중단
     #[case("> - foo\n- bar", vec![BlockNode::blockquote(vec![BlockNode::bullet_list(true, vec![ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("foo")])])])]), BlockNode::bullet_list(true, vec![ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("bar")])])])])]
@@ -186,9 +210,118 @@ mod tests {
     #[case("> ```\nfoo\n```", vec![BlockNode::blockquote(vec![BlockNode::code_block(None, "")]), BlockNode::paragraph(vec![InlineNode::text("foo")]), BlockNode::code_block(None, "")])]
     // Example 249: 빈 blockquote 줄 후 paragraph
     #[case("> bar\n>\nbaz", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar")])]), BlockNode::paragraph(vec![InlineNode::text("baz")])])]
-    #[ignore = "현재 파서 미지원"]
-    fn test_blockquote_pending(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+    fn test_blockquote_laziness(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, expected);
+    }
+
+    #[rstest]
+    // blockquote는 첫 `>`부터, 내용 블록은 `>` 마커를 뗀 원문 위치
+    #[case("> a\n> b", ((1, 1), (2, 4)), vec![((1, 3), (2, 4))])]
+    #[case(" > a\nlazy", ((1, 2), (2, 5)), vec![((1, 4), (2, 5))])]
+    #[case("> # T\n>\n> para", ((1, 1), (3, 7)), vec![((1, 3), (1, 6)), ((3, 3), (3, 7))])]
+    // 중첩 blockquote
+    #[case("> > a\n> > b", ((1, 1), (2, 6)), vec![((1, 3), (2, 6))])]
+    #[case(">     code\n>\n> ```\n> x\n> ```", ((1, 1), (5, 6)), vec![((1, 7), (1, 11)), ((3, 3), (5, 6))])]
+    // blockquote 안 리스트는 마커부터
+    #[case("> - a\n>   b", ((1, 1), (2, 6)), vec![((1, 3), (2, 6))])]
+    fn test_blockquote_span(
+        #[case] input: &str,
+        #[case] blockquote: LineColumns,
+        #[case] children: Vec<LineColumns>,
+    ) {
         let doc = parse(input);
+        let Some(BlockNode::Blockquote(node)) = doc.children.first() else {
+            panic!("첫 블록이 Blockquote여야 함");
+        };
+        assert_eq!(line_columns(node.span), blockquote);
+        let actual: Vec<LineColumns> = node.children.iter().map(|child| line_columns(child.span())).collect();
+        assert_eq!(actual, children);
+    }
+
+    fn callout(kind: &str, title: &str, folded: Option<bool>, children: Vec<BlockNode>) -> BlockNode {
+        let title = match title {
+            "" => vec![],
+            title => vec![InlineNode::text(title)],
+        };
+        let node = CalloutNode::new(kind, title, children);
+        BlockNode::Callout(match folded {
+            Some(folded) => node.with_folded(folded),
+            None => node,
+        })
+    }
+
+    fn header(kind: &str, folded: Option<bool>, title: &str, title_start: usize) -> CalloutHeader {
+        CalloutHeader {
+            kind: kind.to_string(),
+            folded,
+            title: title.to_string(),
+            title_start,
+        }
+    }
+
+    #[rstest]
+    #[case("[!note]", Ok(header("note", None, "", 7)))]
+    #[case("[!WARNING] Be careful ", Ok(header("warning", None, "Be careful", 11)))]
+    #[case("[!faq]- Why?", Ok(header("faq", Some(true), "Why?", 8)))]
+    #[case("[!my-type]+", Ok(header("my-type", Some(false), "", 11)))]
+    #[case("  [!tip]Title", Ok(header("tip", None, "Title", 8)))]
+    #[case("[note]", Err(CalloutErr::NotCalloutMarker))]
+    #[case("text [!note]", Err(CalloutErr::NotCalloutMarker))]
+    #[case("[!]", Err(CalloutErr::InvalidKind))]
+    #[case("[!a b]", Err(CalloutErr::InvalidKind))]
+    #[case("[!note", Err(CalloutErr::InvalidKind))]
+    fn test_parse_callout(#[case] line: &str, #[case] expected: Result<CalloutHeader, CalloutErr>) {
+        assert_eq!(parse_callout(line, 0), expected);
+    }
+
+    #[rstest]
+    // 제목과 내용
+    #[case(
+        "> [!warning] Careful\n> body",
+        vec![callout("warning", "Careful", None, vec![BlockNode::paragraph(vec![InlineNode::text("body")])])]
+    )]
+    // 접기 표시
+    #[case("> [!tip]- Hidden\n> x", vec![callout("tip", "Hidden", Some(true), vec![BlockNode::paragraph(vec![InlineNode::text("x")])])])]
+    #[case("> [!tip]+\n> x", vec![callout("tip", "", Some(false), vec![BlockNode::paragraph(vec![InlineNode::text("x")])])])]
+    // 제목의 인라인
+    #[case(
+        "> [!note] *Read* this",
+        vec![BlockNode::Callout(CalloutNode::new(
+            "note",
+            vec![InlineNode::emphasis(vec![InlineNode::text("Read")]), InlineNode::text(" this")],
+            vec![],
+        ))]
+    )]
+    // 중첩 callout
+    #[case(
+        "> [!note] Outer\n> > [!bug] Inner\n> > deep\n> after",
+        vec![callout("note", "Outer", None, vec![
+            callout("bug", "Inner", None, vec![BlockNode::paragraph(vec![
+                InlineNode::text("deep"),
+                InlineNode::soft_break(),
+                InlineNode::text("after"),
+            ])]),
+        ])]
+    )]
+    // 내용 Paragraph의 lazy continuation
+    #[case("> [!note]\n> a\nb", vec![callout("note", "", None, vec![BlockNode::paragraph(vec![InlineNode::text("a"), InlineNode::soft_break(), InlineNode::text("b")])])])]
+    // 첫 줄이 아니면 일반 blockquote 내용
+    #[case("> a\n> [!note]", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("a"), InlineNode::soft_break(), InlineNode::text("[!note]")])])])]
+    #[case("> \\[!note]", vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("[!note]")])])])]
+    fn test_callout(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse(input).without_spans();
         assert_eq!(doc.children, expected);
     }
+
+    #[test]
+    fn test_callout_span() {
+        let doc = parse("> [!note] Title\n> body");
+        let Some(BlockNode::Callout(node)) = doc.children.first() else {
+            panic!("첫 블록이 Callout이어야 함");
+        };
+        assert_eq!(line_columns(node.span), ((1, 1), (2, 7)));
+        assert_eq!(line_columns(node.title[0].span()), ((1, 11), (1, 16)));
+        assert_eq!(line_columns(node.children[0].span()), ((2, 3), (2, 7)));
+    }
 }
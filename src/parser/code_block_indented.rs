@@ -2,7 +2,7 @@
 //!
 //! 4칸 들여쓰기로 작성된 코드 블록을 파싱합니다.
 
-use super::helpers::count_leading_char;
+use super::helpers::{calculate_indent, remove_indent};
 
 // =============================================================================
 // 타입 정의
@@ -37,14 +37,15 @@ pub enum CodeBlockIndentedNotStartReason {
 
 /// Indented Code Block 시작 줄인지 확인
 /// 성공 시 Ok(Started), 실패 시 Err(사유) 반환
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
 pub(crate) fn try_start(
     line: &str,
+    column: usize,
 ) -> Result<CodeBlockIndentedStartReason, CodeBlockIndentedNotStartReason> {
     // 1. 들여쓰기 확인 (4칸 이상이면 코드 줄)
-    let indent = count_leading_char(line, ' ');
-    if indent >= 4 {
-        // 4칸 제거 후 내용 반환 (공백만 있는 줄도 코드의 일부)
-        let content = line[4..].to_string();
+    if calculate_indent(line, column) >= 4 {
+        // 4칸 제거 후 내용 반환 (공백만 있는 줄도 코드의 일부, 탭은 일부만 제거될 수 있음)
+        let content = remove_indent(line, column, 4).into_owned();
         return Ok(CodeBlockIndentedStartReason::Started(
             CodeBlockIndentedStart { content },
         ));
@@ -71,6 +72,10 @@ mod tests {
     #[case("    code", Ok("code"))]
     #[case("     code", Ok(" code"))]
     #[case("        code", Ok("    code"))]
+    // 성공 케이스: 탭 (Example 1, 2)
+    #[case("\tfoo\tbaz\t\tbim", Ok("foo\tbaz\t\tbim"))]
+    #[case("  \tfoo\tbaz\t\tbim", Ok("foo\tbaz\t\tbim"))]
+    #[case("\t\tbar", Ok("\tbar"))]
     // 실패 케이스: 빈 줄
     #[case("", Err(CodeBlockIndentedNotStartReason::Empty))]
     #[case("   ", Err(CodeBlockIndentedNotStartReason::Empty))]
@@ -83,7 +88,7 @@ mod tests {
         #[case] input: &str,
         #[case] expected: Result<&str, CodeBlockIndentedNotStartReason>,
     ) {
-        let result = try_start(input);
+        let result = try_start(input, 0);
         match expected {
             Ok(content) => {
                 let reason = result.expect("시작이어야 함");
@@ -108,7 +113,7 @@ mod tests {
     // Example 112: 들여쓰기된 빈 줄 유지
     #[case("    chunk1\n      \n      chunk2", vec![BlockNode::code_block(None, "chunk1\n  \n  chunk2")])]
     // Example 113: Paragraph 인터럽트 불가 - 빈 줄 없이 4칸 들여쓰기는 Paragraph 일부
-    #[case("Foo\n    bar", vec![BlockNode::paragraph(vec![InlineNode::text("Foo\nbar")])])]
+    #[case("Foo\n    bar", vec![BlockNode::paragraph(vec![InlineNode::text("Foo"), InlineNode::soft_break(), InlineNode::text("bar")])])]
     // Example 114: 코드 블록 후 4칸 미만 줄은 새 Paragraph
     #[case("    foo\nbar", vec![BlockNode::code_block(None, "foo"), BlockNode::paragraph(vec![InlineNode::text("bar")])])]
     // Example 116: 8칸 들여쓰기 (4칸 제거 후 4칸 유지)
@@ -118,7 +123,7 @@ mod tests {
     // Example 118: 후행 공백은 유지됨
     #[case("    foo  ", vec![BlockNode::code_block(None, "foo  ")])]
     fn test_code_block_indented(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
-        let doc = crate::parse(input);
+        let doc = crate::parse(input).without_spans();
         assert_eq!(doc.children, expected);
     }
 }
@@ -0,0 +1,137 @@
+//! https://help.obsidian.md/syntax#Comments
+//!
+//! Obsidian 블록 주석 파서
+//!
+//! `%%`로 시작하고 같은 줄에서 닫히지 않는 줄부터, `%%`로 끝나는 줄까지를 주석으로 묶습니다.
+//! 사이에 빈 줄이 있어도 이어지므로 여러 문단을 감쌀 수 있고, 내용은 마크다운으로 해석하지 않습니다.
+//! 닫는 줄이 없으면 Fenced Code Block처럼 컨테이너 끝(또는 문서 끝)까지 주석입니다.
+//! 같은 줄에서 닫히는 `%%note%%`는 인라인 주석입니다 (inline/comment.rs).
+
+use super::helpers::calculate_indent;
+use crate::node::BlockNode;
+
+/// 주석 구분자
+const MARKER: &str = "%%";
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// 블록 주석 시작 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentStart {
+    /// 여는 `%%` 뒤의 내용 (앞 공백 제거, 없으면 None)
+    pub content: Option<String>,
+}
+
+/// 블록 주석 시작 성공 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommentStartReason {
+    /// 정상적인 시작
+    Started(CommentStart),
+}
+
+/// 블록 주석 시작 아님 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommentNotStartReason {
+    /// 4칸 이상 들여쓰기 (Indented Code Block)
+    CodeBlockIndented,
+    /// `%%`로 시작하지 않음
+    NoMarker,
+    /// 같은 줄에서 닫힘 (인라인 주석)
+    ClosedInline,
+}
+
+// =============================================================================
+// 함수
+// =============================================================================
+
+/// 블록 주석 시작 줄인지 확인
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+pub(crate) fn try_start(line: &str, column: usize) -> Result<CommentStartReason, CommentNotStartReason> {
+    if calculate_indent(line, column) > 3 {
+        return Err(CommentNotStartReason::CodeBlockIndented);
+    }
+    let rest = line
+        .trim_start_matches([' ', '\t'])
+        .strip_prefix(MARKER)
+        .ok_or(CommentNotStartReason::NoMarker)?;
+    if rest.contains(MARKER) {
+        return Err(CommentNotStartReason::ClosedInline);
+    }
+
+    let content = rest.trim();
+    let content = (!content.is_empty()).then(|| content.to_string());
+    Ok(CommentStartReason::Started(CommentStart { content }))
+}
+
+/// 닫는 줄이면 닫는 `%%` 앞의 내용 (뒤 공백 제거)
+pub(crate) fn try_end(line: &str) -> Option<&str> {
+    line.trim_end().strip_suffix(MARKER).map(str::trim_end)
+}
+
+pub fn finalize(lines: Vec<String>) -> BlockNode {
+    BlockNode::comment(&lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::InlineNode;
+    use crate::parser::parse;
+    use crate::parser::source_map::{LineColumns, line_columns};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("%%", None)]
+    #[case("   %%  note ", Some("note"))]
+    fn test_try_start_ok(#[case] line: &str, #[case] content: Option<&str>) {
+        let expected = CommentStart { content: content.map(str::to_string) };
+        assert_eq!(try_start(line, 0), Ok(CommentStartReason::Started(expected)));
+    }
+
+    #[rstest]
+    #[case("    %%", CommentNotStartReason::CodeBlockIndented)]
+    #[case("a %%", CommentNotStartReason::NoMarker)]
+    #[case("%note", CommentNotStartReason::NoMarker)]
+    #[case("%%note%%", CommentNotStartReason::ClosedInline)]
+    fn test_try_start_err(#[case] line: &str, #[case] expected: CommentNotStartReason) {
+        assert_eq!(try_start(line, 0), Err(expected));
+    }
+
+    #[rstest]
+    #[case("%%\nfirst\n\nsecond\n%%", vec![BlockNode::comment("first\n\nsecond")])]
+    #[case(
+        "%% one\ntwo %%\nafter",
+        vec![BlockNode::comment("one\ntwo"), BlockNode::paragraph(vec![InlineNode::text("after")])]
+    )]
+    #[case(
+        "before\n\n%%\n# not heading\n%%",
+        vec![BlockNode::paragraph(vec![InlineNode::text("before")]), BlockNode::comment("# not heading")]
+    )]
+    // 닫는 줄이 없으면 문서 끝까지
+    #[case("%%\nhidden\n\nstill hidden", vec![BlockNode::comment("hidden\n\nstill hidden")])]
+    // 컨테이너 안의 주석
+    #[case("> %%\n> note\n> %%", vec![BlockNode::blockquote(vec![BlockNode::comment("note")])])]
+    // Paragraph는 인터럽트하지 않음 (줄을 넘는 인라인 주석)
+    #[case(
+        "text\n%%\nhidden\n%%",
+        vec![BlockNode::paragraph(vec![
+            InlineNode::text("text"),
+            InlineNode::soft_break(),
+            InlineNode::comment("\nhidden\n"),
+        ])]
+    )]
+    // 같은 줄에서 닫히면 인라인 주석
+    #[case("%%note%%", vec![BlockNode::paragraph(vec![InlineNode::comment("note")])])]
+    fn test_block_comment(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        assert_eq!(parse(input).without_spans().children, expected);
+    }
+
+    #[test]
+    fn test_block_comment_span() {
+        let doc = parse("%%\na\n\nb\n%%\n\nafter");
+        let expected: LineColumns = ((1, 1), (5, 3));
+        assert_eq!(line_columns(doc.children[0].span()), expected);
+    }
+}
@@ -0,0 +1,208 @@
+//! 새 블록 시작 감지
+//!
+//! 열린 블록들이 이어 받지 못한 줄의 나머지에서 새 블록을 엽니다.
+//! 컨테이너 블록(Blockquote, List Item, Footnote Definition)은 마커를 소비한 뒤 그 안에서 다시 시작을 찾고,
+//! Leaf 블록을 열면 멈춥니다.
+
+use super::{OpenBlock, ParsingContext};
+use crate::node::{BlockNode, Span};
+use crate::parser::code_block_fenced::{self, CodeBlockFencedOk};
+use crate::parser::code_block_indented::{self, CodeBlockIndentedStartReason};
+use crate::parser::comment::{self, CommentStartReason};
+use crate::parser::footnote_definition::{self, FootnoteDefinitionStartReason};
+use crate::parser::heading_setext::{self, HeadingSetextStartReason};
+use crate::parser::html_block::{self, HtmlBlockStartReason};
+use crate::parser::line::Line;
+use crate::parser::list_item::{self, ListItemStartReason, TASK_MARKER_LEN};
+use crate::parser::math_block::{self, MathBlockStartReason};
+use crate::parser::source_map::{line_span, shift};
+use crate::parser::{blockquote, heading, thematic_break};
+
+/// 새 블록 시작 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum Opened {
+    /// 새 블록 없음
+    Nothing,
+    /// 새 블록을 열었음 (남은 내용은 가장 안쪽 블록이 받음)
+    Blocks,
+    /// 줄을 모두 소비한 블록 (ATX Heading, Thematic Break, Setext 밑줄, 여는 펜스/수식/주석 줄, Table 구분 행)
+    Consumed,
+}
+
+/// 줄의 나머지에서 새 블록들을 엶
+/// matched: 줄을 이어 받은 열린 블록 수 (새 블록을 열면 그 안쪽 블록은 닫힘)
+///
+/// 시작 조건은 명세 순서대로 확인:
+/// Blockquote (Callout) → ATX Heading → Fenced Code → Math → HTML → Comment → Setext 밑줄 → Thematic Break
+/// → Footnote Definition → List Item → Table → Indented Code
+pub fn open_blocks(context: &mut ParsingContext, line: &mut Line, matched: usize) -> Opened {
+    let mut opened = Opened::Nothing;
+    let mut depth = matched;
+    // 이어 받은 가장 안쪽 블록이 Paragraph인지 (Setext 밑줄, Paragraph 인터럽트 규칙)
+    let mut in_paragraph = depth == context.depth() && context.tip().is_some_and(OpenBlock::is_paragraph);
+
+    loop {
+        let rest = line.rest();
+        let offset = line.offset();
+        let column = line.column();
+
+        // Blockquote: `>` 마커를 소비하고 안쪽에서 계속
+        if let Ok(marker) = blockquote::parse(rest, column) {
+            context.close_to(depth);
+            let start = offset + marker.indent;
+            line.advance(marker.indent + 1);
+            line.skip_indent(1);
+            // Callout: 첫 줄의 `[!type]` 헤더는 내용이 아님 (Obsidian)
+            if let Ok(header) = blockquote::parse_callout(line.rest(), line.offset()) {
+                let end = line_span(line.rest(), line.offset()).end.offset;
+                context.open(OpenBlock::callout(header, Span::from_offsets(start, end)));
+                return Opened::Consumed;
+            }
+            context.open(OpenBlock::blockquote(Span::from_offsets(start, start + 1)));
+            depth = context.depth();
+            in_paragraph = false;
+            opened = Opened::Blocks;
+            continue;
+        }
+
+        // ATX Heading
+        if let Ok(node) = heading::parse(rest, column) {
+            context.close_to(depth);
+            context.add_node(shift(node, offset));
+            return Opened::Consumed;
+        }
+
+        // Fenced Code Block (여는 펜스 줄은 내용이 아님)
+        if let Ok(CodeBlockFencedOk::Start(start)) = code_block_fenced::parse(rest, column, None) {
+            context.close_to(depth);
+            context.open(OpenBlock::CodeBlockFenced {
+                start,
+                content: Vec::new(),
+                span: line_span(rest, offset),
+            });
+            return Opened::Consumed;
+        }
+
+        // Math Block (여는 `$$` 줄은 내용이 아님, 같은 줄에서 닫히면 바로 블록)
+        match math_block::try_start(rest, column) {
+            Ok(MathBlockStartReason::Started(start)) => {
+                context.close_to(depth);
+                context.open(OpenBlock::MathBlock {
+                    start,
+                    lines: Vec::new(),
+                    span: line_span(rest, offset),
+                });
+                return Opened::Consumed;
+            }
+            Ok(MathBlockStartReason::Closed(content)) => {
+                context.close_to(depth);
+                context.add_node(BlockNode::math_block(&content).with_span(line_span(rest, offset)));
+                return Opened::Consumed;
+            }
+            Err(_) => {}
+        }
+
+        // HTML Block (시작 줄도 내용)
+        // 조건 7(임의의 완전한 태그)은 Paragraph 인터럽트 불가 (Example 187)
+        if let Ok(HtmlBlockStartReason::Started(start)) = html_block::try_start(rest, column, in_paragraph) {
+            context.close_to(depth);
+            context.open(OpenBlock::HtmlBlock {
+                start,
+                lines: Vec::new(),
+                span: line_span(rest, offset),
+            });
+            return Opened::Blocks;
+        }
+
+        // Obsidian 블록 주석 (여는 줄의 `%%` 뒤 내용은 첫 줄, Paragraph 인터럽트 불가)
+        if !in_paragraph && let Ok(CommentStartReason::Started(start)) = comment::try_start(rest, column) {
+            context.close_to(depth);
+            context.open(OpenBlock::Comment {
+                lines: start.content.into_iter().collect(),
+                span: line_span(rest, offset),
+            });
+            return Opened::Consumed;
+        }
+
+        // Setext Heading 밑줄이면 Paragraph를 Heading으로 변환
+        // 중요: Thematic Break보다 먼저 확인해야 함 (---가 Setext 밑줄로 해석됨)
+        if in_paragraph
+            && let Ok(HeadingSetextStartReason::Started(start)) = heading_setext::try_start(rest.trim(), line.indent())
+            && context.close_setext_heading(start.level.to_level(), line_span(rest, offset).end.offset)
+        {
+            return Opened::Consumed;
+        }
+
+        // Thematic Break
+        if let Ok(node) = thematic_break::parse(rest, column) {
+            context.close_to(depth);
+            context.add_node(shift(node, offset));
+            return Opened::Consumed;
+        }
+
+        // Footnote Definition: `[^label]:`와 뒤 공백을 소비하고 안쪽에서 계속
+        if let Ok(FootnoteDefinitionStartReason::Started(start)) = footnote_definition::try_start(rest, column) {
+            context.close_to(depth);
+            let marker_start = offset + start.indent;
+            let span = Span::from_offsets(marker_start, marker_start + start.marker_len);
+            context.open(OpenBlock::footnote_definition(start.label, span));
+            line.advance(start.indent + start.marker_len);
+            line.skip_whitespace();
+            depth = context.depth();
+            in_paragraph = false;
+            opened = Opened::Blocks;
+            continue;
+        }
+
+        // List Item: 마커와 뒤 공백을 소비하고 안쪽에서 계속
+        // Paragraph는 빈 아이템이나 1이 아닌 번호로 인터럽트할 수 없음 (Example 285, 304)
+        if let Ok(ListItemStartReason::Started(start)) = list_item::try_start(rest, column)
+            && (!in_paragraph || start.can_interrupt_paragraph())
+        {
+            context.close_to(depth);
+            let marker = rest.trim_start_matches([' ', '\t']);
+            let marker_len = marker.find([' ', '\t']).unwrap_or(marker.len());
+            let marker_start = offset + rest.len() - marker.len();
+            context.open(OpenBlock::ListItem {
+                content_indent: start.content_indent,
+                span: Span::from_offsets(marker_start, marker_start + marker_len),
+                children: Vec::new(),
+                marker: start.marker,
+                checked: start.checked,
+            });
+            // 들여쓰기와 마커, 뒤 공백은 열 단위 (마커 뒤 탭은 일부만 소비될 수 있음)
+            line.advance(rest.len() - marker.len() + marker_len);
+            line.skip_indent(start.content_indent - start.indent - marker_len);
+            // Task list 마커와 뒤 공백은 내용이 아님
+            if start.checked.is_some() {
+                line.advance(TASK_MARKER_LEN);
+                line.skip_whitespace();
+            }
+            depth = context.depth();
+            in_paragraph = false;
+            opened = Opened::Blocks;
+            continue;
+        }
+
+        // Table: Paragraph의 마지막 줄이 헤더 행, 이 줄이 구분 행 (GFM)
+        if in_paragraph && context.open_table(rest, line.indent(), line_span(rest, offset).end.offset) {
+            return Opened::Consumed;
+        }
+
+        // Indented Code Block (Paragraph의 lazy continuation이 우선)
+        if !line.is_blank()
+            && !context.tip().is_some_and(OpenBlock::is_paragraph)
+            && let Ok(CodeBlockIndentedStartReason::Started(_)) = code_block_indented::try_start(rest, column)
+        {
+            context.close_to(depth);
+            context.open(OpenBlock::CodeBlockIndented {
+                lines: Vec::new(),
+                span: line_span(rest, offset),
+            });
+            line.skip_indent(4);
+            return Opened::Blocks;
+        }
+
+        return opened;
+    }
+}
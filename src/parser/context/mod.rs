@@ -1,72 +1,208 @@
 //! 파싱 컨텍스트 타입 정의
 //!
-//! 시작 정보(Start)와 파싱 상태(ParsingContext)를 분리하여 관리합니다.
+//! 블록 구조 파싱 상태는 열린 블록 스택(ParsingContext)입니다.
+//! 바닥은 문서, 꼭대기는 가장 안쪽의 열린 블록이며, 각 줄은 바깥 블록부터 차례로 이어 받습니다.
+//! 시작 정보(Start)는 각 파서 모듈에 두고, 열린 블록(OpenBlock)이 이를 보관합니다.
 
-mod none_context;
+mod block_start;
+mod open_block;
 mod paragraph_context;
 
-pub use none_context::NoneContext;
+pub use block_start::{open_blocks, Opened};
+pub use open_block::{Continuation, OpenBlock};
 pub use paragraph_context::ParagraphContext;
 
-use crate::node::BlockNode;
-
-// 각 파서 모듈에서 타입 re-export
-pub use super::code_block_fenced::CodeBlockFencedStart;
-pub use super::code_block_indented::{
-    CodeBlockIndentedNotStartReason, CodeBlockIndentedStartReason,
-};
-pub use super::heading_setext::HeadingSetextStartReason;
-pub use super::list_item::{
-    ItemLine, ListContinueReason, ListEndReason, ListItemStart, ListItemStartReason,
-};
-
-/// 한 줄 처리 결과: (새로 완성된 노드들, 새 컨텍스트)
-pub type LineResult = (Vec<BlockNode>, ParsingContext);
+use crate::node::{BlockNode, Span};
+use super::list_item::ListMarker;
+use super::paragraph;
+use super::table::{self, TableStartReason};
+use super::source_map::extend_to;
 
 // =============================================================================
 // Parsing Context
 // =============================================================================
 
-/// 파싱 중인 컨텍스트 (상태 기계의 상태)
-pub enum ParsingContext {
-    /// 새 블록 시작 대기
-    None(NoneContext),
-
-    /// Fenced Code Block 파싱 중
-    CodeBlockFenced {
-        /// 시작 정보 (불변)
-        start: CodeBlockFencedStart,
-        /// 축적된 코드 줄 (가변)
-        content: Vec<String>,
-    },
-
-    /// Paragraph 파싱 중 (여러 줄이 하나의 문단)
-    Paragraph(ParagraphContext),
-
-    /// Blockquote 파싱 중 (여러 줄 수집)
-    Blockquote { pending_lines: Vec<String> },
-
-    /// List 파싱 중
-    List {
-        /// 첫 아이템의 시작 정보 (리스트 타입 결정용)
-        first_item_start: ListItemStart,
-        /// 완성된 아이템들의 내용
-        items: Vec<Vec<ItemLine>>,
-        /// 현재 아이템의 줄들
-        current_item_lines: Vec<ItemLine>,
-        /// 현재 아이템의 content_indent (continuation line 판단용)
-        current_content_indent: usize,
-        /// tight 리스트 여부 (아이템 간 빈 줄 없음)
-        tight: bool,
-        /// 대기 중인 빈 줄 개수 (continuation 시 내용에 추가)
-        pending_blank_count: usize,
-    },
-
-    /// Indented Code Block 파싱 중
-    CodeBlockIndented {
-        /// 축적된 코드 줄 (4칸 들여쓰기 제거 후)
-        pending_lines: Vec<String>,
-        /// 대기 중인 빈 줄 개수 (다음 코드 줄이 오면 내용에 추가)
-        pending_blank_count: usize,
-    },
+/// 파싱 중인 컨텍스트: 열린 블록 스택
+#[derive(Debug, Default)]
+pub struct ParsingContext {
+    /// 닫힌 최상위 블록들
+    nodes: Vec<BlockNode>,
+    /// 열린 블록 (바깥 → 안쪽)
+    open: Vec<Entry>,
+}
+
+/// 스택의 한 칸: 열린 블록과 tight/loose 판단용 빈 줄 정보
+#[derive(Debug)]
+struct Entry {
+    block: OpenBlock,
+    /// 마지막으로 이어 받은 줄이 빈 줄이었는지
+    last_line_blank: bool,
+    /// 마지막으로 닫힌 자식 블록이 빈 줄로 끝났는지
+    last_child_blank: bool,
+}
+
+impl ParsingContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 열린 블록 수
+    pub fn depth(&self) -> usize {
+        self.open.len()
+    }
+
+    /// 바깥에서 index번째 열린 블록
+    pub fn get_mut(&mut self, index: usize) -> &mut OpenBlock {
+        &mut self.open[index].block
+    }
+
+    /// 가장 안쪽 열린 블록
+    pub fn tip(&self) -> Option<&OpenBlock> {
+        self.open.last().map(|entry| &entry.block)
+    }
+
+    pub fn tip_mut(&mut self) -> Option<&mut OpenBlock> {
+        self.open.last_mut().map(|entry| &mut entry.block)
+    }
+
+    /// 바깥 depth개만 남기고 안쪽 블록을 모두 닫음
+    pub fn close_to(&mut self, depth: usize) {
+        while self.open.len() > depth {
+            self.close_tip();
+        }
+    }
+
+    /// 가장 안쪽 블록을 닫고 부모에 추가
+    pub fn close_tip(&mut self) {
+        let Some(entry) = self.open.pop() else {
+            return;
+        };
+        // List와 List Item은 마지막 자식이 빈 줄로 끝나도 빈 줄로 끝난 것
+        let ends_blank = entry.last_line_blank || (entry.block.is_list_or_item() && entry.last_child_blank);
+        self.append(entry.block.finalize(), ends_blank);
+    }
+
+    /// 새 블록 열기
+    /// 가장 안쪽 블록이 담을 수 없으면 닫고, List Item이면 필요할 때 List를 먼저 엶
+    pub fn open(&mut self, block: OpenBlock) {
+        self.prepare_child(block.item_marker());
+        self.open.push(Entry {
+            block,
+            last_line_blank: false,
+            last_child_blank: false,
+        });
+    }
+
+    /// 한 줄로 완성된 블록 추가 (ATX Heading, Thematic Break)
+    pub fn add_node(&mut self, node: BlockNode) {
+        self.prepare_child(None);
+        self.append(vec![node], false);
+    }
+
+    /// 가장 안쪽 Paragraph를 Setext Heading으로 닫음
+    /// 앞부분의 Link Reference Definition을 빼고 남은 내용이 없으면 Heading이 아님 (Example 215, 216)
+    /// end: 밑줄 끝 위치
+    pub fn close_setext_heading(&mut self, level: u8, end: usize) -> bool {
+        let Some(OpenBlock::Paragraph(ctx)) = self.tip() else {
+            return false;
+        };
+        let (mut nodes, raw) = paragraph::extract_definitions(&ctx.pending_lines);
+        let Some(span) = paragraph::raw_span(&raw) else {
+            return false;
+        };
+        nodes.push(BlockNode::heading(level, raw).with_span(extend_to(span, end)));
+        self.open.pop();
+        self.append(nodes, false);
+        true
+    }
+
+    /// 가장 안쪽 Paragraph의 마지막 줄을 헤더 행으로 Table 시작 (GFM)
+    /// 앞 줄들은 Paragraph로 닫음, 헤더 행과 구분 행의 셀 수가 다르면 Table이 아님
+    /// delimiter: 구분 행, indent: 구분 행의 들여쓰기, end: 구분 행 끝 위치
+    pub fn open_table(&mut self, delimiter: &str, indent: usize, end: usize) -> bool {
+        let Some(OpenBlock::Paragraph(ctx)) = self.tip() else {
+            return false;
+        };
+        let Some(header) = ctx.pending_lines.last() else {
+            return false;
+        };
+        let Ok(TableStartReason::Started(start)) = table::try_start(header, delimiter, indent) else {
+            return false;
+        };
+        let span = Span::from_offsets(start.header.span.start.offset, end);
+        if let Some(Entry { block: OpenBlock::Paragraph(mut ctx), .. }) = self.open.pop() {
+            ctx.pending_lines.pop();
+            self.append(paragraph::parse(&ctx.pending_lines), false);
+        }
+        self.open(OpenBlock::table(start, span));
+        true
+    }
+
+    /// 줄 처리를 마친 뒤 빈 줄 정보 갱신 (lazy continuation 줄은 제외)
+    /// 빈 줄이면 가장 안쪽 블록의 마지막 자식은 빈 줄로 끝난 것
+    /// Blockquote, Fenced Code Block, 방금 시작한 빈 아이템 안의 빈 줄은 tight/loose 판단에 쓰지 않음
+    pub fn mark_line(&mut self, blank: bool) {
+        let Some(tip) = self.open.last_mut() else {
+            return;
+        };
+        if blank && tip.block.has_children() {
+            tip.last_child_blank = true;
+        }
+        let counted = match &tip.block {
+            OpenBlock::Blockquote { .. } | OpenBlock::CodeBlockFenced { .. } => false,
+            OpenBlock::ListItem { .. } => tip.block.has_children(),
+            _ => true,
+        };
+        for entry in &mut self.open {
+            entry.last_line_blank = blank && counted;
+        }
+    }
+
+    /// 모든 블록을 닫고 문서의 블록들 반환
+    pub fn finish(mut self) -> Vec<BlockNode> {
+        self.close_to(0);
+        self.nodes
+    }
+
+    /// 자식 블록을 추가하기 전 준비 (item: 추가할 블록이 List Item이면 그 마커)
+    /// - 가장 안쪽 블록이 담을 수 없으면 닫음 (List 안의 Paragraph, 다른 종류의 List Item 등)
+    /// - List Item인데 가장 안쪽이 List가 아니면 List를 엶
+    /// - 빈 줄로 끝난 형제 다음이면 List는 loose
+    fn prepare_child(&mut self, item: Option<&ListMarker>) {
+        while self.tip().is_some_and(|tip| !tip.accepts(item)) {
+            self.close_tip();
+        }
+        if let Some(marker) = item
+            && !matches!(self.tip(), Some(OpenBlock::List { .. }))
+        {
+            self.open(OpenBlock::list(marker.clone()));
+        }
+
+        let Some(parent) = self.open.last() else {
+            return;
+        };
+        if !(parent.last_child_blank && parent.block.has_children()) {
+            return;
+        }
+        // 아이템 사이, 또는 아이템 안의 블록 사이에 빈 줄
+        let list_index = match parent.block {
+            OpenBlock::List { .. } => self.open.len() - 1,
+            OpenBlock::ListItem { .. } => self.open.len() - 2,
+            _ => return,
+        };
+        if let OpenBlock::List { tight, .. } = &mut self.open[list_index].block {
+            *tight = false;
+        }
+    }
+
+    /// 닫힌 블록을 부모(없으면 문서)에 추가
+    fn append(&mut self, nodes: Vec<BlockNode>, ends_blank: bool) {
+        match self.open.last_mut() {
+            Some(parent) => {
+                parent.block.push_children(nodes);
+                parent.last_child_blank = ends_blank;
+            }
+            None => self.nodes.extend(nodes),
+        }
+    }
 }
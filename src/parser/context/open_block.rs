@@ -0,0 +1,492 @@
+//! OpenBlock: 열린 블록 하나의 상태
+//!
+//! 컨테이너 블록(Blockquote/Callout, List, List Item, Footnote Definition)은 닫힌 자식 블록을 모으고,
+//! Leaf 블록(Paragraph, Code Block, Math Block, HTML Block, Comment, Table)은 내용 줄을 모읍니다.
+
+use super::ParagraphContext;
+use crate::node::{
+    BlockNode, BlockquoteNode, CodeBlockNode, FootnoteDefinitionNode, ListItemNode, ListNode, Span, TableRowNode,
+};
+use crate::parser::blockquote::CalloutHeader;
+use crate::parser::code_block_fenced::{self, CodeBlockFencedOk, CodeBlockFencedStart};
+use crate::parser::code_block_indented::{self, CodeBlockIndentedNotStartReason};
+use crate::parser::comment;
+use crate::parser::footnote_definition::{self, FootnoteDefinitionContinueReason};
+use crate::parser::helpers::trim_blank_lines;
+use crate::parser::html_block::{self, HtmlBlockStart};
+use crate::parser::line::Line;
+use crate::parser::list_item::{self, ListItemContinueReason, ListMarker};
+use crate::parser::math_block::{self, MathBlockStart};
+use crate::parser::source_map::{cover, extend_to, line_span};
+use crate::parser::table::{self, TableStart};
+use crate::parser::{blockquote, paragraph};
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// 열린 블록
+#[derive(Debug)]
+pub enum OpenBlock {
+    /// Blockquote (첫 줄이 `[!type]`이면 Callout)
+    Blockquote {
+        /// 첫 `>`부터 마지막 `>` 또는 마지막 자식 블록까지의 범위
+        span: Span,
+        /// Callout 헤더 (Obsidian)
+        callout: Option<CalloutHeader>,
+        /// 닫힌 자식 블록
+        children: Vec<BlockNode>,
+    },
+
+    /// List (같은 종류의 List Item만 담음)
+    List {
+        /// 첫 아이템의 마커 (리스트 타입과 시작 번호)
+        marker: ListMarker,
+        /// 닫힌 아이템
+        items: Vec<ListItemNode>,
+        /// tight 리스트 여부 (아이템 사이나 아이템 안 블록 사이에 빈 줄 없음)
+        tight: bool,
+    },
+
+    /// List Item
+    ListItem {
+        /// 아이템 마커
+        marker: ListMarker,
+        /// Task list 체크 상태 (GFM)
+        checked: Option<bool>,
+        /// 내용 시작 위치 (이어지는 줄은 이만큼 들여써야 함)
+        content_indent: usize,
+        /// 마커부터 마지막 자식 블록까지의 범위
+        span: Span,
+        /// 닫힌 자식 블록
+        children: Vec<BlockNode>,
+    },
+
+    /// Footnote Definition (이어지는 줄은 4칸 들여쓰기)
+    FootnoteDefinition {
+        /// 정의 label (`^` 제외)
+        label: String,
+        /// `[^label]:` 마커부터 마지막 자식 블록까지의 범위
+        span: Span,
+        /// 닫힌 자식 블록
+        children: Vec<BlockNode>,
+    },
+
+    /// Paragraph (여러 줄이 하나의 문단)
+    Paragraph(ParagraphContext),
+
+    /// Fenced Code Block
+    CodeBlockFenced {
+        /// 시작 정보 (불변)
+        start: CodeBlockFencedStart,
+        /// 축적된 코드 줄
+        content: Vec<String>,
+        /// 여는 펜스부터 마지막 줄까지의 범위
+        span: Span,
+    },
+
+    /// Indented Code Block
+    CodeBlockIndented {
+        /// 축적된 코드 줄 (4칸 들여쓰기 제거 후, 빈 줄 포함)
+        lines: Vec<String>,
+        /// 첫 코드 줄부터 마지막 코드 줄까지의 범위
+        span: Span,
+    },
+
+    /// Math Block (`$$`로 끝나는 줄까지 TeX 원문 줄 수집)
+    MathBlock {
+        /// 시작 정보 (여는 `$$`의 들여쓰기)
+        start: MathBlockStart,
+        /// 축적된 수식 줄 (여는 줄과 닫는 줄의 `$$` 제외)
+        lines: Vec<String>,
+        /// 여는 `$$`부터 닫는 `$$`까지의 범위
+        span: Span,
+    },
+
+    /// HTML Block (종료 조건까지 원문 줄 수집)
+    HtmlBlock {
+        /// 시작 정보 (종료 조건 결정용)
+        start: HtmlBlockStart,
+        /// 축적된 원문 줄
+        lines: Vec<String>,
+        /// 첫 줄부터 마지막 줄까지의 범위
+        span: Span,
+    },
+
+    /// Obsidian 블록 주석 (`%%`로 끝나는 줄까지 원문 줄 수집)
+    Comment {
+        /// 축적된 원문 줄 (여는 줄과 닫는 줄의 `%%` 제외)
+        lines: Vec<String>,
+        /// 여는 `%%`부터 닫는 `%%`까지의 범위
+        span: Span,
+    },
+
+    /// Table (GFM, 빈 줄이나 다른 블록 시작까지 본문 행 수집)
+    Table {
+        /// 시작 정보 (열 정렬과 헤더 행)
+        start: TableStart,
+        /// 축적된 본문 행
+        rows: Vec<TableRowNode>,
+        /// 헤더 행부터 마지막 행까지의 범위
+        span: Span,
+    },
+}
+
+/// 열린 블록이 줄을 이어 받은 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum Continuation {
+    /// 이어 받음 (블록의 마커와 들여쓰기는 소비됨)
+    Matched,
+    /// 이어 받지 못함 (이 블록과 안쪽 블록은 닫히거나 lazy continuation)
+    Unmatched,
+    /// 줄을 모두 소비하고 블록이 끝남 (닫는 펜스, 닫는 수식/주석 줄)
+    Closed,
+}
+
+// =============================================================================
+// 생성
+// =============================================================================
+
+impl OpenBlock {
+    /// Blockquote 시작 (span: 첫 `>` 위치)
+    pub fn blockquote(span: Span) -> Self {
+        OpenBlock::Blockquote {
+            span,
+            callout: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Callout 시작 (span: 첫 `>`부터 헤더 줄 끝까지)
+    pub fn callout(header: CalloutHeader, span: Span) -> Self {
+        OpenBlock::Blockquote {
+            span,
+            callout: Some(header),
+            children: Vec::new(),
+        }
+    }
+
+    /// 빈 List 시작 (첫 아이템의 마커)
+    pub fn list(marker: ListMarker) -> Self {
+        OpenBlock::List {
+            marker,
+            items: Vec::new(),
+            tight: true,
+        }
+    }
+
+    /// Footnote Definition 시작 (span: `[^label]:` 마커)
+    pub fn footnote_definition(label: String, span: Span) -> Self {
+        OpenBlock::FootnoteDefinition {
+            label,
+            span,
+            children: Vec::new(),
+        }
+    }
+
+    /// Table 시작 (span: 헤더 행부터 구분 행까지)
+    pub fn table(start: TableStart, span: Span) -> Self {
+        OpenBlock::Table { start, rows: Vec::new(), span }
+    }
+
+    /// Paragraph 시작 줄
+    pub fn paragraph(line: &Line) -> Self {
+        OpenBlock::Paragraph(ParagraphContext::new(vec![super::paragraph_context::paragraph_line(line)]))
+    }
+}
+
+// =============================================================================
+// 줄 처리
+// =============================================================================
+
+impl OpenBlock {
+    /// 줄의 남은 부분을 이어 받는지 확인하고, 이어 받으면 블록의 마커와 들여쓰기를 소비
+    /// has_open_child: 안쪽에 열린 블록이 있는지 (빈 아이템 판단용)
+    pub fn continue_line(&mut self, line: &mut Line, has_open_child: bool) -> Continuation {
+        match self {
+            OpenBlock::Blockquote { span, .. } => match blockquote::parse(line.rest(), line.column()) {
+                Ok(marker) => {
+                    let marker_end = line.offset() + marker.indent + 1;
+                    *span = extend_to(*span, span.end.offset.max(marker_end));
+                    // 마커 뒤 공백 한 칸은 마커의 일부 (탭이면 한 칸만)
+                    line.advance(marker.indent + 1);
+                    line.skip_indent(1);
+                    Continuation::Matched
+                }
+                Err(_) => Continuation::Unmatched,
+            },
+            // List는 항상 이어 받음 (아이템이 끝나면 새 아이템 또는 다른 블록이 List를 닫음)
+            OpenBlock::List { .. } => Continuation::Matched,
+            OpenBlock::ListItem {
+                content_indent,
+                children,
+                ..
+            } => {
+                let has_content = !children.is_empty() || has_open_child;
+                match list_item::try_continue(line.rest(), line.column(), *content_indent, has_content) {
+                    Ok(ListItemContinueReason::Blank) => {
+                        line.skip_whitespace();
+                        Continuation::Matched
+                    }
+                    Ok(ListItemContinueReason::Indented) => {
+                        line.skip_indent(*content_indent);
+                        Continuation::Matched
+                    }
+                    Err(_) => Continuation::Unmatched,
+                }
+            }
+            OpenBlock::FootnoteDefinition { .. } => match footnote_definition::try_continue(line.rest(), line.column()) {
+                Ok(FootnoteDefinitionContinueReason::Blank) => {
+                    line.skip_whitespace();
+                    Continuation::Matched
+                }
+                Ok(FootnoteDefinitionContinueReason::Indented) => {
+                    line.skip_indent(footnote_definition::CONTENT_INDENT);
+                    Continuation::Matched
+                }
+                Err(_) => Continuation::Unmatched,
+            },
+            OpenBlock::Paragraph(_) | OpenBlock::Table { .. } => match line.is_blank() {
+                true => Continuation::Unmatched,
+                false => Continuation::Matched,
+            },
+            OpenBlock::CodeBlockFenced { start, span, .. } => {
+                match code_block_fenced::parse(line.rest(), line.column(), Some(start)) {
+                    Ok(CodeBlockFencedOk::End) => {
+                        *span = extend_to(*span, line_span(line.rest(), line.offset()).end.offset);
+                        Continuation::Closed
+                    }
+                    _ => {
+                        // 여는 펜스의 들여쓰기만큼 제거
+                        line.skip_indent(start.indent);
+                        Continuation::Matched
+                    }
+                }
+            }
+            OpenBlock::CodeBlockIndented { .. } => match code_block_indented::try_start(line.rest(), line.column()) {
+                Ok(_) => {
+                    line.skip_indent(4);
+                    Continuation::Matched
+                }
+                // 4칸 미만 빈 줄도 코드 블록에 포함 (끝의 빈 줄은 마무리에서 제거)
+                Err(CodeBlockIndentedNotStartReason::Empty) => {
+                    line.skip_whitespace();
+                    Continuation::Matched
+                }
+                Err(CodeBlockIndentedNotStartReason::InsufficientIndent) => Continuation::Unmatched,
+            },
+            OpenBlock::MathBlock { start, lines, span } => {
+                // 여는 `$$`의 들여쓰기만큼 제거
+                line.skip_indent(start.indent);
+                match math_block::try_end(&line.content()) {
+                    Some(content) => {
+                        if !content.is_empty() {
+                            lines.push(content.to_string());
+                        }
+                        *span = extend_to(*span, line_span(line.rest(), line.offset()).end.offset);
+                        Continuation::Closed
+                    }
+                    None => Continuation::Matched,
+                }
+            }
+            OpenBlock::Comment { lines, span } => match comment::try_end(&line.content()) {
+                Some(content) => {
+                    if !content.is_empty() {
+                        lines.push(content.to_string());
+                    }
+                    *span = extend_to(*span, line_span(line.rest(), line.offset()).end.offset);
+                    Continuation::Closed
+                }
+                None => Continuation::Matched,
+            },
+            // 조건 6, 7: 빈 줄에서 종료 (빈 줄은 포함하지 않음)
+            OpenBlock::HtmlBlock { start, .. } => match start.kind.ends_at_blank_line() && line.is_blank() {
+                true => Continuation::Unmatched,
+                false => Continuation::Matched,
+            },
+        }
+    }
+
+    /// 줄 내용을 받는 블록인지 (Paragraph, Code Block, Math Block, HTML Block, Comment, Table)
+    pub fn accepts_lines(&self) -> bool {
+        matches!(
+            self,
+            OpenBlock::Paragraph(_)
+                | OpenBlock::CodeBlockFenced { .. }
+                | OpenBlock::CodeBlockIndented { .. }
+                | OpenBlock::MathBlock { .. }
+                | OpenBlock::HtmlBlock { .. }
+                | OpenBlock::Comment { .. }
+                | OpenBlock::Table { .. }
+        )
+    }
+
+    pub fn is_paragraph(&self) -> bool {
+        matches!(self, OpenBlock::Paragraph(_))
+    }
+
+    /// 내용 줄 대신 새 블록이 시작될 수 있는 Leaf 블록인지 (Paragraph, Table)
+    pub fn is_interruptible(&self) -> bool {
+        matches!(self, OpenBlock::Paragraph(_) | OpenBlock::Table { .. })
+    }
+
+    /// 줄의 남은 부분을 내용으로 추가
+    /// 반환: 블록이 이 줄에서 끝났는지 (HTML Block 조건 1~5의 종료 조건)
+    /// 일부만 소비한 탭의 남은 칸은 공백으로 채움 (Example 6, 7)
+    pub fn add_line(&mut self, line: &Line) -> bool {
+        let text = line.content();
+        match self {
+            OpenBlock::Paragraph(ctx) => {
+                ctx.push_line(line);
+                false
+            }
+            OpenBlock::CodeBlockFenced { content, span, .. } => {
+                content.push(text.into_owned());
+                *span = extend_span_to_line(*span, line);
+                false
+            }
+            OpenBlock::CodeBlockIndented { lines, span } => {
+                lines.push(text.into_owned());
+                *span = extend_span_to_line(*span, line);
+                false
+            }
+            OpenBlock::HtmlBlock { start, lines, span } => {
+                let ends = start.kind.is_end(&text);
+                lines.push(text.into_owned());
+                *span = extend_span_to_line(*span, line);
+                ends
+            }
+            OpenBlock::MathBlock { lines, span, .. } | OpenBlock::Comment { lines, span } => {
+                lines.push(text.into_owned());
+                *span = extend_span_to_line(*span, line);
+                false
+            }
+            OpenBlock::Table { start, rows, span } => {
+                let rest = line.rest();
+                let row = table::parse_row(rest, line.offset(), start.alignments.len());
+                *span = extend_to(*span, row.span.end.offset);
+                rows.push(row);
+                false
+            }
+            OpenBlock::Blockquote { .. }
+            | OpenBlock::List { .. }
+            | OpenBlock::ListItem { .. }
+            | OpenBlock::FootnoteDefinition { .. } => {
+                unreachable!("container blocks do not accept lines")
+            }
+        }
+    }
+}
+
+// =============================================================================
+// 자식 블록
+// =============================================================================
+
+impl OpenBlock {
+    /// 자식으로 담을 수 있는지 (item: 자식이 List Item이면 그 마커)
+    /// Blockquote와 List Item은 List Item을 직접 담지 않지만, 사이에 List를 열어 담음
+    pub fn accepts(&self, item: Option<&ListMarker>) -> bool {
+        match self {
+            OpenBlock::List { marker, .. } => item.is_some_and(|item| marker.is_same_type(item)),
+            OpenBlock::Blockquote { .. } | OpenBlock::ListItem { .. } | OpenBlock::FootnoteDefinition { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// List Item이면 그 마커
+    pub fn item_marker(&self) -> Option<&ListMarker> {
+        match self {
+            OpenBlock::ListItem { marker, .. } => Some(marker),
+            _ => None,
+        }
+    }
+
+    pub fn is_list_or_item(&self) -> bool {
+        matches!(self, OpenBlock::List { .. } | OpenBlock::ListItem { .. })
+    }
+
+    /// 닫힌 자식 블록이 있는지
+    pub fn has_children(&self) -> bool {
+        match self {
+            OpenBlock::Blockquote { children, .. }
+            | OpenBlock::ListItem { children, .. }
+            | OpenBlock::FootnoteDefinition { children, .. } => !children.is_empty(),
+            OpenBlock::List { items, .. } => !items.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// 닫힌 자식 블록 추가 (컨테이너 범위는 자식 끝까지 늘림)
+    pub fn push_children(&mut self, nodes: Vec<BlockNode>) {
+        match self {
+            OpenBlock::Blockquote { span, children, .. }
+            | OpenBlock::ListItem { span, children, .. }
+            | OpenBlock::FootnoteDefinition { span, children, .. } => {
+                for node in nodes {
+                    *span = extend_to(*span, span.end.offset.max(node.span().end.offset));
+                    children.push(node);
+                }
+            }
+            OpenBlock::List { items, .. } => {
+                for node in nodes {
+                    match node {
+                        BlockNode::ListItem(item) => items.push(item),
+                        _ => unreachable!("list contains only list items"),
+                    }
+                }
+            }
+            _ => unreachable!("leaf blocks have no children"),
+        }
+    }
+}
+
+// =============================================================================
+// 마무리
+// =============================================================================
+
+impl OpenBlock {
+    /// 블록을 닫아 노드 생성
+    /// Paragraph는 앞부분의 Link Reference Definition 때문에 여러 노드가 될 수 있음
+    pub fn finalize(self) -> Vec<BlockNode> {
+        match self {
+            OpenBlock::Blockquote { span, callout, children } => match callout {
+                Some(header) => vec![blockquote::finalize_callout(header, children).with_span(span)],
+                None => vec![BlockNode::Blockquote(BlockquoteNode::new(children)).with_span(span)],
+            },
+            OpenBlock::List { marker, items, tight } => {
+                let (list_type, start) = marker.to_list_type();
+                let span = match (items.first(), items.last()) {
+                    (Some(first), Some(last)) => cover(first.span, last.span),
+                    _ => Span::default(),
+                };
+                vec![BlockNode::List(ListNode::new(list_type, start, tight, items)).with_span(span)]
+            }
+            OpenBlock::ListItem { span, children, checked, .. } => {
+                vec![BlockNode::ListItem(ListItemNode { checked, span, ..ListItemNode::new(children) })]
+            }
+            OpenBlock::FootnoteDefinition { label, span, children } => {
+                vec![BlockNode::FootnoteDefinition(FootnoteDefinitionNode::new(&label, children)).with_span(span)]
+            }
+            OpenBlock::Paragraph(ctx) => paragraph::parse(&ctx.pending_lines),
+            OpenBlock::CodeBlockFenced { start, content, span } => {
+                vec![code_block_fenced::finalize(start, content).with_span(span)]
+            }
+            OpenBlock::CodeBlockIndented { lines, span } => {
+                let content = trim_blank_lines(lines);
+                vec![BlockNode::CodeBlock(CodeBlockNode::new(None, content)).with_span(span)]
+            }
+            OpenBlock::MathBlock { lines, span, .. } => vec![math_block::finalize(lines).with_span(span)],
+            OpenBlock::HtmlBlock { lines, span, .. } => vec![html_block::finalize(lines).with_span(span)],
+            OpenBlock::Comment { lines, span } => vec![comment::finalize(lines).with_span(span)],
+            OpenBlock::Table { start, rows, span } => vec![table::finalize(start, rows).with_span(span)],
+        }
+    }
+}
+
+/// 빈 줄이 아니면 범위 끝을 줄 끝까지 늘림
+fn extend_span_to_line(span: Span, line: &Line) -> Span {
+    if line.is_blank() {
+        return span;
+    }
+    extend_to(span, line_span(line.rest(), line.offset()).end.offset)
+}
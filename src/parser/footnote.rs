@@ -0,0 +1,271 @@
+//! 각주 번호 매기기
+//!
+//! 인라인 파싱이 끝난 블록 트리에서 각주 참조를 처음 참조된 순서대로 1부터 번호 매깁니다.
+//! 정의되지 않은 label의 참조와 참조되지 않은 정의는 번호 없이(None) 남겨 표시합니다.
+//! 인라인 각주(`^[text]`)는 참조가 곧 정의이므로 항상 새 번호를 받습니다.
+//! label은 Link Reference Definition과 같은 규칙으로 정규화해 비교합니다.
+
+use std::collections::{HashMap, HashSet};
+
+use super::inline::link::normalize_label;
+use crate::node::{BlockNode, FootnoteReferenceNode, InlineNode};
+
+/// 번호 매기기 상태
+#[derive(Debug, Default)]
+struct Numbering {
+    /// 정의된 label (정규화)
+    defined: HashSet<String>,
+    /// label (정규화) → (번호, 참조 횟수)
+    numbers: HashMap<String, (usize, usize)>,
+    /// 마지막으로 매긴 번호
+    last: usize,
+}
+
+impl Numbering {
+    /// 참조 하나에 번호 매기기
+    fn reference(&mut self, node: &mut FootnoteReferenceNode) {
+        if node.inline.is_some() {
+            self.last += 1;
+            node.index = Some(self.last);
+            node.occurrence = 1;
+            return;
+        }
+
+        let label = normalize_label(&node.label);
+        if !self.defined.contains(&label) {
+            return;
+        }
+        let (index, count) = self.numbers.entry(label).or_insert_with(|| {
+            self.last += 1;
+            (self.last, 0)
+        });
+        *count += 1;
+        node.index = Some(*index);
+        node.occurrence = *count;
+    }
+}
+
+/// 문서 전체의 각주 참조와 정의에 번호 매기기
+/// 같은 label이 여러 번 정의되면 먼저 나온 정의만 번호를 받음
+pub(crate) fn number(mut blocks: Vec<BlockNode>) -> Vec<BlockNode> {
+    let mut numbering = Numbering::default();
+    visit_blocks(&mut blocks, &mut |block| {
+        if let BlockNode::FootnoteDefinition(node) = block {
+            numbering.defined.insert(normalize_label(&node.label));
+        }
+    });
+
+    visit_blocks(&mut blocks, &mut |block| {
+        for inlines in inline_children(block) {
+            visit_references(inlines, &mut |node| numbering.reference(node));
+        }
+    });
+
+    let mut numbered = HashSet::new();
+    visit_blocks(&mut blocks, &mut |block| {
+        let BlockNode::FootnoteDefinition(node) = block else {
+            return;
+        };
+        let label = normalize_label(&node.label);
+        if let Some(&(index, count)) = numbering.numbers.get(&label)
+            && numbered.insert(label)
+        {
+            node.index = Some(index);
+            node.ref_count = count;
+        }
+    });
+
+    blocks
+}
+
+/// 모든 블록을 앞에서부터 순회 (부모 → 자식)
+pub(super) fn visit_blocks(blocks: &mut [BlockNode], f: &mut impl FnMut(&mut BlockNode)) {
+    for block in blocks {
+        f(block);
+        match block {
+            BlockNode::Blockquote(node) => visit_blocks(&mut node.children, f),
+            BlockNode::Callout(node) => visit_blocks(&mut node.children, f),
+            BlockNode::List(node) => {
+                for item in &mut node.children {
+                    visit_blocks(&mut item.children, f);
+                }
+            }
+            BlockNode::ListItem(node) => visit_blocks(&mut node.children, f),
+            BlockNode::FootnoteDefinition(node) => visit_blocks(&mut node.children, f),
+            _ => {}
+        }
+    }
+}
+
+/// 블록이 직접 담은 인라인 목록들 (Paragraph, Heading, Callout 제목, Table 셀)
+pub(super) fn inline_children(block: &mut BlockNode) -> Vec<&mut Vec<InlineNode>> {
+    match block {
+        BlockNode::Paragraph(node) => vec![&mut node.children],
+        BlockNode::Heading(node) => vec![&mut node.children],
+        BlockNode::Callout(node) => vec![&mut node.title],
+        BlockNode::Table(node) => std::iter::once(&mut node.header)
+            .chain(node.rows.iter_mut())
+            .flat_map(|row| row.cells.iter_mut().map(|cell| &mut cell.children))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// 인라인 트리의 각주 참조를 앞에서부터 순회 (인라인 각주 안의 참조는 그 각주 다음)
+fn visit_references(inlines: &mut [InlineNode], f: &mut impl FnMut(&mut FootnoteReferenceNode)) {
+    for inline in inlines {
+        match inline {
+            InlineNode::FootnoteReference(node) => {
+                f(node);
+                if let Some(children) = &mut node.inline {
+                    visit_references(children, f);
+                }
+            }
+            InlineNode::Emphasis(node) => visit_references(&mut node.children, f),
+            InlineNode::Strong(node) => visit_references(&mut node.children, f),
+            InlineNode::Strikethrough(node) => visit_references(&mut node.children, f),
+            InlineNode::Highlight(node) => visit_references(&mut node.children, f),
+            InlineNode::Link(node) => visit_references(&mut node.children, f),
+            InlineNode::Image(node) => visit_references(&mut node.children, f),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, FootnoteDefinitionNode, FootnoteReferenceNode, InlineNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
+    use rstest::rstest;
+
+    #[rstest]
+    // 처음 참조된 순서대로 번호 (정의 순서와 무관)
+    #[case(
+        "a[^x] b[^y]\n\n[^y]: Y\n[^x]: X",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("x").numbered(1, 1)),
+                InlineNode::text(" b"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("y").numbered(2, 1)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new("y", vec![BlockNode::paragraph(vec![InlineNode::text("Y")])])
+                    .numbered(2, 1),
+            ),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new("x", vec![BlockNode::paragraph(vec![InlineNode::text("X")])])
+                    .numbered(1, 1),
+            ),
+        ]
+    )]
+    // 같은 각주를 여러 번 참조, label은 대소문자 무시
+    #[case(
+        "a[^x] b[^X]\n\n[^x]: X",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("x").numbered(1, 1)),
+                InlineNode::text(" b"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("X").numbered(1, 2)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new("x", vec![BlockNode::paragraph(vec![InlineNode::text("X")])])
+                    .numbered(1, 2),
+            ),
+        ]
+    )]
+    // 정의되지 않은 참조와 참조되지 않은 정의는 번호 없음
+    #[case(
+        "a[^missing]\n\n[^unused]: U",
+        vec![
+            BlockNode::paragraph(vec![InlineNode::text("a"), InlineNode::footnote_reference("missing")]),
+            BlockNode::FootnoteDefinition(FootnoteDefinitionNode::new(
+                "unused",
+                vec![BlockNode::paragraph(vec![InlineNode::text("U")])],
+            )),
+        ]
+    )]
+    // 같은 label의 두 번째 정의는 번호 없음
+    #[case(
+        "a[^x]\n\n[^x]: first\n\n[^x]: second",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("x").numbered(1, 1)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new("x", vec![BlockNode::paragraph(vec![InlineNode::text("first")])])
+                    .numbered(1, 1),
+            ),
+            BlockNode::FootnoteDefinition(FootnoteDefinitionNode::new(
+                "x",
+                vec![BlockNode::paragraph(vec![InlineNode::text("second")])],
+            )),
+        ]
+    )]
+    // 인라인 각주도 같은 순서로 번호
+    #[case(
+        "a[^x] b^[inline *note*] c[^x]\n\n[^x]: X",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("x").numbered(1, 1)),
+                InlineNode::text(" b"),
+                InlineNode::FootnoteReference(
+                    FootnoteReferenceNode::inline(vec![
+                        InlineNode::text("inline "),
+                        InlineNode::emphasis(vec![InlineNode::text("note")]),
+                    ])
+                    .numbered(2, 1),
+                ),
+                InlineNode::text(" c"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("x").numbered(1, 2)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new("x", vec![BlockNode::paragraph(vec![InlineNode::text("X")])])
+                    .numbered(1, 2),
+            ),
+        ]
+    )]
+    fn test_number(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, expected);
+    }
+
+    #[rstest]
+    // 인라인 링크가 우선
+    #[case("[^x](/url)", vec![InlineNode::link("/url", None, vec![InlineNode::text("^x")])])]
+    // 공백이 있거나 빈 label은 참조 아님
+    #[case("[^a b] [^]", vec![InlineNode::text("[^a b] [^]")])]
+    // 이미지 자리는 참조 아님
+    #[case("![^x]", vec![InlineNode::text("![^x]")])]
+    // 닫히지 않은 인라인 각주
+    #[case("^[open", vec![InlineNode::text("^[open")])]
+    // 인라인 각주 안의 링크
+    #[case(
+        "^[see [a](/b)]",
+        vec![InlineNode::FootnoteReference(
+            FootnoteReferenceNode::inline(vec![
+                InlineNode::text("see "),
+                InlineNode::link("/b", None, vec![InlineNode::text("a")]),
+            ])
+            .numbered(1, 1),
+        )]
+    )]
+    fn test_footnote_reference_syntax(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[test]
+    fn test_footnote_reference_span() {
+        let doc = parse("a[^x] ^[b]");
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        let actual: Vec<LineColumns> = node.children.iter().map(|child| line_columns(child.span())).collect();
+        assert_eq!(actual, vec![((1, 1), (1, 2)), ((1, 2), (1, 6)), ((1, 6), (1, 7)), ((1, 7), (1, 11))]);
+    }
+}
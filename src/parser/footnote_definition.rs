@@ -0,0 +1,277 @@
+//! 각주 정의 파서 (GitHub/Obsidian 확장)
+//!
+//! `[^label]:`로 시작하는 줄을 감지합니다.
+//! 각주 정의는 List Item처럼 자식 블록을 담는 컨테이너이며,
+//! 이어지는 줄은 4칸 이상 들여써야 합니다 (Paragraph의 lazy continuation은 허용).
+
+use super::helpers::calculate_indent;
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// 이어지는 줄의 내용 시작 위치 (들여쓰기 칸 수)
+pub(crate) const CONTENT_INDENT: usize = 4;
+
+/// 각주 정의 시작 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct FootnoteDefinitionStart {
+    /// 정의 label (`^` 제외)
+    pub label: String,
+    /// `[` 앞 들여쓰기 (byte)
+    pub indent: usize,
+    /// `[^label]:` 마커 길이 (byte)
+    pub marker_len: usize,
+}
+
+/// 각주 정의 시작 성공 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum FootnoteDefinitionStartReason {
+    /// 정상적인 시작
+    Started(FootnoteDefinitionStart),
+}
+
+/// 각주 정의 시작 아님 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum FootnoteDefinitionNotStartReason {
+    /// 4칸 이상 들여쓰기 (indented code block으로 해석됨)
+    CodeBlockIndented,
+    /// `[^`로 시작하지 않음
+    NotDefinitionMarker,
+    /// label이 비었거나 공백을 포함하거나 `]:`로 닫히지 않음
+    InvalidLabel,
+}
+
+/// 각주 정의 계속 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum FootnoteDefinitionContinueReason {
+    /// 빈 줄
+    Blank,
+    /// 4칸 이상 들여쓴 줄
+    Indented,
+}
+
+/// 각주 정의 종료 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum FootnoteDefinitionEndReason {
+    /// 4칸 미만 들여쓴 줄
+    InsufficientIndent,
+}
+
+// =============================================================================
+// 함수
+// =============================================================================
+
+/// 각주 정의 시작 줄인지 확인
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+pub(crate) fn try_start(
+    line: &str,
+    column: usize,
+) -> Result<FootnoteDefinitionStartReason, FootnoteDefinitionNotStartReason> {
+    if calculate_indent(line, column) > 3 {
+        return Err(FootnoteDefinitionNotStartReason::CodeBlockIndented);
+    }
+
+    let rest = line.trim_start_matches([' ', '\t']);
+    let Some(after_caret) = rest.strip_prefix("[^") else {
+        return Err(FootnoteDefinitionNotStartReason::NotDefinitionMarker);
+    };
+
+    let label_len = after_caret.find(']').ok_or(FootnoteDefinitionNotStartReason::InvalidLabel)?;
+    let label = &after_caret[..label_len];
+    if !is_valid_label(label) || !after_caret[label_len..].starts_with("]:") {
+        return Err(FootnoteDefinitionNotStartReason::InvalidLabel);
+    }
+
+    Ok(FootnoteDefinitionStartReason::Started(FootnoteDefinitionStart {
+        label: label.to_string(),
+        indent: line.len() - rest.len(),
+        marker_len: "[^".len() + label_len + "]:".len(),
+    }))
+}
+
+/// 열린 각주 정의가 줄을 이어 받는지 확인
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+pub(crate) fn try_continue(
+    line: &str,
+    column: usize,
+) -> Result<FootnoteDefinitionContinueReason, FootnoteDefinitionEndReason> {
+    if line.trim().is_empty() {
+        return Ok(FootnoteDefinitionContinueReason::Blank);
+    }
+    if calculate_indent(line, column) >= CONTENT_INDENT {
+        return Ok(FootnoteDefinitionContinueReason::Indented);
+    }
+    Err(FootnoteDefinitionEndReason::InsufficientIndent)
+}
+
+/// 각주 label: 비어 있지 않고 공백이 없음 (참조 `[^label]`과 같은 규칙)
+pub(crate) fn is_valid_label(label: &str) -> bool {
+    !label.is_empty() && !label.contains(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{BlockNode, FootnoteDefinitionNode, FootnoteReferenceNode, InlineNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
+    use rstest::rstest;
+
+    fn start(label: &str, indent: usize) -> FootnoteDefinitionStartReason {
+        FootnoteDefinitionStartReason::Started(FootnoteDefinitionStart {
+            label: label.to_string(),
+            indent,
+            marker_len: label.len() + 4,
+        })
+    }
+
+    #[rstest]
+    #[case("[^1]: foo", Ok(start("1", 0)))]
+    #[case("   [^note]:", Ok(start("note", 3)))]
+    #[case("[^a-b_c]:bar", Ok(start("a-b_c", 0)))]
+    #[case("    [^1]: foo", Err(FootnoteDefinitionNotStartReason::CodeBlockIndented))]
+    #[case("[1]: foo", Err(FootnoteDefinitionNotStartReason::NotDefinitionMarker))]
+    #[case("[^]: foo", Err(FootnoteDefinitionNotStartReason::InvalidLabel))]
+    #[case("[^a b]: foo", Err(FootnoteDefinitionNotStartReason::InvalidLabel))]
+    #[case("[^1] foo", Err(FootnoteDefinitionNotStartReason::InvalidLabel))]
+    #[case("[^1", Err(FootnoteDefinitionNotStartReason::InvalidLabel))]
+    fn test_try_start(
+        #[case] line: &str,
+        #[case] expected: Result<FootnoteDefinitionStartReason, FootnoteDefinitionNotStartReason>,
+    ) {
+        assert_eq!(try_start(line, 0), expected);
+    }
+
+    #[rstest]
+    #[case("", Ok(FootnoteDefinitionContinueReason::Blank))]
+    #[case("    foo", Ok(FootnoteDefinitionContinueReason::Indented))]
+    #[case("\tfoo", Ok(FootnoteDefinitionContinueReason::Indented))]
+    #[case("   foo", Err(FootnoteDefinitionEndReason::InsufficientIndent))]
+    fn test_try_continue(
+        #[case] line: &str,
+        #[case] expected: Result<FootnoteDefinitionContinueReason, FootnoteDefinitionEndReason>,
+    ) {
+        assert_eq!(try_continue(line, 0), expected);
+    }
+
+    #[rstest]
+    // 기본 정의와 참조
+    #[case(
+        "a[^1]\n\n[^1]: foo",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("1").numbered(1, 1)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new("1", vec![BlockNode::paragraph(vec![InlineNode::text("foo")])])
+                    .numbered(1, 1),
+            ),
+        ]
+    )]
+    // 4칸 들여쓴 줄은 같은 정의의 블록
+    #[case(
+        "a[^n]\n\n[^n]: foo\n\n    bar\n\n        code\nbaz",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("n").numbered(1, 1)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new(
+                    "n",
+                    vec![
+                        BlockNode::paragraph(vec![InlineNode::text("foo")]),
+                        BlockNode::paragraph(vec![InlineNode::text("bar")]),
+                        BlockNode::code_block(None, "code"),
+                    ],
+                )
+                .numbered(1, 1),
+            ),
+            BlockNode::paragraph(vec![InlineNode::text("baz")]),
+        ]
+    )]
+    // Paragraph의 lazy continuation
+    #[case(
+        "a[^1]\n\n[^1]: foo\nbar",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("1").numbered(1, 1)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new(
+                    "1",
+                    vec![BlockNode::paragraph(vec![
+                        InlineNode::text("foo"),
+                        InlineNode::soft_break(),
+                        InlineNode::text("bar"),
+                    ])],
+                )
+                .numbered(1, 1),
+            ),
+        ]
+    )]
+    // 들여쓰지 않은 줄에서 끝남
+    #[case(
+        "a[^1]\n\n[^1]: foo\n\nbar",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("1").numbered(1, 1)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new("1", vec![BlockNode::paragraph(vec![InlineNode::text("foo")])])
+                    .numbered(1, 1),
+            ),
+            BlockNode::paragraph(vec![InlineNode::text("bar")]),
+        ]
+    )]
+    // Paragraph를 인터럽트
+    #[case(
+        "a[^1]\n[^1]: foo",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("a"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("1").numbered(1, 1)),
+            ]),
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new("1", vec![BlockNode::paragraph(vec![InlineNode::text("foo")])])
+                    .numbered(1, 1),
+            ),
+        ]
+    )]
+    // 정의 안의 블록
+    #[case(
+        "[^1]: > quote\n\nx[^1]",
+        vec![
+            BlockNode::FootnoteDefinition(
+                FootnoteDefinitionNode::new(
+                    "1",
+                    vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![
+                        InlineNode::text("quote"),
+                    ])])],
+                )
+                .numbered(1, 1),
+            ),
+            BlockNode::paragraph(vec![
+                InlineNode::text("x"),
+                InlineNode::FootnoteReference(FootnoteReferenceNode::new("1").numbered(1, 1)),
+            ]),
+        ]
+    )]
+    fn test_footnote_definition(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, expected);
+    }
+
+    #[rstest]
+    // 마커부터 마지막 자식 블록까지
+    #[case("[^1]: foo\n    bar", ((1, 1), (2, 8)))]
+    #[case("  [^1]:", ((1, 3), (1, 8)))]
+    fn test_footnote_definition_span(#[case] input: &str, #[case] expected: LineColumns) {
+        let doc = parse(input);
+        assert_eq!(line_columns(doc.children[0].span()), expected);
+    }
+}
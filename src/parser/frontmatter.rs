@@ -0,0 +1,103 @@
+//! YAML frontmatter (Obsidian properties, Jekyll 등)
+//!
+//! 문서 첫 줄이 `---`이고 뒤에 닫는 `---`(또는 `...`) 줄이 있으면 그 사이를 frontmatter로 떼어냅니다.
+//! 블록 파싱은 닫는 줄 다음부터 시작하므로 `---`가 Thematic Break나 Setext 밑줄로 해석되지 않습니다.
+//! 닫는 줄이 없으면 frontmatter가 아니며 문서 전체를 그대로 파싱합니다.
+
+use super::source_map::lines_with_offsets;
+use crate::node::{Frontmatter, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrontmatterErr {
+    /// 첫 줄이 `---`가 아님
+    NoOpeningFence,
+    /// 닫는 구분선 없음
+    Unclosed,
+}
+
+/// 문서 앞의 frontmatter와 본문 시작 offset
+pub fn parse(input: &str) -> Result<(Frontmatter, usize), FrontmatterErr> {
+    let mut lines = lines_with_offsets(input);
+    match lines.next() {
+        Some((first, _)) if is_fence(first, "---") => {}
+        _ => return Err(FrontmatterErr::NoOpeningFence),
+    }
+
+    let content_start = input.find('\n').map_or(input.len(), |i| i + 1);
+    let (closing, closing_offset) = lines
+        .find(|(line, _)| is_fence(line, "---") || is_fence(line, "..."))
+        .ok_or(FrontmatterErr::Unclosed)?;
+
+    let raw = &input[content_start..closing_offset];
+    let raw = raw.strip_suffix('\n').map_or(raw, |raw| raw.strip_suffix('\r').unwrap_or(raw));
+    let mut frontmatter = Frontmatter::new(raw);
+    frontmatter.span = Span::from_offsets(0, closing_offset + closing.trim_end().len());
+    let body_start = input[closing_offset..]
+        .find('\n')
+        .map_or(input.len(), |i| closing_offset + i + 1);
+    Ok((frontmatter, body_start))
+}
+
+/// 구분선 줄 (들여쓰기 없이 구분자만, 뒤 공백 허용)
+fn is_fence(line: &str, fence: &str) -> bool {
+    line.trim_end_matches([' ', '\t']) == fence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse as parse_document;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("---\ntitle: a\n---\nbody", "title: a", 17)]
+    #[case("---\ntitle: a\ntags: [x]\n...\n", "title: a\ntags: [x]", 27)]
+    #[case("---\r\ntitle: a\r\n---\r\n", "title: a", 20)]
+    #[case("---  \n---", "", 9)]
+    #[case("---\n\n---", "", 8)]
+    fn test_parse_ok(#[case] input: &str, #[case] raw: &str, #[case] body_start: usize) {
+        let (frontmatter, start) = parse(input).unwrap();
+        assert_eq!(frontmatter.raw, raw);
+        assert_eq!(start, body_start);
+    }
+
+    #[rstest]
+    #[case("", FrontmatterErr::NoOpeningFence)]
+    #[case("title: a\n---", FrontmatterErr::NoOpeningFence)]
+    #[case(" ---\na\n---", FrontmatterErr::NoOpeningFence)]
+    #[case("----\na\n----", FrontmatterErr::NoOpeningFence)]
+    #[case("\n---\na\n---", FrontmatterErr::NoOpeningFence)]
+    #[case("---\ntitle: a", FrontmatterErr::Unclosed)]
+    #[case("---\ntitle: a\n ---", FrontmatterErr::Unclosed)]
+    fn test_parse_err(#[case] input: &str, #[case] expected: FrontmatterErr) {
+        assert_eq!(parse(input), Err(expected));
+    }
+
+    #[test]
+    fn test_document_frontmatter() {
+        let doc = parse_document("---\ntitle: a\n---\n# Heading\n\ntext\n---\n");
+        let frontmatter = doc.frontmatter.as_ref().unwrap();
+        assert_eq!(frontmatter.raw, "title: a");
+        assert_eq!((frontmatter.span.start.line, frontmatter.span.end.line), (1, 3));
+        assert_eq!((frontmatter.span.end.column, frontmatter.span.end.offset), (4, 16));
+
+        // 본문 위치는 frontmatter를 포함한 원문 기준
+        let expected = vec![
+            BlockNode::heading(1, vec![InlineNode::text("Heading")]),
+            BlockNode::heading(2, vec![InlineNode::text("text")]),
+        ];
+        assert_eq!(doc.clone().without_spans().children, expected);
+        assert_eq!(doc.children[0].span().start.line, 4);
+        assert_eq!(doc.children[1].span().start.line, 6);
+    }
+
+    #[rstest]
+    // 닫는 구분선이 없으면 Thematic Break
+    #[case("---\ntext")]
+    // 첫 줄이 아니면 Setext Heading
+    #[case("text\n---\na\n---")]
+    fn test_document_without_frontmatter(#[case] input: &str) {
+        assert_eq!(parse_document(input).frontmatter, None);
+    }
+}
@@ -1,7 +1,8 @@
 //! https://spec.commonmark.org/0.31.2/#atx-headings
 
 use super::helpers::{calculate_indent, count_leading_char};
-use crate::node::{BlockNode, HeadingNode, InlineNode, TextNode};
+use super::source_map::trimmed_span;
+use crate::node::{BlockNode, InlineNode, Span};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum HeadingErr {
@@ -15,8 +16,10 @@ pub enum HeadingErr {
     NoSpaceAfterHashes,
 }
 
-pub fn parse(line: &str) -> Result<BlockNode, HeadingErr> {
-    let indent = calculate_indent(line);
+/// ATX Heading 줄 파싱
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+pub fn parse(line: &str, column: usize) -> Result<BlockNode, HeadingErr> {
+    let indent = calculate_indent(line, column);
     let trimmed = line.trim();
 
     // 들여쓰기 3칸 초과면 코드 블록
@@ -43,10 +46,11 @@ pub fn parse(line: &str) -> Result<BlockNode, HeadingErr> {
     if rest.is_empty() || rest.starts_with(' ') || rest.starts_with('\t') {
         let content = rest.trim();
         let content = strip_closing_hashes(content);
-        Ok(BlockNode::Heading(HeadingNode::new(
-            level as u8,
-            vec![InlineNode::Text(TextNode::new(content))],
-        )))
+        // 위치는 줄 기준: 제목 내용은 `#`들과 그 뒤 공백 다음부터
+        let span = trimmed_span(line);
+        let content_start = span.start.offset + level + (rest.len() - rest.trim_start().len());
+        let text = InlineNode::text(content).with_span(Span::from_offsets(content_start, content_start + content.len()));
+        Ok(BlockNode::heading(level as u8, vec![text]).with_span(span))
     } else {
         Err(HeadingErr::NoSpaceAfterHashes)
     }
@@ -83,6 +87,7 @@ fn strip_closing_hashes(s: &str) -> &str {
 mod tests {
     use crate::node::{BlockNode, InlineNode};
     use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
     use rstest::rstest;
 
     #[rstest]
@@ -97,7 +102,7 @@ mod tests {
     #[case("####### foo", vec![BlockNode::paragraph(vec![InlineNode::text("####### foo")])])]
     // Example 64: # 뒤 공백 없음 → Paragraph
     #[case("#5 bolt", vec![BlockNode::paragraph(vec![InlineNode::text("#5 bolt")])])]
-    #[case("#hashtag", vec![BlockNode::paragraph(vec![InlineNode::text("#hashtag")])])]
+    #[case("#hashtag", vec![BlockNode::paragraph(vec![InlineNode::tag("hashtag")])])]
     // Example 67: # 뒤 여러 공백
     #[case("#                  foo", vec![BlockNode::heading(1, vec![InlineNode::text("foo")])])]
     // Example 68: 1-3칸 들여쓰기 허용
@@ -107,7 +112,7 @@ mod tests {
     // Example 69: 4칸 들여쓰기는 코드 블록
     #[case("    # foo", vec![BlockNode::code_block(None, "# foo")])]
     // Example 70: Paragraph 내 4칸 들여쓰기는 continuation
-    #[case("foo\n    # bar", vec![BlockNode::paragraph(vec![InlineNode::text("foo\n# bar")])])]
+    #[case("foo\n    # bar", vec![BlockNode::paragraph(vec![InlineNode::text("foo"), InlineNode::soft_break(), InlineNode::text("# bar")])])]
     // Example 71: 닫는 # 시퀀스
     #[case("## foo ##", vec![BlockNode::heading(2, vec![InlineNode::text("foo")])])]
     #[case("  ###   bar    ###", vec![BlockNode::heading(3, vec![InlineNode::text("bar")])])]
@@ -125,13 +130,13 @@ mod tests {
     // Example 78: Heading이 paragraph 인터럽트
     #[case("Foo bar\n# baz\nBar foo", vec![BlockNode::paragraph(vec![InlineNode::text("Foo bar")]), BlockNode::heading(1, vec![InlineNode::text("baz")]), BlockNode::paragraph(vec![InlineNode::text("Bar foo")])])]
     // Example 79: 빈 heading
-    #[case("##", vec![BlockNode::heading(2, vec![InlineNode::text("")])])]
-    #[case("#", vec![BlockNode::heading(1, vec![InlineNode::text("")])])]
-    #[case("### ###", vec![BlockNode::heading(3, vec![InlineNode::text("")])])]
+    #[case("##", vec![BlockNode::heading(2, vec![])])]
+    #[case("#", vec![BlockNode::heading(1, vec![])])]
+    #[case("### ###", vec![BlockNode::heading(3, vec![])])]
     // 추가 케이스
     #[case("# heading", vec![BlockNode::heading(1, vec![InlineNode::text("heading")])])]
     #[case("###### h6 title", vec![BlockNode::heading(6, vec![InlineNode::text("h6 title")])])]
-    #[case("# ", vec![BlockNode::heading(1, vec![InlineNode::text("")])])]
+    #[case("# ", vec![BlockNode::heading(1, vec![])])]
     #[case("## a ## b", vec![BlockNode::heading(2, vec![InlineNode::text("a ## b")])])]
     #[case("#\tfoo", vec![BlockNode::heading(1, vec![InlineNode::text("foo")])])]
     #[case("# foo\t#", vec![BlockNode::heading(1, vec![InlineNode::text("foo")])])]
@@ -140,9 +145,30 @@ mod tests {
     #[case("# foo   bar", vec![BlockNode::heading(1, vec![InlineNode::text("foo   bar")])])]
     #[case("# 안녕하세요", vec![BlockNode::heading(1, vec![InlineNode::text("안녕하세요")])])]
     #[case("## 🎉 축하합니다", vec![BlockNode::heading(2, vec![InlineNode::text("🎉 축하합니다")])])]
-    #[case("#no_space", vec![BlockNode::paragraph(vec![InlineNode::text("#no_space")])])]
+    #[case("#no_space", vec![BlockNode::paragraph(vec![InlineNode::tag("no_space")])])]
     fn test_heading(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
-        let doc = parse(input);
+        let doc = parse(input).without_spans();
         assert_eq!(doc.children, expected);
     }
+
+    #[rstest]
+    // 제목 전체는 앞뒤 공백을 뺀 줄, 내용 Text는 닫는 # 앞까지
+    #[case("# Foo", ((1, 1), (1, 6)), ((1, 3), (1, 6)))]
+    #[case("  ## Foo ##  ", ((1, 3), (1, 12)), ((1, 6), (1, 9)))]
+    #[case("para\n# 제목", ((2, 1), (2, 5)), ((2, 3), (2, 5)))]
+    // Setext heading은 밑줄까지
+    #[case("Foo\n===", ((1, 1), (2, 4)), ((1, 1), (1, 4)))]
+    #[case("[a]: /u\nFoo\n---", ((2, 1), (3, 4)), ((2, 1), (2, 4)))]
+    fn test_heading_span(
+        #[case] input: &str,
+        #[case] heading: LineColumns,
+        #[case] text: LineColumns,
+    ) {
+        let doc = parse(input);
+        let Some(BlockNode::Heading(node)) = doc.children.last() else {
+            panic!("마지막 블록이 Heading이어야 함");
+        };
+        assert_eq!(line_columns(node.span), heading);
+        assert_eq!(line_columns(node.children[0].span()), text);
+    }
 }
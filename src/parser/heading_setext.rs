@@ -155,8 +155,8 @@ mod tests {
     #[case("Foo\n===", vec![BlockNode::heading(1, vec![InlineNode::text("Foo")])])]
     #[case("Foo\n---", vec![BlockNode::heading(2, vec![InlineNode::text("Foo")])])]
     // Example 81: 여러 줄 제목
-    #[case("Foo\nbar\n===", vec![BlockNode::heading(1, vec![InlineNode::text("Foo\nbar")])])]
-    #[case("Foo\nbar\nbaz\n---", vec![BlockNode::heading(2, vec![InlineNode::text("Foo\nbar\nbaz")])])]
+    #[case("Foo\nbar\n===", vec![BlockNode::heading(1, vec![InlineNode::text("Foo"), InlineNode::soft_break(), InlineNode::text("bar")])])]
+    #[case("Foo\nbar\nbaz\n---", vec![BlockNode::heading(2, vec![InlineNode::text("Foo"), InlineNode::soft_break(), InlineNode::text("bar"), InlineNode::soft_break(), InlineNode::text("baz")])])]
     // Example 83: 다양한 밑줄 길이
     #[case("Foo\n=", vec![BlockNode::heading(1, vec![InlineNode::text("Foo")])])]
     #[case("Foo\n-------------------------", vec![BlockNode::heading(2, vec![InlineNode::text("Foo")])])]
@@ -182,7 +182,7 @@ mod tests {
     #[case("  Foo\n===", vec![BlockNode::heading(1, vec![InlineNode::text("Foo")])])]
     // Setext Heading이 아닌 케이스
     // 밑줄에 4칸 이상 들여쓰기 → Paragraph continuation
-    #[case("Foo\n    ===", vec![BlockNode::paragraph(vec![InlineNode::text("Foo\n===")])])]
+    #[case("Foo\n    ===", vec![BlockNode::paragraph(vec![InlineNode::text("Foo"), InlineNode::soft_break(), InlineNode::text("===")])])]
     // 빈 줄 후 밑줄 → 두 개의 Paragraph
     #[case("Foo\n\n===", vec![BlockNode::paragraph(vec![InlineNode::text("Foo")]), BlockNode::paragraph(vec![InlineNode::text("===")])])]
     // 밑줄만 단독 (=) → Paragraph
@@ -190,9 +190,9 @@ mod tests {
     // 밑줄만 단독 (-) → Thematic Break
     #[case("---", vec![BlockNode::thematic_break()])]
     // 밑줄 뒤 비공백 문자 → Paragraph continuation
-    #[case("Foo\n=== bar", vec![BlockNode::paragraph(vec![InlineNode::text("Foo\n=== bar")])])]
+    #[case("Foo\n=== bar", vec![BlockNode::paragraph(vec![InlineNode::text("Foo"), InlineNode::soft_break(), InlineNode::text("=== bar")])])]
     fn test_setext_heading(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
-        let doc = crate::parse(input);
+        let doc = crate::parse(input).without_spans();
         assert_eq!(doc.children, expected);
     }
 
@@ -1,23 +1,56 @@
 //! 파서 공통 헬퍼 함수
 
+use std::borrow::Cow;
+
 /// 문자열 앞에서 특정 문자가 연속으로 몇 개 있는지 세기
 pub(crate) fn count_leading_char(s: &str, c: char) -> usize {
     s.chars().take_while(|&ch| ch == c).count()
 }
 
-/// 들여쓰기 계산 (공백=1, 탭=4)
-pub(crate) fn calculate_indent(s: &str) -> usize {
-    s.chars()
-        .take_while(|c| *c == ' ' || *c == '\t')
-        .map(|c| if c == '\t' { 4 } else { 1 })
-        .sum()
+/// 탭 정지 간격 (탭은 다음 4의 배수 열까지 채움)
+const TAB_STOP: usize = 4;
+
+/// column 열에 있는 탭의 너비 (다음 탭 정지까지의 칸 수)
+pub(crate) fn tab_width(column: usize) -> usize {
+    TAB_STOP - column % TAB_STOP
 }
 
-/// 문자열에서 최대 n칸의 공백 제거
-pub(crate) fn remove_indent(s: &str, n: usize) -> &str {
-    let spaces = count_leading_char(s, ' ');
-    let remove = spaces.min(n);
-    &s[remove..]
+/// 들여쓰기 계산 (공백=1, 탭=다음 탭 정지까지)
+/// column: s가 시작하는 열 (컨테이너 마커 뒤에서 시작하는 줄은 0이 아님)
+pub(crate) fn calculate_indent(s: &str, column: usize) -> usize {
+    let mut col = column;
+    for c in s.chars() {
+        match c {
+            ' ' => col += 1,
+            '\t' => col += tab_width(col),
+            _ => break,
+        }
+    }
+    col - column
+}
+
+/// 문자열에서 최대 n칸의 들여쓰기 제거 (column: s가 시작하는 열)
+/// 탭을 일부만 제거하면 남은 칸은 공백으로 채움
+pub(crate) fn remove_indent(s: &str, column: usize, n: usize) -> Cow<'_, str> {
+    let mut col = column;
+    let mut remaining = n;
+    for (i, c) in s.char_indices() {
+        let width = match c {
+            ' ' => 1,
+            '\t' => tab_width(col),
+            _ => return Cow::Borrowed(&s[i..]),
+        };
+        if remaining == 0 {
+            return Cow::Borrowed(&s[i..]);
+        }
+        if width > remaining {
+            // 탭을 일부만 소비: 남은 칸은 공백
+            return Cow::Owned(format!("{}{}", " ".repeat(width - remaining), &s[i + 1..]));
+        }
+        col += width;
+        remaining -= width;
+    }
+    Cow::Borrowed("")
 }
 
 /// 앞뒤 빈 줄(빈 문자열) 제거 후 join
@@ -35,6 +68,48 @@ pub(crate) fn trim_blank_lines(lines: Vec<String>) -> String {
     trimmed_lines.join("\n")
 }
 
+/// Unicode 공백 문자 여부
+/// CommonMark 정의: Zs 카테고리 문자 또는 탭, 줄바꿈, 폼피드, 캐리지 리턴
+pub(crate) fn is_unicode_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        '\t' | '\n' | '\u{0C}' | '\r' | ' ' | '\u{A0}' | '\u{1680}'
+            | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}'
+    )
+}
+
+/// Unicode 구두점 문자 여부
+/// CommonMark 정의: P(구두점) 또는 S(기호) 카테고리 문자
+/// ASCII 외에는 P/S 문자가 모여 있는 주요 블록만 판별합니다.
+pub(crate) fn is_punctuation(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_punctuation();
+    }
+    matches!(
+        c,
+        // Latin-1 구두점/기호 (ª, ², µ, ¹, º, ¼-¾, soft hyphen 제외)
+        '\u{A1}'..='\u{A9}' | '\u{AB}' | '\u{AC}' | '\u{AE}'..='\u{B1}' | '\u{B4}'
+            | '\u{B6}'..='\u{B8}' | '\u{BB}' | '\u{BF}' | '\u{D7}' | '\u{F7}'
+            // General Punctuation (공백/서식 문자 제외)
+            | '\u{2010}'..='\u{2027}' | '\u{2030}'..='\u{205E}'
+            // 통화 기호
+            | '\u{20A0}'..='\u{20C0}'
+            // 화살표, 수학 연산자, 기술 기호, 도형, 딩뱃 등
+            | '\u{2190}'..='\u{23FF}' | '\u{2500}'..='\u{2775}' | '\u{2794}'..='\u{2BFF}'
+            // 보조 구두점
+            | '\u{2E00}'..='\u{2E7F}'
+            // CJK 기호 및 구두점
+            | '\u{3001}'..='\u{3004}' | '\u{3008}'..='\u{3020}' | '\u{3030}' | '\u{303D}'
+            // 세로형/호환/소형 구두점
+            | '\u{FE10}'..='\u{FE19}' | '\u{FE30}'..='\u{FE6B}'
+            // 전각 구두점
+            | '\u{FF01}'..='\u{FF0F}' | '\u{FF1A}'..='\u{FF20}' | '\u{FF3B}'..='\u{FF40}'
+            | '\u{FF5B}'..='\u{FF65}' | '\u{FFE0}'..='\u{FFEE}'
+            // 이모지 및 그림 기호
+            | '\u{1F300}'..='\u{1FAFF}'
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,56 +145,67 @@ mod tests {
 
     #[rstest]
     // 기본 케이스
-    #[case("  code", 2, "code")]
-    #[case("    code", 4, "code")]
+    #[case("  code", 0, 2, "code")]
+    #[case("    code", 0, 4, "code")]
     // 부분 제거 (n보다 공백이 많음)
-    #[case("    code", 2, "  code")]
-    #[case("      code", 3, "   code")]
+    #[case("    code", 0, 2, "  code")]
+    #[case("      code", 0, 3, "   code")]
     // 공백 없는 경우
-    #[case("code", 2, "code")]
-    #[case("code", 0, "code")]
+    #[case("code", 0, 2, "code")]
+    #[case("code", 0, 0, "code")]
     // n이 공백보다 큰 경우
-    #[case("  code", 5, "code")]
-    #[case("  code", 100, "code")]
+    #[case("  code", 0, 5, "code")]
+    #[case("  code", 0, 100, "code")]
     // n이 0인 경우
-    #[case("  code", 0, "  code")]
+    #[case("  code", 0, 0, "  code")]
     // 빈 문자열
-    #[case("", 2, "")]
-    #[case("", 0, "")]
+    #[case("", 0, 2, "")]
+    #[case("", 0, 0, "")]
     // 전체가 공백
-    #[case("    ", 2, "  ")]
-    #[case("    ", 4, "")]
-    #[case("    ", 10, "")]
-    // 탭은 제거 안 됨 (공백만 제거)
-    #[case("\tcode", 2, "\tcode")]
-    #[case("  \tcode", 2, "\tcode")]   // 공백 2개 제거, 탭은 남음
-    #[case("  \tcode", 3, "\tcode")]   // 공백 2개만 있으므로 2개만 제거
-    fn test_remove_indent(#[case] input: &str, #[case] n: usize, #[case] expected: &str) {
-        assert_eq!(remove_indent(input, n), expected);
+    #[case("    ", 0, 2, "  ")]
+    #[case("    ", 0, 4, "")]
+    #[case("    ", 0, 10, "")]
+    // 탭 전체 제거
+    #[case("\tcode", 0, 4, "code")]
+    #[case("  \tcode", 0, 4, "code")]      // 공백 2칸 + 탭 2칸
+    // 탭 일부 제거: 남은 칸은 공백
+    #[case("\tcode", 0, 2, "  code")]
+    #[case("  \tcode", 0, 3, " code")]
+    #[case("\t\tcode", 0, 6, "  code")]
+    // 시작 열에 따라 탭 너비가 다름
+    #[case("\tcode", 2, 2, "code")]
+    #[case("\tcode", 1, 1, "  code")]
+    fn test_remove_indent(#[case] input: &str, #[case] column: usize, #[case] n: usize, #[case] expected: &str) {
+        assert_eq!(remove_indent(input, column, n), expected);
     }
 
     #[rstest]
     // 공백만
-    #[case("", 0)]
-    #[case(" ", 1)]
-    #[case("  ", 2)]
-    #[case("   ", 3)]
-    #[case("    ", 4)]
-    // 탭 (1탭 = 4칸)
-    #[case("\t", 4)]
-    #[case("\t\t", 8)]
+    #[case("", 0, 0)]
+    #[case(" ", 0, 1)]
+    #[case("  ", 0, 2)]
+    #[case("   ", 0, 3)]
+    #[case("    ", 0, 4)]
+    // 탭 (다음 탭 정지까지)
+    #[case("\t", 0, 4)]
+    #[case("\t\t", 0, 8)]
     // 공백 + 탭 혼합
-    #[case(" \t", 5)]           // 1 + 4
-    #[case("  \t", 6)]          // 2 + 4
-    #[case("\t ", 5)]           // 4 + 1
+    #[case(" \t", 0, 4)]           // 1 + 3
+    #[case("  \t", 0, 4)]          // 2 + 2
+    #[case("\t ", 0, 5)]           // 4 + 1
     // 텍스트 포함
-    #[case("code", 0)]
-    #[case(" code", 1)]
-    #[case("  code", 2)]
-    #[case("\tcode", 4)]
-    #[case("  \tcode", 6)]      // 2 + 4
-    fn test_calculate_indent(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(calculate_indent(input), expected);
+    #[case("code", 0, 0)]
+    #[case(" code", 0, 1)]
+    #[case("  code", 0, 2)]
+    #[case("\tcode", 0, 4)]
+    #[case("  \tcode", 0, 4)]      // 2 + 2
+    // 시작 열이 0이 아니면 첫 탭이 짧아짐 (Example 6, 7)
+    #[case("\t", 1, 3)]
+    #[case("\t", 2, 2)]
+    #[case("\t\tfoo", 2, 6)]     // 2 + 4
+    #[case(" \t", 4, 4)]           // 1 + 3
+    fn test_calculate_indent(#[case] input: &str, #[case] column: usize, #[case] expected: usize) {
+        assert_eq!(calculate_indent(input, column), expected);
     }
 
     #[rstest]
@@ -150,4 +236,43 @@ mod tests {
     fn test_trim_blank_lines(#[case] input: Vec<String>, #[case] expected: &str) {
         assert_eq!(trim_blank_lines(input), expected);
     }
+
+    #[rstest]
+    // CommonMark 공백 문자
+    #[case(' ', true)]
+    #[case('\t', true)]
+    #[case('\n', true)]
+    #[case('\r', true)]
+    #[case('\u{0C}', true)]
+    // Zs 카테고리
+    #[case('\u{A0}', true)]
+    #[case('\u{3000}', true)]
+    // 공백 아님
+    #[case('a', false)]
+    #[case('*', false)]
+    #[case('\u{200B}', false)]     // zero width space는 Cf
+    fn test_is_unicode_whitespace(#[case] c: char, #[case] expected: bool) {
+        assert_eq!(is_unicode_whitespace(c), expected);
+    }
+
+    #[rstest]
+    // ASCII 구두점
+    #[case('*', true)]
+    #[case('_', true)]
+    #[case('$', true)]
+    #[case('(', true)]
+    // Unicode 구두점/기호
+    #[case('£', true)]
+    #[case('€', true)]
+    #[case('“', true)]
+    #[case('。', true)]
+    // 구두점 아님
+    #[case('a', false)]
+    #[case('가', false)]
+    #[case(' ', false)]
+    #[case('\u{A0}', false)]
+    #[case('ª', false)]
+    fn test_is_punctuation(#[case] c: char, #[case] expected: bool) {
+        assert_eq!(is_punctuation(c), expected);
+    }
 }
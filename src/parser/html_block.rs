@@ -0,0 +1,473 @@
+//! https://spec.commonmark.org/0.31.2/#html-blocks
+//!
+//! HTML 블록 파서
+//!
+//! 7가지 시작 조건 중 하나로 시작하고, 조건별 종료 조건을 만날 때까지의 줄을
+//! 원문 그대로 보존합니다. 블록 안의 내용은 마크다운으로 해석하지 않습니다.
+
+use super::helpers::calculate_indent;
+use super::inline::raw_html::{parse_closing_tag, parse_open_tag};
+use crate::node::BlockNode;
+
+/// 조건 1의 태그 (내용에 빈 줄이 있어도 닫는 태그까지 이어짐)
+const RAW_TEXT_TAGS: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// 조건 6의 블록 수준 태그
+const BLOCK_TAGS: [&str; 62] = [
+    "address", "article", "aside", "base", "basefont", "blockquote", "body", "caption", "center",
+    "col", "colgroup", "dd", "details", "dialog", "dir", "div", "dl", "dt", "fieldset",
+    "figcaption", "figure", "footer", "form", "frame", "frameset", "h1", "h2", "h3", "h4", "h5",
+    "h6", "head", "header", "hr", "html", "iframe", "legend", "li", "link", "main", "menu",
+    "menuitem", "nav", "noframes", "ol", "optgroup", "option", "p", "param", "search", "section",
+    "summary", "table", "tbody", "td", "tfoot", "th", "thead", "title", "tr", "track", "ul",
+];
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// HTML 블록 종류 (명세의 시작 조건 1~7)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HtmlBlockKind {
+    /// 1: `<pre`, `<script`, `<style`, `<textarea` → 해당 닫는 태그까지
+    RawText,
+    /// 2: `<!--` → `-->`까지
+    Comment,
+    /// 3: `<?` → `?>`까지
+    ProcessingInstruction,
+    /// 4: `<!` + ASCII 영문자 → `>`까지
+    Declaration,
+    /// 5: `<![CDATA[` → `]]>`까지
+    Cdata,
+    /// 6: 블록 수준 태그 (`<div`, `</table` 등) → 빈 줄까지
+    BlockTag,
+    /// 7: 그 밖의 완전한 여는/닫는 태그 한 개만 있는 줄 → 빈 줄까지
+    CompleteTag,
+}
+
+/// HTML 블록 시작 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlBlockStart {
+    pub kind: HtmlBlockKind,
+}
+
+/// HTML 블록 시작 성공 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum HtmlBlockStartReason {
+    /// 정상적인 시작
+    Started(HtmlBlockStart),
+}
+
+/// HTML 블록 시작 아님 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum HtmlBlockNotStartReason {
+    /// 4칸 이상 들여쓰기 (Indented Code Block)
+    CodeBlockIndented,
+    /// 어떤 시작 조건에도 맞지 않음
+    NotHtml,
+    /// 조건 7은 Paragraph를 인터럽트할 수 없음 (Example 187)
+    CannotInterruptParagraph,
+}
+
+impl HtmlBlockKind {
+    /// 빈 줄에서 끝나는 종류인지 (조건 6, 7)
+    /// 빈 줄 자체는 블록에 포함되지 않음
+    pub fn ends_at_blank_line(&self) -> bool {
+        matches!(self, HtmlBlockKind::BlockTag | HtmlBlockKind::CompleteTag)
+    }
+
+    /// 줄에 종료 조건이 있는지 (조건 1~5)
+    /// 종료 조건이 있는 줄까지 블록에 포함됨
+    pub fn is_end(&self, line: &str) -> bool {
+        match self {
+            HtmlBlockKind::RawText => {
+                let lower = line.to_ascii_lowercase();
+                RAW_TEXT_TAGS.iter().any(|tag| lower.contains(&format!("</{}>", tag)))
+            }
+            HtmlBlockKind::Comment => line.contains("-->"),
+            HtmlBlockKind::ProcessingInstruction => line.contains("?>"),
+            HtmlBlockKind::Declaration => line.contains('>'),
+            HtmlBlockKind::Cdata => line.contains("]]>"),
+            HtmlBlockKind::BlockTag | HtmlBlockKind::CompleteTag => false,
+        }
+    }
+}
+
+// =============================================================================
+// 함수
+// =============================================================================
+
+/// HTML 블록 시작 줄인지 확인
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+/// in_paragraph가 true면 Paragraph를 인터럽트하는 경우로, 조건 7은 제외
+pub(crate) fn try_start(
+    line: &str,
+    column: usize,
+    in_paragraph: bool,
+) -> Result<HtmlBlockStartReason, HtmlBlockNotStartReason> {
+    if calculate_indent(line, column) >= 4 {
+        return Err(HtmlBlockNotStartReason::CodeBlockIndented);
+    }
+    let rest = line.trim_start_matches([' ', '\t']);
+    if !rest.starts_with('<') {
+        return Err(HtmlBlockNotStartReason::NotHtml);
+    }
+
+    let kind = detect_kind(rest).ok_or(HtmlBlockNotStartReason::NotHtml)?;
+    if kind == HtmlBlockKind::CompleteTag && in_paragraph {
+        return Err(HtmlBlockNotStartReason::CannotInterruptParagraph);
+    }
+    Ok(HtmlBlockStartReason::Started(HtmlBlockStart { kind }))
+}
+
+/// 수집된 줄로 HtmlBlock 노드 생성
+pub(crate) fn finalize(lines: Vec<String>) -> BlockNode {
+    BlockNode::html_block(&lines.join("\n"))
+}
+
+/// `<`로 시작하는 문자열의 시작 조건 판별 (조건 번호 순서대로 확인)
+fn detect_kind(rest: &str) -> Option<HtmlBlockKind> {
+    if let Some((name, after)) = tag_name(&rest[1..])
+        && is_one_of(name, &RAW_TEXT_TAGS)
+        && (after.is_empty() || after.starts_with([' ', '\t', '>']))
+    {
+        return Some(HtmlBlockKind::RawText);
+    }
+    if rest.starts_with("<!--") {
+        return Some(HtmlBlockKind::Comment);
+    }
+    if rest.starts_with("<?") {
+        return Some(HtmlBlockKind::ProcessingInstruction);
+    }
+    if rest.strip_prefix("<!").is_some_and(|body| body.starts_with(|c: char| c.is_ascii_alphabetic())) {
+        return Some(HtmlBlockKind::Declaration);
+    }
+    if rest.starts_with("<![CDATA[") {
+        return Some(HtmlBlockKind::Cdata);
+    }
+    if is_block_tag(rest) {
+        return Some(HtmlBlockKind::BlockTag);
+    }
+    if is_complete_tag_line(rest) {
+        return Some(HtmlBlockKind::CompleteTag);
+    }
+    None
+}
+
+/// 조건 6: `<` 또는 `</` + 블록 태그 이름 + 공백, 줄 끝, `>`, `/>`
+fn is_block_tag(rest: &str) -> bool {
+    let body = rest.strip_prefix("</").unwrap_or(&rest[1..]);
+    match tag_name(body) {
+        Some((name, after)) => {
+            is_one_of(name, &BLOCK_TAGS)
+                && (after.is_empty() || after.starts_with([' ', '\t', '>']) || after.starts_with("/>"))
+        }
+        None => false,
+    }
+}
+
+/// 조건 7: 완전한 여는 태그 또는 닫는 태그 하나 + 공백만 (조건 1의 태그 제외)
+fn is_complete_tag_line(rest: &str) -> bool {
+    let body = rest.strip_prefix("</").unwrap_or(&rest[1..]);
+    if tag_name(body).is_some_and(|(name, _)| is_one_of(name, &RAW_TEXT_TAGS)) {
+        return false;
+    }
+    parse_open_tag(rest, 0)
+        .or_else(|| parse_closing_tag(rest, 0))
+        .is_some_and(|end| rest[end..].trim_matches([' ', '\t']).is_empty())
+}
+
+/// 태그 이름(ASCII 영문자로 시작, 이후 영숫자 또는 `-`)과 나머지 문자열
+fn tag_name(text: &str) -> Option<(&str, &str)> {
+    if !text.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let len = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(text.len());
+    Some(text.split_at(len))
+}
+
+/// 대소문자 구분 없이 목록에 있는 태그 이름인지
+fn is_one_of(name: &str, tags: &[&str]) -> bool {
+    tags.iter().any(|tag| tag.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{BlockNode, InlineNode, ListItemNode};
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    /// try_start 테스트: 시작 조건 판별
+    #[rstest]
+    #[case("<pre>", false, Ok(HtmlBlockKind::RawText))]
+    #[case("<SCRIPT type=\"x\">", false, Ok(HtmlBlockKind::RawText))]
+    #[case("<textarea", false, Ok(HtmlBlockKind::RawText))]
+    #[case("<!-- foo", false, Ok(HtmlBlockKind::Comment))]
+    #[case("<?php", false, Ok(HtmlBlockKind::ProcessingInstruction))]
+    #[case("<!DOCTYPE html>", false, Ok(HtmlBlockKind::Declaration))]
+    #[case("<![CDATA[", false, Ok(HtmlBlockKind::Cdata))]
+    #[case("   <div>", false, Ok(HtmlBlockKind::BlockTag))]
+    #[case("</div>", false, Ok(HtmlBlockKind::BlockTag))]
+    #[case("<hr/>", false, Ok(HtmlBlockKind::BlockTag))]
+    #[case("<div *???-&&&-<---", false, Ok(HtmlBlockKind::BlockTag))]
+    #[case("<a href=\"foo\">", false, Ok(HtmlBlockKind::CompleteTag))]
+    #[case("</ins>  ", false, Ok(HtmlBlockKind::CompleteTag))]
+    // 실패 케이스
+    #[case("    <div>", false, Err(HtmlBlockNotStartReason::CodeBlockIndented))]
+    #[case("<divx>foo", false, Err(HtmlBlockNotStartReason::NotHtml))]
+    #[case("<del>*foo*</del>", false, Err(HtmlBlockNotStartReason::NotHtml))]
+    #[case("<pre/>", false, Err(HtmlBlockNotStartReason::NotHtml))]
+    #[case("foo <div>", false, Err(HtmlBlockNotStartReason::NotHtml))]
+    #[case("<a href=\"bar\">", true, Err(HtmlBlockNotStartReason::CannotInterruptParagraph))]
+    fn test_try_start(
+        #[case] input: &str,
+        #[case] in_paragraph: bool,
+        #[case] expected: Result<HtmlBlockKind, HtmlBlockNotStartReason>,
+    ) {
+        let result = try_start(input, 0, in_paragraph).map(|HtmlBlockStartReason::Started(start)| start.kind);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    // Example 148: 빈 줄 뒤의 `</pre>`는 Paragraph 안의 인라인 HTML
+    #[case(
+        "<table><tr><td>\n<pre>\n**Hello**,\n\n_world_.\n</pre>\n</td></tr></table>",
+        vec![
+            BlockNode::html_block("<table><tr><td>\n<pre>\n**Hello**,"),
+            BlockNode::paragraph(vec![
+                InlineNode::emphasis(vec![InlineNode::text("world")]),
+                InlineNode::text("."),
+                InlineNode::soft_break(),
+                InlineNode::raw_html("</pre>"),
+            ]),
+            BlockNode::html_block("</td></tr></table>"),
+        ]
+    )]
+    // Example 149: 빈 줄까지 HTML 블록
+    #[case(
+        "<table>\n  <tr>\n    <td>\n           hi\n    </td>\n  </tr>\n</table>\n\nokay.",
+        vec![
+            BlockNode::html_block("<table>\n  <tr>\n    <td>\n           hi\n    </td>\n  </tr>\n</table>"),
+            BlockNode::paragraph(vec![InlineNode::text("okay.")]),
+        ]
+    )]
+    // Example 150: 들여쓰기 보존, 내용은 해석하지 않음
+    #[case(" <div>\n  *hello*\n         <foo><a>", vec![BlockNode::html_block(" <div>\n  *hello*\n         <foo><a>")])]
+    // Example 151: 닫는 태그로 시작
+    #[case("</div>\n*foo*", vec![BlockNode::html_block("</div>\n*foo*")])]
+    // Example 152: 대소문자 무시, 빈 줄 사이는 마크다운
+    #[case(
+        "<DIV CLASS=\"foo\">\n\n*Markdown*\n\n</DIV>",
+        vec![
+            BlockNode::html_block("<DIV CLASS=\"foo\">"),
+            BlockNode::paragraph(vec![InlineNode::emphasis(vec![InlineNode::text("Markdown")])]),
+            BlockNode::html_block("</DIV>"),
+        ]
+    )]
+    // Example 153-154: 여러 줄에 걸친 태그
+    #[case(
+        "<div id=\"foo\"\n  class=\"bar\">\n</div>",
+        vec![BlockNode::html_block("<div id=\"foo\"\n  class=\"bar\">\n</div>")]
+    )]
+    #[case(
+        "<div id=\"foo\" class=\"bar\n  baz\">\n</div>",
+        vec![BlockNode::html_block("<div id=\"foo\" class=\"bar\n  baz\">\n</div>")]
+    )]
+    // Example 155: 빈 줄에서 종료
+    #[case(
+        "<div>\n*foo*\n\n*bar*",
+        vec![
+            BlockNode::html_block("<div>\n*foo*"),
+            BlockNode::paragraph(vec![InlineNode::emphasis(vec![InlineNode::text("bar")])]),
+        ]
+    )]
+    // Example 156-158: 완전하지 않은 태그도 조건 6이면 시작
+    #[case("<div id=\"foo\"\n*hi*", vec![BlockNode::html_block("<div id=\"foo\"\n*hi*")])]
+    #[case("<div class\nfoo", vec![BlockNode::html_block("<div class\nfoo")])]
+    #[case("<div *???-&&&-<---\n*foo*", vec![BlockNode::html_block("<div *???-&&&-<---\n*foo*")])]
+    // Example 159-160: 한 줄 또는 여러 줄 태그
+    #[case("<div><a href=\"bar\">*foo*</a></div>", vec![BlockNode::html_block("<div><a href=\"bar\">*foo*</a></div>")])]
+    #[case(
+        "<table><tr><td>\nfoo\n</td></tr></table>",
+        vec![BlockNode::html_block("<table><tr><td>\nfoo\n</td></tr></table>")]
+    )]
+    // Example 161: 블록 안의 펜스도 원문 그대로
+    #[case("<div></div>\n``` c\nint x = 33;\n```", vec![BlockNode::html_block("<div></div>\n``` c\nint x = 33;\n```")])]
+    // Example 162-165: 조건 7 (임의 태그)
+    #[case("<a href=\"foo\">\n*bar*\n</a>", vec![BlockNode::html_block("<a href=\"foo\">\n*bar*\n</a>")])]
+    #[case("<Warning>\n*bar*\n</Warning>", vec![BlockNode::html_block("<Warning>\n*bar*\n</Warning>")])]
+    #[case("<i class=\"foo\">\n*bar*\n</i>", vec![BlockNode::html_block("<i class=\"foo\">\n*bar*\n</i>")])]
+    #[case("</ins>\n*bar*", vec![BlockNode::html_block("</ins>\n*bar*")])]
+    // Example 166-167: 조건 7 태그 사이의 빈 줄
+    #[case("<del>\n*foo*\n</del>", vec![BlockNode::html_block("<del>\n*foo*\n</del>")])]
+    #[case(
+        "<del>\n\n*foo*\n\n</del>",
+        vec![
+            BlockNode::html_block("<del>"),
+            BlockNode::paragraph(vec![InlineNode::emphasis(vec![InlineNode::text("foo")])]),
+            BlockNode::html_block("</del>"),
+        ]
+    )]
+    // Example 168: 태그 뒤에 내용이 있으면 인라인 HTML
+    #[case(
+        "<del>*foo*</del>",
+        vec![BlockNode::paragraph(vec![
+            InlineNode::raw_html("<del>"),
+            InlineNode::emphasis(vec![InlineNode::text("foo")]),
+            InlineNode::raw_html("</del>"),
+        ])]
+    )]
+    // Example 169-172: 조건 1은 빈 줄이 있어도 닫는 태그까지
+    #[case(
+        "<pre language=\"haskell\"><code>\nimport Text.HTML.TagSoup\n\nmain :: IO ()\nmain = print $ parseTags tags\n</code></pre>\nokay",
+        vec![
+            BlockNode::html_block("<pre language=\"haskell\"><code>\nimport Text.HTML.TagSoup\n\nmain :: IO ()\nmain = print $ parseTags tags\n</code></pre>"),
+            BlockNode::paragraph(vec![InlineNode::text("okay")]),
+        ]
+    )]
+    #[case(
+        "<script type=\"text/javascript\">\n// JavaScript example\n\ndocument.getElementById(\"demo\").innerHTML = \"Hello JavaScript!\";\n</script>\nokay",
+        vec![
+            BlockNode::html_block("<script type=\"text/javascript\">\n// JavaScript example\n\ndocument.getElementById(\"demo\").innerHTML = \"Hello JavaScript!\";\n</script>"),
+            BlockNode::paragraph(vec![InlineNode::text("okay")]),
+        ]
+    )]
+    #[case(
+        "<textarea>\n\n*foo*\n\n_bar_\n\n</textarea>",
+        vec![BlockNode::html_block("<textarea>\n\n*foo*\n\n_bar_\n\n</textarea>")]
+    )]
+    #[case(
+        "<style\n  type=\"text/css\">\nh1 {color:red;}\n\np {color:blue;}\n</style>\nokay",
+        vec![
+            BlockNode::html_block("<style\n  type=\"text/css\">\nh1 {color:red;}\n\np {color:blue;}\n</style>"),
+            BlockNode::paragraph(vec![InlineNode::text("okay")]),
+        ]
+    )]
+    // Example 173: 종료 조건이 없으면 문서 끝까지
+    #[case("<style\n  type=\"text/css\">\n\nfoo", vec![BlockNode::html_block("<style\n  type=\"text/css\">\n\nfoo")])]
+    // Example 175: 리스트 아이템 안의 HTML 블록
+    #[case(
+        "- <div>\n- foo",
+        vec![BlockNode::bullet_list(
+            true,
+            vec![
+                ListItemNode::new(vec![BlockNode::html_block("<div>")]),
+                ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("foo")])]),
+            ],
+        )]
+    )]
+    // Example 176-177: 시작 줄에 종료 조건이 있으면 그 줄에서 종료
+    #[case(
+        "<style>p{color:red;}</style>\n*foo*",
+        vec![
+            BlockNode::html_block("<style>p{color:red;}</style>"),
+            BlockNode::paragraph(vec![InlineNode::emphasis(vec![InlineNode::text("foo")])]),
+        ]
+    )]
+    #[case(
+        "<!-- foo -->*bar*\n*baz*",
+        vec![
+            BlockNode::html_block("<!-- foo -->*bar*"),
+            BlockNode::paragraph(vec![InlineNode::emphasis(vec![InlineNode::text("baz")])]),
+        ]
+    )]
+    // Example 178: 종료 조건 뒤의 내용도 같은 줄이면 블록에 포함
+    #[case("<script>\nfoo\n</script>1. *bar*", vec![BlockNode::html_block("<script>\nfoo\n</script>1. *bar*")])]
+    // Example 179-182: 주석, processing instruction, 선언, CDATA
+    #[case(
+        "<!-- Foo\n\nbar\n   baz -->\nokay",
+        vec![BlockNode::html_block("<!-- Foo\n\nbar\n   baz -->"), BlockNode::paragraph(vec![InlineNode::text("okay")])]
+    )]
+    #[case(
+        "<?php\n\n  echo '>';\n\n?>\nokay",
+        vec![BlockNode::html_block("<?php\n\n  echo '>';\n\n?>"), BlockNode::paragraph(vec![InlineNode::text("okay")])]
+    )]
+    #[case("<!DOCTYPE html>", vec![BlockNode::html_block("<!DOCTYPE html>")])]
+    #[case(
+        "<![CDATA[\nfunction matchwo(a,b)\n{\n  if (a < b && a < 0) then {\n    return 1;\n\n  } else {\n\n    return 0;\n  }\n}\n]]>\nokay",
+        vec![
+            BlockNode::html_block("<![CDATA[\nfunction matchwo(a,b)\n{\n  if (a < b && a < 0) then {\n    return 1;\n\n  } else {\n\n    return 0;\n  }\n}\n]]>"),
+            BlockNode::paragraph(vec![InlineNode::text("okay")]),
+        ]
+    )]
+    // Example 183-184: 4칸 이상 들여쓰기는 코드 블록
+    #[case(
+        "  <!-- foo -->\n\n    <!-- foo -->",
+        vec![BlockNode::html_block("  <!-- foo -->"), BlockNode::code_block(None, "<!-- foo -->")]
+    )]
+    #[case("  <div>\n\n    <div>", vec![BlockNode::html_block("  <div>"), BlockNode::code_block(None, "<div>")])]
+    // Example 185: 조건 1~6은 Paragraph를 인터럽트
+    #[case(
+        "Foo\n<div>\nbar\n</div>",
+        vec![BlockNode::paragraph(vec![InlineNode::text("Foo")]), BlockNode::html_block("<div>\nbar\n</div>")]
+    )]
+    // Example 186: 빈 줄 전까지 마크다운 해석 안 함
+    #[case("<div>\nbar\n</div>\n*foo*", vec![BlockNode::html_block("<div>\nbar\n</div>\n*foo*")])]
+    // Example 188-189: 빈 줄 유무에 따른 마크다운 해석
+    #[case(
+        "<div>\n\n*Emphasized* text.\n\n</div>",
+        vec![
+            BlockNode::html_block("<div>"),
+            BlockNode::paragraph(vec![
+                InlineNode::emphasis(vec![InlineNode::text("Emphasized")]),
+                InlineNode::text(" text."),
+            ]),
+            BlockNode::html_block("</div>"),
+        ]
+    )]
+    #[case("<div>\n*Emphasized* text.\n</div>", vec![BlockNode::html_block("<div>\n*Emphasized* text.\n</div>")])]
+    // Example 190: 태그마다 빈 줄로 구분
+    #[case(
+        "<table>\n\n<tr>\n\n<td>\nHi\n</td>\n\n</tr>\n\n</table>",
+        vec![
+            BlockNode::html_block("<table>"),
+            BlockNode::html_block("<tr>"),
+            BlockNode::html_block("<td>\nHi\n</td>"),
+            BlockNode::html_block("</tr>"),
+            BlockNode::html_block("</table>"),
+        ]
+    )]
+    // Example 191: 들여쓴 태그는 코드 블록이 됨
+    #[case(
+        "<table>\n\n  <tr>\n\n    <td>\n      Hi\n    </td>\n\n  </tr>\n\n</table>",
+        vec![
+            BlockNode::html_block("<table>"),
+            BlockNode::html_block("  <tr>"),
+            BlockNode::code_block(None, "<td>\n  Hi\n</td>"),
+            BlockNode::html_block("  </tr>"),
+            BlockNode::html_block("</table>"),
+        ]
+    )]
+    fn test_html_block(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, expected);
+    }
+
+    #[test]
+    fn test_complete_tag_cannot_interrupt_paragraph() {
+        // Example 187: 조건 7은 Paragraph를 인터럽트할 수 없음
+        let doc = parse("Foo\n<a href=\"bar\">\nbaz").without_spans();
+        let expected = vec![BlockNode::paragraph(vec![
+            InlineNode::text("Foo"),
+            InlineNode::soft_break(),
+            InlineNode::raw_html("<a href=\"bar\">"),
+            InlineNode::soft_break(),
+            InlineNode::text("baz"),
+        ])];
+        assert_eq!(doc.children, expected);
+    }
+
+    #[test]
+    fn test_html_block_in_blockquote() {
+        // Example 174: blockquote 안의 HTML 블록
+        let doc = parse("> <div>\n> foo\n\nbar").without_spans();
+        let expected = vec![
+            BlockNode::blockquote(vec![BlockNode::html_block("<div>\nfoo")]),
+            BlockNode::paragraph(vec![InlineNode::text("bar")]),
+        ];
+        assert_eq!(doc.children, expected);
+    }
+}
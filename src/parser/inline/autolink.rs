@@ -0,0 +1,130 @@
+//! https://spec.commonmark.org/0.31.2/#autolinks
+//!
+//! `<scheme:...>` 형태의 URI autolink와 `<user@host>` 형태의 email autolink를 파싱합니다.
+
+use crate::node::InlineNode;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum AutolinkErr {
+    /// 닫는 `>` 없음
+    NotClosed,
+    /// 절대 URI도 email 주소도 아님
+    InvalidAddress,
+}
+
+/// scheme 길이 제한 (2-32자)
+const MIN_SCHEME_LEN: usize = 2;
+const MAX_SCHEME_LEN: usize = 32;
+
+/// email 도메인 label 최대 길이
+const MAX_DOMAIN_LABEL_LEN: usize = 63;
+
+/// pos 위치(`<`)에서 autolink 파싱
+/// 반환: (Autolink 노드, autolink 다음 위치)
+pub(super) fn parse(text: &str, pos: usize) -> Result<(InlineNode, usize), AutolinkErr> {
+    let inner_start = pos + 1;
+    let close = text[inner_start..]
+        .find(['>', '<', ' ', '\n'])
+        .filter(|&offset| text[inner_start + offset..].starts_with('>'))
+        .ok_or(AutolinkErr::NotClosed)?;
+    let address = &text[inner_start..inner_start + close];
+    let end = inner_start + close + 1;
+
+    if is_absolute_uri(address) {
+        return Ok((InlineNode::autolink(address, false), end));
+    }
+    if is_email_address(address) {
+        return Ok((InlineNode::autolink(address, true), end));
+    }
+    Err(AutolinkErr::InvalidAddress)
+}
+
+/// 절대 URI: scheme(영문자로 시작, 영숫자 `+` `.` `-`, 2-32자) + `:` + 공백/제어문자/`<>` 없는 문자열
+fn is_absolute_uri(address: &str) -> bool {
+    let Some((scheme, rest)) = address.split_once(':') else {
+        return false;
+    };
+
+    let valid_scheme = (MIN_SCHEME_LEN..=MAX_SCHEME_LEN).contains(&scheme.len())
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'));
+
+    valid_scheme && !rest.chars().any(|c| c.is_ascii_control() || matches!(c, ' ' | '<' | '>'))
+}
+
+/// email 주소 (HTML5 명세의 valid email address)
+fn is_email_address(address: &str) -> bool {
+    let Some((local, domain)) = address.split_once('@') else {
+        return false;
+    };
+
+    let valid_local = !local.is_empty()
+        && local.chars().all(|c| c.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(c));
+
+    valid_local && domain.split('.').all(is_domain_label)
+}
+
+/// 도메인 label: 영숫자로 시작/끝, 중간에 `-` 허용, 최대 63자
+fn is_domain_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= MAX_DOMAIN_LABEL_LEN
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    // Example 594-601: URI autolink
+    #[case("<http://foo.bar.baz>", vec![InlineNode::autolink("http://foo.bar.baz", false)])]
+    #[case(
+        "<https://foo.bar.baz/test?q=hello&id=22&boolean>",
+        vec![InlineNode::autolink("https://foo.bar.baz/test?q=hello&id=22&boolean", false)]
+    )]
+    #[case("<irc://foo.bar:2233/baz>", vec![InlineNode::autolink("irc://foo.bar:2233/baz", false)])]
+    #[case("<MAILTO:FOO@BAR.BAZ>", vec![InlineNode::autolink("MAILTO:FOO@BAR.BAZ", false)])]
+    #[case("<a+b+c:d>", vec![InlineNode::autolink("a+b+c:d", false)])]
+    #[case("<made-up-scheme://foo,bar>", vec![InlineNode::autolink("made-up-scheme://foo,bar", false)])]
+    #[case("<https://../>", vec![InlineNode::autolink("https://../", false)])]
+    #[case("<localhost:5001/foo>", vec![InlineNode::autolink("localhost:5001/foo", false)])]
+    // Example 602: 공백 불가
+    #[case("<https://foo.bar/baz bim>", vec![InlineNode::text("<https://foo.bar/baz bim>")])]
+    // Example 603: autolink 안에서는 이스케이프 안 됨
+    #[case("<https://example.com/\\[\\>", vec![InlineNode::autolink("https://example.com/\\[\\", false)])]
+    // Example 604-605: email autolink
+    #[case("<foo@bar.example.com>", vec![InlineNode::autolink("foo@bar.example.com", true)])]
+    #[case("<foo+special@Bar.baz-bar0.com>", vec![InlineNode::autolink("foo+special@Bar.baz-bar0.com", true)])]
+    // Example 606: email autolink 안의 백슬래시 불가
+    #[case("<foo\\+@bar.example.com>", vec![InlineNode::text("<foo+@bar.example.com>")])]
+    // Example 607-610: autolink 아님
+    #[case("<>", vec![InlineNode::text("<>")])]
+    #[case(
+        "< https://foo.bar >",
+        vec![InlineNode::text("< "), InlineNode::extended_autolink("https://foo.bar", false), InlineNode::text(" >")]
+    )]
+    #[case("<m:abc>", vec![InlineNode::text("<m:abc>")])]
+    #[case("<foo.bar.baz>", vec![InlineNode::text("<foo.bar.baz>")])]
+    // Example 611-612: 꺾쇠 없으면 CommonMark autolink 아님 (GFM extended autolink가 됨)
+    #[case("https://example.com", vec![InlineNode::extended_autolink("https://example.com", false)])]
+    #[case("foo@bar.example.com", vec![InlineNode::extended_autolink("foo@bar.example.com", true)])]
+    // Example 20: 이스케이프는 autolink 안에서 동작 안 함
+    #[case("<https://example.com?find=\\*>", vec![InlineNode::autolink("https://example.com?find=\\*", false)])]
+    // Example 345: 먼저 시작한 code span이 우선
+    #[case("`<https://foo.bar.`baz>`", vec![InlineNode::code_span("<https://foo.bar."), InlineNode::text("baz>`")])]
+    // Example 346: 먼저 시작한 autolink가 우선
+    #[case("<https://foo.bar.`baz>`", vec![InlineNode::autolink("https://foo.bar.`baz", false), InlineNode::text("`")])]
+    // Example 526: autolink가 링크 텍스트보다 우선
+    #[case(
+        "[foo<https://example.com/?search=](uri)>",
+        vec![InlineNode::text("[foo"), InlineNode::autolink("https://example.com/?search=](uri)", false)]
+    )]
+    fn test_autolink(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+}
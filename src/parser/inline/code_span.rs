@@ -0,0 +1,109 @@
+//! https://spec.commonmark.org/0.31.2/#code-spans
+
+use crate::node::InlineNode;
+use crate::parser::helpers::count_leading_char;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum CodeSpanErr {
+    /// 같은 길이의 닫는 백틱 문자열 없음 (여는 백틱 문자열은 리터럴)
+    NotClosed { opening_len: usize },
+}
+
+/// pos 위치의 백틱 문자열로 시작하는 code span 파싱
+/// 반환: (CodeSpan 노드, code span 다음 위치)
+pub(super) fn parse(text: &str, pos: usize) -> Result<(InlineNode, usize), CodeSpanErr> {
+    let opening_len = count_leading_char(&text[pos..], '`');
+    let content_start = pos + opening_len;
+
+    // 같은 길이의 백틱 문자열 찾기 (더 길거나 짧은 문자열은 내용의 일부)
+    let mut search = content_start;
+    while let Some(offset) = text[search..].find('`') {
+        let run_start = search + offset;
+        let run_len = count_leading_char(&text[run_start..], '`');
+        if run_len == opening_len {
+            let content = normalize(&text[content_start..run_start]);
+            return Ok((InlineNode::code_span(&content), run_start + run_len));
+        }
+        search = run_start + run_len;
+    }
+
+    Err(CodeSpanErr::NotClosed { opening_len })
+}
+
+/// 내용 정규화
+/// - 줄바꿈은 공백으로 변환
+/// - 양끝이 모두 공백이고 전체가 공백이 아니면 양쪽 공백 하나씩 제거 (Example 329, 331)
+fn normalize(raw: &str) -> String {
+    let content = raw.replace('\n', " ");
+    let is_padded = content.starts_with(' ') && content.ends_with(' ');
+    let all_spaces = content.chars().all(|c| c == ' ');
+
+    if is_padded && !all_spaces {
+        content[1..content.len() - 1].to_string()
+    } else {
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    // Example 328: 기본 code span
+    #[case("`foo`", vec![InlineNode::code_span("foo")])]
+    // Example 329: 내용에 백틱이 있으면 더 긴 백틱 문자열 사용, 양끝 공백 하나씩 제거
+    #[case("`` foo ` bar ``", vec![InlineNode::code_span("foo ` bar")])]
+    // Example 330: 공백 제거로 백틱만 남음
+    #[case("` `` `", vec![InlineNode::code_span("``")])]
+    // Example 331: 공백은 하나씩만 제거
+    #[case("`  ``  `", vec![InlineNode::code_span(" `` ")])]
+    // Example 332: 한쪽만 공백이면 제거 안 함
+    #[case("` a`", vec![InlineNode::code_span(" a")])]
+    // Example 333: non-breaking space는 제거 대상 아님
+    #[case("`\u{a0}b\u{a0}`", vec![InlineNode::code_span("\u{a0}b\u{a0}")])]
+    // Example 338: 백슬래시 이스케이프는 code span 안에서 동작 안 함
+    #[case("`foo\\`bar`", vec![InlineNode::code_span("foo\\"), InlineNode::text("bar`")])]
+    // Example 339-340: 내용 중 다른 길이의 백틱 문자열
+    #[case("``foo`bar``", vec![InlineNode::code_span("foo`bar")])]
+    #[case("` foo `` bar `", vec![InlineNode::code_span("foo `` bar")])]
+    // Example 341: code span이 강조보다 우선
+    #[case("*foo`*`", vec![InlineNode::text("*foo"), InlineNode::code_span("*")])]
+    // Example 347: 길이가 맞지 않으면 리터럴
+    #[case("```foo``", vec![InlineNode::text("```foo``")])]
+    // Example 348: 닫히지 않은 백틱
+    #[case("`foo", vec![InlineNode::text("`foo")])]
+    // Example 349: 여는 백틱만 리터럴, 이후 code span
+    #[case("`foo``bar``", vec![InlineNode::text("`foo"), InlineNode::code_span("bar")])]
+    // Example 478-479: 강조 안의 code span
+    #[case("*a `*`*", vec![InlineNode::emphasis(vec![InlineNode::text("a "), InlineNode::code_span("*")])])]
+    #[case("_a `_`_", vec![InlineNode::emphasis(vec![InlineNode::text("a "), InlineNode::code_span("_")])])]
+    // 추가 케이스
+    #[case("`a` 와 `b`", vec![InlineNode::code_span("a"), InlineNode::text(" 와 "), InlineNode::code_span("b")])]
+    #[case("`  `", vec![InlineNode::code_span("  ")])]
+    fn test_code_span(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // Example 121: 백틱 2개는 펜스가 아님 → 줄바꿈은 공백, 양끝 공백 제거
+    #[case("``\nfoo\n``", vec![InlineNode::code_span("foo")])]
+    // Example 334: 여러 줄
+    #[case(
+        "`\u{a0}`\n`  `",
+        vec![InlineNode::code_span("\u{a0}"), InlineNode::soft_break(), InlineNode::code_span("  ")]
+    )]
+    // Example 335-337: 줄 끝 공백은 code span 내용으로 보존
+    #[case("``\nfoo\nbar  \nbaz\n``", vec![InlineNode::code_span("foo bar   baz")])]
+    #[case("``\nfoo \n``", vec![InlineNode::code_span("foo ")])]
+    #[case("`foo   bar \nbaz`", vec![InlineNode::code_span("foo   bar  baz")])]
+    // 줄바꿈은 공백으로 변환
+    #[case("``\nfoo\nbar\n``", vec![InlineNode::code_span("foo bar")])]
+    fn test_code_span_multiline(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+}
@@ -0,0 +1,58 @@
+//! https://help.obsidian.md/syntax#Comments
+//!
+//! Obsidian 인라인 주석 `%%note%%`를 파싱합니다.
+//! 여는 `%%` 뒤 첫 `%%`에서 닫히며, 같은 Leaf 블록 안이면 줄을 넘어도 됩니다.
+//! 내용은 원문 그대로 남기며 강조, 링크 등은 해석하지 않습니다.
+//! 닫는 `%%`가 없으면 리터럴 텍스트입니다.
+
+use crate::node::InlineNode;
+
+/// 주석 구분자
+const MARKER: &str = "%%";
+
+/// pos 위치(`%%`)에서 주석 파싱
+/// 반환: (Comment 노드, 닫는 `%%` 다음 위치)
+pub(super) fn parse(text: &str, pos: usize) -> Option<(InlineNode, usize)> {
+    let content_start = pos + MARKER.len();
+    let rest = text.get(pos..)?.strip_prefix(MARKER)?;
+    let len = rest.find(MARKER)?;
+    let end = content_start + len + MARKER.len();
+    Some((InlineNode::comment(&rest[..len]), end))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{LineColumns, line_columns};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("%%note%%", vec![InlineNode::comment("note")])]
+    #[case("a %%b%% c", vec![InlineNode::text("a "), InlineNode::comment("b"), InlineNode::text(" c")])]
+    #[case("%%%%", vec![InlineNode::comment("")])]
+    // 내용은 해석하지 않음
+    #[case("%%*em* [[link]]%%", vec![InlineNode::comment("*em* [[link]]")])]
+    // 줄을 넘는 주석
+    #[case("a %%one\ntwo%% b", vec![InlineNode::text("a "), InlineNode::comment("one\ntwo"), InlineNode::text(" b")])]
+    // 강조를 가로지르는 주석
+    #[case("*a %%b* c%%", vec![InlineNode::text("*a "), InlineNode::comment("b* c")])]
+    // 주석 아님
+    #[case("100%", vec![InlineNode::text("100%")])]
+    #[case("a %%unclosed", vec![InlineNode::text("a %%unclosed")])]
+    #[case("`%%code%%`", vec![InlineNode::code_span("%%code%%")])]
+    #[case("\\%%a%%", vec![InlineNode::text("%%a%%")])]
+    fn test_comment(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        assert_eq!(parse(input).without_spans().children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[test]
+    fn test_comment_span() {
+        let doc = parse("a %%b%% c");
+        let BlockNode::Paragraph(node) = &doc.children[0] else {
+            panic!("Paragraph여야 함");
+        };
+        let expected: LineColumns = ((1, 3), (1, 8));
+        assert_eq!(line_columns(node.children[1].span()), expected);
+    }
+}
@@ -0,0 +1,92 @@
+//! https://help.obsidian.md/embeds
+//!
+//! Obsidian 임베드 `![[note]]`, `![[note#Heading]]`, `![[note#^block]]`, `![[image.png|300x200]]`를 파싱합니다.
+//! `!` 뒤의 `[[...]]`는 위키링크와 같은 규칙으로 나누고, `|` 뒤가 `300` 또는 `300x200` 형태면 크기로 해석합니다.
+
+use super::wikilink;
+use crate::node::{EmbedNode, InlineNode};
+
+/// pos 위치(`![[`)에서 임베드 파싱
+/// 반환: (Embed 노드, `]]` 다음 위치)
+pub(super) fn parse(text: &str, pos: usize) -> Option<(InlineNode, usize)> {
+    let (link, end) = wikilink::parse_link(text, pos + "!".len())?;
+
+    let mut node = EmbedNode::new(&link.target);
+    if let Some(heading) = &link.heading {
+        node = node.with_heading(heading);
+    }
+    if let Some(block_ref) = &link.block_ref {
+        node = node.with_block_ref(block_ref);
+    }
+    if let Some(alias) = &link.alias {
+        node = match parse_size(alias) {
+            Some((width, height)) => node.with_size(width, height),
+            None => node.with_alias(alias),
+        };
+    }
+
+    Some((InlineNode::Embed(node), end))
+}
+
+/// 크기 힌트: `너비` 또는 `너비x높이` (숫자만)
+fn parse_size(s: &str) -> Option<(u32, Option<u32>)> {
+    match s.split_once('x') {
+        Some((width, height)) => Some((parse_number(width)?, Some(parse_number(height)?))),
+        None => Some((parse_number(s)?, None)),
+    }
+}
+
+fn parse_number(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, EmbedNode, InlineNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("![[note]]", vec![InlineNode::embed("note")])]
+    #[case("![[note#Section]]", vec![InlineNode::Embed(EmbedNode::new("note").with_heading("Section"))])]
+    #[case("![[note#^abc]]", vec![InlineNode::Embed(EmbedNode::new("note").with_block_ref("abc"))])]
+    // 크기 힌트
+    #[case("![[image.png|300]]", vec![InlineNode::Embed(EmbedNode::new("image.png").with_size(300, None))])]
+    #[case("![[image.png|300x200]]", vec![InlineNode::Embed(EmbedNode::new("image.png").with_size(300, Some(200)))])]
+    // 크기가 아니면 표시 텍스트
+    #[case("![[image.png|a cat]]", vec![InlineNode::Embed(EmbedNode::new("image.png").with_alias("a cat"))])]
+    #[case("![[image.png|300x]]", vec![InlineNode::Embed(EmbedNode::new("image.png").with_alias("300x"))])]
+    #[case("![[image.png|+300]]", vec![InlineNode::Embed(EmbedNode::new("image.png").with_alias("+300"))])]
+    // 문장 중간
+    #[case("see ![[note]] here", vec![InlineNode::text("see "), InlineNode::embed("note"), InlineNode::text(" here")])]
+    // 임베드가 아니면 `!`는 텍스트, 뒤는 위키링크
+    #[case("! [[note]]", vec![InlineNode::text("! "), InlineNode::wikilink("note")])]
+    #[case("![[]]", vec![InlineNode::text("![[]]")])]
+    fn test_embed(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[test]
+    fn test_embed_span() {
+        let doc = parse("a ![[b.png|10]]");
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        let actual: Vec<LineColumns> = node.children.iter().map(|child| line_columns(child.span())).collect();
+        assert_eq!(actual, vec![((1, 1), (1, 3)), ((1, 3), (1, 16))]);
+    }
+
+    #[rstest]
+    #[case("image.png", true)]
+    #[case("photos/Cat.JPG", true)]
+    #[case("note", false)]
+    #[case("note.md", false)]
+    fn test_is_image(#[case] target: &str, #[case] expected: bool) {
+        assert_eq!(EmbedNode::new(target).is_image(), expected);
+    }
+}
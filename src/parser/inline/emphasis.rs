@@ -0,0 +1,712 @@
+//! https://spec.commonmark.org/0.31.2/#emphasis-and-strong-emphasis
+//! https://github.github.com/gfm/#strikethrough-extension-
+//!
+//! 구분자 run 감지(left/right-flanking)와 delimiter stack 기반 짝 맞추기를 담당합니다.
+//! GFM 취소선(`~`, `~~`)도 같은 알고리즘으로 처리하되, 길이가 같은 run끼리만 짝이 됩니다.
+//! Obsidian 하이라이트(`==`)는 길이가 정확히 2인 run끼리만 짝이 됩니다 (`a = b`, `===`는 리터럴).
+//! 짝이 맞으면 토큰을 재배치하지 않고 각 run에 여는/닫는 강조 종류만 기록하며,
+//! 실제 중첩 구조는 트리 구성 단계에서 만들어집니다.
+
+use std::collections::HashMap;
+
+use super::Token;
+use crate::node::InlineNode;
+use crate::parser::helpers::{is_punctuation, is_unicode_whitespace};
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// 취소선 구분자 최대 길이 (더 길면 리터럴)
+const MAX_STRIKETHROUGH_LEN: usize = 2;
+
+/// 하이라이트 구분자 길이 (다른 길이는 리터럴)
+const HIGHLIGHT_LEN: usize = 2;
+
+/// 강조 종류
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum EmphasisKind {
+    /// 구분자 1개 사용 (`*`, `_`)
+    Emphasis,
+    /// 구분자 2개 사용 (`**`, `__`)
+    Strong,
+    /// 취소선 (`~` 또는 `~~`, 사용한 구분자 길이)
+    Strikethrough(usize),
+    /// 하이라이트 (`==`)
+    Highlight,
+}
+
+impl EmphasisKind {
+    /// 사용하는 구분자 길이
+    pub(super) fn delimiter_len(self) -> usize {
+        match self {
+            EmphasisKind::Emphasis => 1,
+            EmphasisKind::Strong => 2,
+            EmphasisKind::Strikethrough(len) => len,
+            EmphasisKind::Highlight => HIGHLIGHT_LEN,
+        }
+    }
+
+    /// 자식 노드를 감싸는 인라인 노드 생성
+    pub(super) fn to_node(self, children: Vec<InlineNode>) -> InlineNode {
+        match self {
+            EmphasisKind::Emphasis => InlineNode::emphasis(children),
+            EmphasisKind::Strong => InlineNode::strong(children),
+            EmphasisKind::Strikethrough(_) => InlineNode::strikethrough(children),
+            EmphasisKind::Highlight => InlineNode::highlight(children),
+        }
+    }
+}
+
+/// 구분자 run (같은 `*`, `_`, `~` 또는 `=` 문자의 연속)
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct DelimiterRun {
+    /// 구분자 문자 ('*', '_', '~' 또는 '=')
+    pub ch: char,
+    /// run 시작 위치
+    pub start: usize,
+    /// 원래 길이 (rule of 3 판단용)
+    pub orig_len: usize,
+    /// 아직 사용되지 않은 길이 (남으면 리터럴 텍스트)
+    pub len: usize,
+    /// 여는 구분자가 될 수 있는지
+    pub can_open: bool,
+    /// 닫는 구분자가 될 수 있는지
+    pub can_close: bool,
+    /// delimiter stack에 남아 있는지 (제거되면 리터럴로만 취급)
+    pub active: bool,
+    /// 이 run 뒤에서 열리는 강조들 (짝이 맞은 순서, 안쪽부터)
+    pub opens: Vec<EmphasisKind>,
+    /// 이 run 앞에서 닫히는 강조들 (짝이 맞은 순서, 안쪽부터)
+    pub closes: Vec<EmphasisKind>,
+}
+
+// =============================================================================
+// 구분자 run 감지
+// =============================================================================
+
+/// pos 위치에서 구분자 run을 읽고 flanking 규칙으로 여닫기 가능 여부 판단
+/// 반환: (구분자 run, run 다음 위치)
+pub(super) fn scan_delimiter_run(text: &str, pos: usize) -> (DelimiterRun, usize) {
+    let ch = text[pos..].chars().next().expect("구분자 문자가 있어야 함");
+    let len = text[pos..].chars().take_while(|&c| c == ch).count();
+    let end = pos + len * ch.len_utf8();
+
+    // 줄의 시작과 끝은 공백으로 취급
+    let before = text[..pos].chars().next_back().unwrap_or('\n');
+    let after = text[end..].chars().next().unwrap_or('\n');
+
+    let left_flanking = is_left_flanking(before, after);
+    let right_flanking = is_right_flanking(before, after);
+
+    let (can_open, can_close) = match ch {
+        // `_`는 단어 내부 강조 불가 (Example 360, 374)
+        '_' => (
+            left_flanking && (!right_flanking || is_punctuation(before)),
+            right_flanking && (!left_flanking || is_punctuation(after)),
+        ),
+        // `~~~` 이상은 취소선이 아님 (GFM Example 493)
+        '~' if len > MAX_STRIKETHROUGH_LEN => (false, false),
+        '=' if len != HIGHLIGHT_LEN => (false, false),
+        _ => (left_flanking, right_flanking),
+    };
+
+    let run = DelimiterRun {
+        ch,
+        start: pos,
+        orig_len: len,
+        len,
+        can_open,
+        can_close,
+        active: true,
+        opens: Vec::new(),
+        closes: Vec::new(),
+    };
+    (run, end)
+}
+
+/// Left-flanking 여부
+/// 뒤가 공백이 아니고, 뒤가 구두점이면 앞은 공백 또는 구두점이어야 함
+fn is_left_flanking(before: char, after: char) -> bool {
+    !is_unicode_whitespace(after)
+        && (!is_punctuation(after) || is_unicode_whitespace(before) || is_punctuation(before))
+}
+
+/// Right-flanking 여부
+/// 앞이 공백이 아니고, 앞이 구두점이면 뒤는 공백 또는 구두점이어야 함
+fn is_right_flanking(before: char, after: char) -> bool {
+    !is_unicode_whitespace(before)
+        && (!is_punctuation(before) || is_unicode_whitespace(after) || is_punctuation(after))
+}
+
+// =============================================================================
+// 강조 처리 (process emphasis)
+// =============================================================================
+
+/// openers_bottom 키: (구분자 문자, 닫는 run 길이 % 3, 닫는 run의 can_open)
+type OpenersBottomKey = (char, usize, bool);
+
+/// stack_bottom 이후의 구분자 run들에 대해 강조 짝 맞추기
+/// 처리가 끝나면 stack_bottom 이후의 구분자는 모두 stack에서 제거됨
+pub(super) fn process(tokens: &mut [Token], stack_bottom: usize) {
+    let mut openers_bottom: HashMap<OpenersBottomKey, usize> = HashMap::new();
+    // 현재 위치 앞에서 stack에 남아 있는 구분자 토큰 위치 (여는 구분자 후보, 원문 순서)
+    let mut stack: Vec<usize> = Vec::new();
+    let mut current = stack_bottom;
+
+    while current < tokens.len() {
+        let Some(closer) = active_run(&tokens[current]).filter(|r| r.can_close && r.len > 0) else {
+            if active_run(&tokens[current]).is_some() {
+                stack.push(current);
+            }
+            current += 1;
+            continue;
+        };
+        let key = (closer.ch, closer.orig_len % 3, closer.can_open);
+        let lower = openers_bottom.get(&key).copied().unwrap_or(stack_bottom).max(stack_bottom);
+
+        // 가장 가까운 여는 구분자를 뒤에서부터 탐색
+        let opener_position = stack
+            .iter()
+            .rposition(|&i| i < lower || active_run(&tokens[i]).is_some_and(|opener| is_matching_opener(opener, closer)))
+            .filter(|&position| stack[position] >= lower);
+
+        match opener_position {
+            Some(position) => {
+                let opener_index = stack[position];
+                match_pair(tokens, opener_index, current);
+                // 사이의 구분자는 stack에서 제거 (다 쓴 여는 구분자도 제거)
+                for &i in &stack[position + 1..] {
+                    deactivate(&mut tokens[i]);
+                }
+                stack.truncate(position + 1);
+                if active_run(&tokens[opener_index]).is_none() {
+                    stack.pop();
+                }
+                // 닫는 run을 모두 썼으면 다음으로, 남았으면 같은 run으로 다시 시도
+                if active_run(&tokens[current]).is_none_or(|r| r.len == 0) {
+                    current += 1;
+                }
+            }
+            None => {
+                // 같은 조건의 닫는 구분자는 이 위치 이전을 다시 탐색할 필요 없음
+                openers_bottom.insert(key, current);
+                match active_run(&tokens[current]).is_some_and(|r| r.can_open) {
+                    true => stack.push(current),
+                    false => deactivate(&mut tokens[current]),
+                }
+                current += 1;
+            }
+        }
+    }
+
+    // 짝이 맞지 않은 구분자는 리터럴 텍스트로 남음
+    for &i in &stack {
+        deactivate(&mut tokens[i]);
+    }
+}
+
+/// 구분자 run을 stack에서 제거 (리터럴로만 취급)
+fn deactivate(token: &mut Token) {
+    if let Token::Delimiter(run) = token {
+        run.active = false;
+    }
+}
+
+/// stack에 남아 있는 구분자 run이면 반환
+fn active_run(token: &Token) -> Option<&DelimiterRun> {
+    match token {
+        Token::Delimiter(run) if run.active => Some(run),
+        _ => None,
+    }
+}
+
+/// 여는 구분자로 짝이 될 수 있는지 확인 (rule of 3 포함)
+fn is_matching_opener(opener: &DelimiterRun, closer: &DelimiterRun) -> bool {
+    if opener.ch != closer.ch || !opener.can_open || opener.len == 0 {
+        return false;
+    }
+
+    // 취소선과 하이라이트는 길이가 같은 run끼리만 짝이 됨
+    if opener.ch == '~' || opener.ch == '=' {
+        return opener.len == closer.len;
+    }
+
+    // Rule of 3: 양쪽 모두 가능한 run이 섞이면 길이 합이 3의 배수일 수 없음
+    // 단, 두 run 모두 3의 배수 길이면 허용 (Example 411, 412)
+    let both_sides = opener.can_close || closer.can_open;
+    let sum_multiple_of_3 = (opener.orig_len + closer.orig_len).is_multiple_of(3);
+    let each_multiple_of_3 = opener.orig_len.is_multiple_of(3) && closer.orig_len.is_multiple_of(3);
+    !(both_sides && sum_multiple_of_3 && !each_multiple_of_3)
+}
+
+/// 여는/닫는 run 짝 기록 (사이의 구분자 제거는 호출하는 쪽에서)
+fn match_pair(tokens: &mut [Token], opener_index: usize, closer_index: usize) {
+    let (head, tail) = tokens.split_at_mut(closer_index);
+    let (Token::Delimiter(opener), Token::Delimiter(closer)) = (&mut head[opener_index], &mut tail[0]) else {
+        unreachable!("opener와 closer는 구분자 토큰이어야 함");
+    };
+
+    let kind = if opener.ch == '~' {
+        EmphasisKind::Strikethrough(closer.len)
+    } else if opener.ch == '=' {
+        EmphasisKind::Highlight
+    } else if opener.len >= 2 && closer.len >= 2 {
+        EmphasisKind::Strong
+    } else {
+        EmphasisKind::Emphasis
+    };
+    let used = kind.delimiter_len();
+
+    opener.len -= used;
+    opener.opens.push(kind);
+    closer.len -= used;
+    closer.closes.push(kind);
+
+    if opener.len == 0 {
+        opener.active = false;
+    }
+    if closer.len == 0 {
+        closer.active = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
+    use rstest::rstest;
+
+    #[rstest]
+    // Example 350: 기본 강조
+    #[case("*foo bar*", vec![InlineNode::emphasis(vec![InlineNode::text("foo bar")])])]
+    // Example 351: 여는 * 뒤 공백 → left-flanking 아님
+    #[case("a * foo bar*", vec![InlineNode::text("a * foo bar*")])]
+    // Example 352: 영숫자 뒤 구두점 앞 * → left-flanking 아님
+    #[case("a*\"foo\"*", vec![InlineNode::text("a*\"foo\"*")])]
+    // Example 353: non-breaking space도 공백
+    #[case("*\u{a0}a\u{a0}*", vec![InlineNode::text("*\u{a0}a\u{a0}*")])]
+    // Example 354: 통화 기호도 구두점
+    #[case("*€*charlie.", vec![InlineNode::text("*€*charlie.")])]
+    // Example 355-356: * 는 단어 내부 강조 가능
+    #[case("foo*bar*", vec![InlineNode::text("foo"), InlineNode::emphasis(vec![InlineNode::text("bar")])])]
+    #[case(
+        "5*6*78",
+        vec![InlineNode::text("5"), InlineNode::emphasis(vec![InlineNode::text("6")]), InlineNode::text("78")]
+    )]
+    // Example 357: _ 기본 강조
+    #[case("_foo bar_", vec![InlineNode::emphasis(vec![InlineNode::text("foo bar")])])]
+    // Example 358: 여는 _ 뒤 공백
+    #[case("_ foo bar_", vec![InlineNode::text("_ foo bar_")])]
+    // Example 360-362: _ 는 단어 내부 강조 불가
+    #[case("foo_bar_", vec![InlineNode::text("foo_bar_")])]
+    #[case("5_6_78", vec![InlineNode::text("5_6_78")])]
+    #[case("пристаням_стремятся_", vec![InlineNode::text("пристаням_стремятся_")])]
+    // Example 364: 구두점 앞뒤 _
+    #[case("foo-_(bar)_", vec![InlineNode::text("foo-"), InlineNode::emphasis(vec![InlineNode::text("(bar)")])])]
+    // Example 365: 다른 문자끼리는 짝이 안 됨
+    #[case("_foo*", vec![InlineNode::text("_foo*")])]
+    // Example 366: 닫는 * 앞 공백
+    #[case("*foo bar *", vec![InlineNode::text("*foo bar *")])]
+    // Example 368-369: 구두점 사이 구분자
+    #[case("*(*foo)", vec![InlineNode::text("*(*foo)")])]
+    #[case(
+        "*(*foo*)*",
+        vec![InlineNode::emphasis(vec![
+            InlineNode::text("("),
+            InlineNode::emphasis(vec![InlineNode::text("foo")]),
+            InlineNode::text(")"),
+        ])]
+    )]
+    // Example 370: 닫는 * 뒤 영숫자
+    #[case("*foo*bar", vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text("bar")])]
+    // Example 374, 376: _ 는 단어 내부에서 닫히지 않음
+    #[case("_foo_bar", vec![InlineNode::text("_foo_bar")])]
+    #[case("_foo_bar_baz_", vec![InlineNode::emphasis(vec![InlineNode::text("foo_bar_baz")])])]
+    // Example 377: 닫는 _ 뒤 구두점
+    #[case("_(bar)_.", vec![InlineNode::emphasis(vec![InlineNode::text("(bar)")]), InlineNode::text(".")])]
+    // Example 378-382: 기본 강한 강조
+    #[case("**foo bar**", vec![InlineNode::strong(vec![InlineNode::text("foo bar")])])]
+    #[case("** foo bar**", vec![InlineNode::text("** foo bar**")])]
+    #[case("foo**bar**", vec![InlineNode::text("foo"), InlineNode::strong(vec![InlineNode::text("bar")])])]
+    #[case("__foo bar__", vec![InlineNode::strong(vec![InlineNode::text("foo bar")])])]
+    // Example 386: __ 는 단어 내부 강조 불가
+    #[case("foo__bar__", vec![InlineNode::text("foo__bar__")])]
+    // Example 389: 중첩 강한 강조
+    #[case(
+        "__foo, __bar__, baz__",
+        vec![InlineNode::strong(vec![
+            InlineNode::text("foo, "),
+            InlineNode::strong(vec![InlineNode::text("bar")]),
+            InlineNode::text(", baz"),
+        ])]
+    )]
+    // Example 393: 강조 안의 강한 강조
+    #[case(
+        "*(**foo**)*",
+        vec![InlineNode::emphasis(vec![
+            InlineNode::text("("),
+            InlineNode::strong(vec![InlineNode::text("foo")]),
+            InlineNode::text(")"),
+        ])]
+    )]
+    // Example 396: 닫는 ** 뒤 영숫자
+    #[case("**foo**bar", vec![InlineNode::strong(vec![InlineNode::text("foo")]), InlineNode::text("bar")])]
+    // Example 402: __ 단어 내부
+    #[case("__foo__bar__baz__", vec![InlineNode::strong(vec![InlineNode::text("foo__bar__baz")])])]
+    // Example 406-408: 중첩
+    #[case(
+        "_foo __bar__ baz_",
+        vec![InlineNode::emphasis(vec![
+            InlineNode::text("foo "),
+            InlineNode::strong(vec![InlineNode::text("bar")]),
+            InlineNode::text(" baz"),
+        ])]
+    )]
+    #[case(
+        "_foo _bar_ baz_",
+        vec![InlineNode::emphasis(vec![
+            InlineNode::text("foo "),
+            InlineNode::emphasis(vec![InlineNode::text("bar")]),
+            InlineNode::text(" baz"),
+        ])]
+    )]
+    #[case(
+        "__foo_ bar_",
+        vec![InlineNode::emphasis(vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text(" bar")])]
+    )]
+    #[case(
+        "*foo *bar**",
+        vec![InlineNode::emphasis(vec![InlineNode::text("foo "), InlineNode::emphasis(vec![InlineNode::text("bar")])])]
+    )]
+    // Example 411-412: rule of 3
+    #[case(
+        "*foo**bar**baz*",
+        vec![InlineNode::emphasis(vec![
+            InlineNode::text("foo"),
+            InlineNode::strong(vec![InlineNode::text("bar")]),
+            InlineNode::text("baz"),
+        ])]
+    )]
+    #[case("*foo**bar*", vec![InlineNode::emphasis(vec![InlineNode::text("foo**bar")])])]
+    // Example 413: ***foo** bar*
+    #[case(
+        "***foo** bar*",
+        vec![InlineNode::emphasis(vec![InlineNode::strong(vec![InlineNode::text("foo")]), InlineNode::text(" bar")])]
+    )]
+    // Example 414-415
+    #[case(
+        "*foo **bar***",
+        vec![InlineNode::emphasis(vec![InlineNode::text("foo "), InlineNode::strong(vec![InlineNode::text("bar")])])]
+    )]
+    #[case(
+        "*foo**bar***",
+        vec![InlineNode::emphasis(vec![InlineNode::text("foo"), InlineNode::strong(vec![InlineNode::text("bar")])])]
+    )]
+    // Example 416-417: 단어 내부 ***
+    #[case(
+        "foo***bar***baz",
+        vec![
+            InlineNode::text("foo"),
+            InlineNode::emphasis(vec![InlineNode::strong(vec![InlineNode::text("bar")])]),
+            InlineNode::text("baz"),
+        ]
+    )]
+    #[case(
+        "foo******bar*********baz",
+        vec![
+            InlineNode::text("foo"),
+            InlineNode::strong(vec![InlineNode::strong(vec![InlineNode::strong(vec![InlineNode::text("bar")])])]),
+            InlineNode::text("***baz"),
+        ]
+    )]
+    // Example 418: 깊은 중첩
+    #[case(
+        "*foo **bar *baz* bim** bop*",
+        vec![InlineNode::emphasis(vec![
+            InlineNode::text("foo "),
+            InlineNode::strong(vec![
+                InlineNode::text("bar "),
+                InlineNode::emphasis(vec![InlineNode::text("baz")]),
+                InlineNode::text(" bim"),
+            ]),
+            InlineNode::text(" bop"),
+        ])]
+    )]
+    // Example 420-421: 빈 강조는 없음
+    #[case("** is not an empty emphasis", vec![InlineNode::text("** is not an empty emphasis")])]
+    #[case("**** is not an empty strong emphasis", vec![InlineNode::text("**** is not an empty strong emphasis")])]
+    // Example 426-427
+    #[case(
+        "____foo__ bar__",
+        vec![InlineNode::strong(vec![InlineNode::strong(vec![InlineNode::text("foo")]), InlineNode::text(" bar")])]
+    )]
+    #[case(
+        "**foo **bar****",
+        vec![InlineNode::strong(vec![InlineNode::text("foo "), InlineNode::strong(vec![InlineNode::text("bar")])])]
+    )]
+    // Example 429-431
+    #[case(
+        "**foo*bar*baz**",
+        vec![InlineNode::strong(vec![
+            InlineNode::text("foo"),
+            InlineNode::emphasis(vec![InlineNode::text("bar")]),
+            InlineNode::text("baz"),
+        ])]
+    )]
+    #[case(
+        "***foo* bar**",
+        vec![InlineNode::strong(vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text(" bar")])]
+    )]
+    #[case(
+        "**foo *bar***",
+        vec![InlineNode::strong(vec![InlineNode::text("foo "), InlineNode::emphasis(vec![InlineNode::text("bar")])])]
+    )]
+    // Example 436, 438-439
+    #[case("foo ***", vec![InlineNode::text("foo ***")])]
+    #[case("foo *_*", vec![InlineNode::text("foo "), InlineNode::emphasis(vec![InlineNode::text("_")])])]
+    #[case("foo *****", vec![InlineNode::text("foo *****")])]
+    // Example 442-447: 길이가 맞지 않는 구분자
+    #[case("**foo*", vec![InlineNode::text("*"), InlineNode::emphasis(vec![InlineNode::text("foo")])])]
+    #[case("*foo**", vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text("*")])]
+    #[case("***foo**", vec![InlineNode::text("*"), InlineNode::strong(vec![InlineNode::text("foo")])])]
+    #[case("****foo*", vec![InlineNode::text("***"), InlineNode::emphasis(vec![InlineNode::text("foo")])])]
+    #[case("**foo***", vec![InlineNode::strong(vec![InlineNode::text("foo")]), InlineNode::text("*")])]
+    #[case("*foo****", vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text("***")])]
+    // Example 454-459: _ 버전
+    #[case("__foo_", vec![InlineNode::text("_"), InlineNode::emphasis(vec![InlineNode::text("foo")])])]
+    #[case("_foo__", vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text("_")])]
+    #[case("___foo__", vec![InlineNode::text("_"), InlineNode::strong(vec![InlineNode::text("foo")])])]
+    #[case("____foo_", vec![InlineNode::text("___"), InlineNode::emphasis(vec![InlineNode::text("foo")])])]
+    #[case("__foo___", vec![InlineNode::strong(vec![InlineNode::text("foo")]), InlineNode::text("_")])]
+    #[case("_foo____", vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text("___")])]
+    // Example 461-468: 중첩 규칙
+    #[case("*_foo_*", vec![InlineNode::emphasis(vec![InlineNode::emphasis(vec![InlineNode::text("foo")])])])]
+    #[case("_*foo*_", vec![InlineNode::emphasis(vec![InlineNode::emphasis(vec![InlineNode::text("foo")])])])]
+    #[case("****foo****", vec![InlineNode::strong(vec![InlineNode::strong(vec![InlineNode::text("foo")])])])]
+    #[case(
+        "******foo******",
+        vec![InlineNode::strong(vec![InlineNode::strong(vec![InlineNode::strong(vec![InlineNode::text("foo")])])])]
+    )]
+    #[case("***foo***", vec![InlineNode::emphasis(vec![InlineNode::strong(vec![InlineNode::text("foo")])])])]
+    #[case(
+        "_____foo_____",
+        vec![InlineNode::emphasis(vec![InlineNode::strong(vec![InlineNode::strong(vec![InlineNode::text("foo")])])])]
+    )]
+    // Example 469-472: 겹치는 강조
+    #[case(
+        "*foo _bar* baz_",
+        vec![InlineNode::emphasis(vec![InlineNode::text("foo _bar")]), InlineNode::text(" baz_")]
+    )]
+    #[case(
+        "*foo __bar *baz bim__ bam*",
+        vec![InlineNode::emphasis(vec![
+            InlineNode::text("foo "),
+            InlineNode::strong(vec![InlineNode::text("bar *baz bim")]),
+            InlineNode::text(" bam"),
+        ])]
+    )]
+    #[case(
+        "**foo **bar baz**",
+        vec![InlineNode::text("**foo "), InlineNode::strong(vec![InlineNode::text("bar baz")])]
+    )]
+    #[case("*foo *bar baz*", vec![InlineNode::text("*foo "), InlineNode::emphasis(vec![InlineNode::text("bar baz")])])]
+    // 추가 케이스: 한글
+    #[case(
+        "*강조*와 **굵게**",
+        vec![
+            InlineNode::emphasis(vec![InlineNode::text("강조")]),
+            InlineNode::text("와 "),
+            InlineNode::strong(vec![InlineNode::text("굵게")]),
+        ]
+    )]
+    fn test_emphasis(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // Example 367, 384, 405, 423: 여러 줄에 걸친 강조
+    #[case("*foo bar\n*", vec![InlineNode::text("*foo bar"), InlineNode::soft_break(), InlineNode::text("*")])]
+    #[case(
+        "*foo\nbar*",
+        vec![InlineNode::emphasis(vec![InlineNode::text("foo"), InlineNode::soft_break(), InlineNode::text("bar")])]
+    )]
+    #[case(
+        "**foo\nbar**",
+        vec![InlineNode::strong(vec![InlineNode::text("foo"), InlineNode::soft_break(), InlineNode::text("bar")])]
+    )]
+    // Example 394: 여러 줄 중첩
+    #[case(
+        "**Gomphocarpus (*Gomphocarpus physocarpus*, syn.\n*Asclepias physocarpa*)**",
+        vec![InlineNode::strong(vec![
+            InlineNode::text("Gomphocarpus ("),
+            InlineNode::emphasis(vec![InlineNode::text("Gomphocarpus physocarpus")]),
+            InlineNode::text(", syn."),
+            InlineNode::soft_break(),
+            InlineNode::emphasis(vec![InlineNode::text("Asclepias physocarpa")]),
+            InlineNode::text(")"),
+        ])]
+    )]
+    fn test_emphasis_multiline(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // ATX Heading 내 강조
+    #[case(
+        "# *foo* bar",
+        vec![BlockNode::heading(1, vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text(" bar")])]
+    )]
+    // Setext Heading 내 강조
+    #[case("**foo**\n===", vec![BlockNode::heading(1, vec![InlineNode::strong(vec![InlineNode::text("foo")])])])]
+    // 리스트/인용 내부 Paragraph
+    #[case(
+        "- *a*",
+        vec![BlockNode::bullet_list(
+            true,
+            vec![crate::node::ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::emphasis(vec![
+                InlineNode::text("a"),
+            ])])])],
+        )]
+    )]
+    #[case(
+        "> **b**",
+        vec![BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::strong(vec![InlineNode::text("b")])])])]
+    )]
+    fn test_emphasis_in_blocks(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, expected);
+    }
+
+    #[rstest]
+    // GFM Example 491: 취소선
+    #[case(
+        "~~Hi~~ Hello, ~there~ world!",
+        vec![
+            InlineNode::strikethrough(vec![InlineNode::text("Hi")]),
+            InlineNode::text(" Hello, "),
+            InlineNode::strikethrough(vec![InlineNode::text("there")]),
+            InlineNode::text(" world!"),
+        ]
+    )]
+    // GFM Example 493: ~~~ 이상은 리터럴
+    #[case("This will ~~~not~~~ strike.", vec![InlineNode::text("This will ~~~not~~~ strike.")])]
+    // 길이가 다른 run끼리는 짝이 안 됨
+    #[case("~foo~~", vec![InlineNode::text("~foo~~")])]
+    #[case("~~foo~", vec![InlineNode::text("~~foo~")])]
+    // 공백 앞뒤의 ~는 flanking 규칙을 따름
+    #[case("a ~ b ~", vec![InlineNode::text("a ~ b ~")])]
+    // 다른 강조와 중첩
+    #[case("*~~foo~~*", vec![InlineNode::emphasis(vec![InlineNode::strikethrough(vec![InlineNode::text("foo")])])])]
+    #[case(
+        "~~**foo** bar~~",
+        vec![InlineNode::strikethrough(vec![
+            InlineNode::strong(vec![InlineNode::text("foo")]),
+            InlineNode::text(" bar"),
+        ])]
+    )]
+    fn test_strikethrough(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // Obsidian 하이라이트
+    #[case("==marked== text", vec![InlineNode::highlight(vec![InlineNode::text("marked")]), InlineNode::text(" text")])]
+    #[case(
+        "a ==b *c*== d",
+        vec![
+            InlineNode::text("a "),
+            InlineNode::highlight(vec![InlineNode::text("b "), InlineNode::emphasis(vec![InlineNode::text("c")])]),
+            InlineNode::text(" d"),
+        ]
+    )]
+    #[case("**==foo==**", vec![InlineNode::strong(vec![InlineNode::highlight(vec![InlineNode::text("foo")])])])]
+    // `=` 하나나 셋 이상, 공백 사이의 `==`는 리터럴
+    #[case("=foo=", vec![InlineNode::text("=foo=")])]
+    #[case("===foo===", vec![InlineNode::text("===foo===")])]
+    #[case("==foo===", vec![InlineNode::text("==foo===")])]
+    #[case("a == b == c", vec![InlineNode::text("a == b == c")])]
+    #[case("x==y", vec![InlineNode::text("x==y")])]
+    fn test_highlight(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[test]
+    fn test_strikethrough_not_across_paragraphs() {
+        // GFM Example 492
+        let doc = parse("This ~~has a\n\nnew paragraph~~.").without_spans();
+        assert_eq!(
+            doc.children,
+            vec![
+                BlockNode::paragraph(vec![InlineNode::text("This ~~has a")]),
+                BlockNode::paragraph(vec![InlineNode::text("new paragraph~~.")]),
+            ]
+        );
+    }
+
+    #[rstest]
+    // 강조는 구분자 포함, 남은 구분자는 run 안의 제자리
+    #[case("*a* **b**", vec![((1, 1), (1, 4)), ((1, 4), (1, 5)), ((1, 5), (1, 10))])]
+    #[case("**a*", vec![((1, 1), (1, 2)), ((1, 2), (1, 5))])]
+    #[case("*a**", vec![((1, 1), (1, 4)), ((1, 4), (1, 5))])]
+    #[case("***x***", vec![((1, 1), (1, 8))])]
+    // 열은 문자 단위
+    #[case("한글 *강조*", vec![((1, 1), (1, 4)), ((1, 4), (1, 8))])]
+    // 줄을 넘는 강조
+    #[case("*a\nb*", vec![((1, 1), (2, 3))])]
+    // 취소선도 구분자 포함
+    #[case("~~a~~ b", vec![((1, 1), (1, 6)), ((1, 6), (1, 8))])]
+    fn test_emphasis_span(#[case] input: &str, #[case] expected: Vec<LineColumns>) {
+        let doc = parse(input);
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        let actual: Vec<LineColumns> = node.children.iter().map(|child| line_columns(child.span())).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_nested_emphasis_span() {
+        let doc = parse("*a **b** c*");
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        let InlineNode::Emphasis(emphasis) = &node.children[0] else {
+            panic!("Emphasis여야 함");
+        };
+        let actual: Vec<LineColumns> = emphasis.children.iter().map(|child| line_columns(child.span())).collect();
+        assert_eq!(actual, vec![((1, 2), (1, 4)), ((1, 4), (1, 9)), ((1, 9), (1, 11))]);
+    }
+
+    #[test]
+    fn test_emphasis_nesting_limit() {
+        // MAX_NESTING보다 깊은 강조는 리터럴
+        let depth = crate::parser::inline::MAX_NESTING;
+        let doc = parse(&("*a ".repeat(depth + 1) + &"a* ".repeat(depth + 1))).without_spans();
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        let mut children = &node.children;
+        for _ in 0..depth {
+            children = children
+                .iter()
+                .find_map(|child| match child {
+                    InlineNode::Emphasis(emphasis) => Some(&emphasis.children),
+                    _ => None,
+                })
+                .expect("Emphasis가 중첩되어야 함");
+        }
+        assert_eq!(children, &vec![InlineNode::text("a *a a* a")]);
+    }
+
+    #[test]
+    fn test_deep_emphasis_no_stack_overflow() {
+        let doc = parse(&("*a ".repeat(32000) + &"a* ".repeat(32000)));
+        assert_eq!(doc.children.len(), 1);
+    }
+}
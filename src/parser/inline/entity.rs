@@ -0,0 +1,122 @@
+//! https://spec.commonmark.org/0.31.2/#entity-and-numeric-character-references
+//!
+//! `&name;`, `&#123;`, `&#x1F;` 형태의 문자 참조를 해석합니다.
+//! 이름 참조는 HTML5 entity 표(entity_table.rs)에 있는 것만 인정합니다.
+
+use super::entity_table::ENTITIES;
+
+/// 숫자 참조 최대 자릿수 (10진수 7자리, 16진수 6자리)
+const MAX_DECIMAL_DIGITS: usize = 7;
+const MAX_HEX_DIGITS: usize = 6;
+
+/// pos 위치(`&`)의 문자 참조 해석
+/// 반환: (대응 문자열, 참조 다음 위치)
+pub(crate) fn parse(text: &str, pos: usize) -> Option<(String, usize)> {
+    let rest = text[pos..].strip_prefix('&')?;
+    let end = rest.find(';')?;
+    let body = &rest[..end];
+    let next = pos + 1 + end + 1;
+
+    let decoded = match body.strip_prefix('#') {
+        Some(number) => decode_numeric(number)?.to_string(),
+        None => decode_named(body)?.to_string(),
+    };
+    Some((decoded, next))
+}
+
+/// 이름 참조 해석 (표에 없으면 None)
+fn decode_named(name: &str) -> Option<&'static str> {
+    ENTITIES
+        .binary_search_by(|(entity, _)| entity.as_bytes().cmp(name.as_bytes()))
+        .ok()
+        .map(|index| ENTITIES[index].1)
+}
+
+/// 숫자 참조 해석
+/// 0, surrogate, 범위 밖 코드 포인트는 U+FFFD로 대체 (Example 26)
+fn decode_numeric(number: &str) -> Option<char> {
+    let (digits, radix, max_len) = match number.strip_prefix(['x', 'X']) {
+        Some(hex) => (hex, 16, MAX_HEX_DIGITS),
+        None => (number, 10, MAX_DECIMAL_DIGITS),
+    };
+
+    if digits.is_empty() || digits.len() > max_len || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    let code = u32::from_str_radix(digits, radix).ok()?;
+    Some(match code {
+        0 => char::REPLACEMENT_CHARACTER,
+        _ => char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse as parse_document;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("&amp;", Some(("&", 5)))]
+    #[case("&ngE;", Some(("≧̸", 5)))]
+    #[case("&#35;", Some(("#", 5)))]
+    #[case("&#X22;", Some(("\"", 6)))]
+    #[case("&#0;", Some(("\u{FFFD}", 4)))]
+    #[case("&#xD800;", Some(("\u{FFFD}", 8)))]
+    #[case("&copy", None)]
+    #[case("&#;", None)]
+    #[case("&#87654321;", None)]
+    #[case("&MadeUpEntity;", None)]
+    fn test_parse(#[case] input: &str, #[case] expected: Option<(&str, usize)>) {
+        let result = parse(input, 0);
+        assert_eq!(result.as_ref().map(|(s, end)| (s.as_str(), *end)), expected);
+    }
+
+    #[rstest]
+    // Example 25: 이름 참조
+    #[case("&nbsp; &amp; &copy; &AElig; &Dcaron;", "\u{a0} & © Æ Ď")]
+    #[case("&frac34; &HilbertSpace; &DifferentialD;", "¾ ℋ ⅆ")]
+    #[case("&ClockwiseContourIntegral; &ngE;", "∲ ≧̸")]
+    // Example 26: 10진수 참조
+    #[case("&#35; &#1234; &#992; &#0;", "# Ӓ Ϡ \u{FFFD}")]
+    // Example 27: 16진수 참조
+    #[case("&#X22; &#XD06; &#xcab;", "\" ആ ಫ")]
+    // Example 28: 유효하지 않은 참조는 리터럴
+    #[case("&nbsp &x; &#; &#x;", "&nbsp &x; &#; &#x;")]
+    #[case("&#87654321;", "&#87654321;")]
+    #[case("&#abcdef0;", "&#abcdef0;")]
+    #[case("&ThisIsNotDefined; &hi?;", "&ThisIsNotDefined; &hi?;")]
+    // Example 29-30: 세미콜론 없음, 정의되지 않은 이름
+    #[case("&copy", "&copy")]
+    #[case("&MadeUpEntity;", "&MadeUpEntity;")]
+    // Example 37: 참조로 만든 문자는 구분자가 아님
+    #[case("&#42;foo&#42;", "*foo*")]
+    // Example 39-40: 줄바꿈/탭 참조는 구조에 영향 없음
+    #[case("foo&#10;&#10;bar", "foo\n\nbar")]
+    #[case("&#9;foo", "\tfoo")]
+    fn test_entity_in_paragraph(#[case] input: &str, #[case] expected: &str) {
+        let doc = parse_document(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(vec![InlineNode::text(expected)])]);
+    }
+
+    #[rstest]
+    // Example 32: 링크 destination과 title
+    #[case("[foo](/f&ouml;&ouml; \"f&ouml;&ouml;\")", vec![BlockNode::paragraph(vec![InlineNode::link("/föö", Some("föö"), vec![InlineNode::text("foo")])])])]
+    // Example 33: 참조 정의의 destination과 title
+    #[case("[foo]\n\n[foo]: /f&ouml;&ouml; \"f&ouml;&ouml;\"", vec![BlockNode::paragraph(vec![InlineNode::link("/föö", Some("föö"), vec![InlineNode::text("foo")])]), BlockNode::link_reference_definition("foo", "/föö", Some("föö"))])]
+    // Example 34: fenced code block info string
+    #[case("``` f&ouml;&ouml;\nfoo\n```", vec![BlockNode::code_block(Some("föö"), "foo")])]
+    // Example 35-36: code span과 code block 안에서는 해석하지 않음
+    #[case("`f&ouml;&ouml;`", vec![BlockNode::paragraph(vec![InlineNode::code_span("f&ouml;&ouml;")])])]
+    #[case("    f&ouml;f&ouml;", vec![BlockNode::code_block(None, "f&ouml;f&ouml;")])]
+    // Example 38: 참조로 만든 문자는 블록 구조에도 영향 없음
+    #[case("&#42; foo\n\n* foo", vec![BlockNode::paragraph(vec![InlineNode::text("* foo")]), BlockNode::bullet_list(true, vec![crate::node::ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("foo")])])])])]
+    // Example 41: 참조는 링크 구문 문자로 쓰이지 않음
+    #[case("[a](url &quot;tit&quot;)", vec![BlockNode::paragraph(vec![InlineNode::text("[a](url \"tit\")")])])]
+    fn test_entity_in_blocks(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse_document(input).without_spans();
+        assert_eq!(doc.children, expected);
+    }
+}
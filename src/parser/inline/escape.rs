@@ -0,0 +1,94 @@
+//! https://spec.commonmark.org/0.31.2/#backslash-escapes
+//!
+//! ASCII 구두점 앞의 백슬래시는 해당 문자를 리터럴로 만듭니다.
+//! 그 외 문자 앞의 백슬래시는 그대로 백슬래시입니다.
+
+use super::entity;
+
+/// pos 위치(`\`)의 백슬래시 이스케이프 해석
+/// 반환: (이스케이프된 문자, 다음 위치)
+pub(crate) fn parse(text: &str, pos: usize) -> Option<(char, usize)> {
+    let mut chars = text[pos..].chars();
+    match (chars.next(), chars.next()) {
+        (Some('\\'), Some(c)) if c.is_ascii_punctuation() => Some((c, pos + 2)),
+        _ => None,
+    }
+}
+
+/// 백슬래시 이스케이프와 문자 참조를 해석한 문자열
+/// 링크 destination/title, fenced code block info string처럼 인라인 파싱을 거치지 않는 곳에서 사용
+pub(crate) fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut pos = 0;
+
+    while let Some(c) = text[pos..].chars().next() {
+        let resolved = match c {
+            '\\' => parse(text, pos).map(|(escaped, end)| (escaped.to_string(), end)),
+            '&' => entity::parse(text, pos),
+            _ => None,
+        };
+        match resolved {
+            Some((s, end)) => {
+                result.push_str(&s);
+                pos = end;
+            }
+            None => {
+                result.push(c);
+                pos += c.len_utf8();
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse as parse_document;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("foo", "foo")]
+    #[case("\\*a\\*", "*a*")]
+    #[case("\\a\\\\", "\\a\\")]
+    #[case("a&amp;b", "a&b")]
+    #[case("\\&amp;", "&amp;")]
+    fn test_unescape(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(unescape(input), expected);
+    }
+
+    #[rstest]
+    // Example 12: 모든 ASCII 구두점 이스케이프
+    #[case("\\!\\\"\\#\\$\\%\\&\\'\\(\\)\\*\\+\\,\\-\\.\\/\\:\\;\\<\\=\\>\\?\\@\\[\\\\\\]\\^\\_\\`\\{\\|\\}\\~", vec![InlineNode::text("!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~")])]
+    // Example 13: 구두점이 아닌 문자 앞의 백슬래시는 리터럴
+    #[case("\\\t\\A\\a\\ \\3\\φ\\«", vec![InlineNode::text("\\\t\\A\\a\\ \\3\\φ\\«")])]
+    // Example 15: 이스케이프된 백슬래시 뒤의 강조
+    #[case("\\\\*emphasis*", vec![InlineNode::text("\\"), InlineNode::emphasis(vec![InlineNode::text("emphasis")])])]
+    // Example 17: code span 안에서는 이스케이프 안 됨
+    #[case("`` \\[\\` ``", vec![InlineNode::code_span("\\[\\`")])]
+    // Example 22: 링크 destination과 title
+    #[case("[foo](/bar\\* \"ti\\*tle\")", vec![InlineNode::link("/bar*", Some("ti*tle"), vec![InlineNode::text("foo")])])]
+    // 추가 케이스: 이스케이프된 대괄호는 링크가 아님
+    #[case("\\[foo\\](/bar)", vec![InlineNode::text("[foo](/bar)")])]
+    fn test_escape_in_paragraph(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse_document(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // Example 14: 이스케이프된 문자는 구조 문자로 쓰이지 않음
+    #[case("\\*not emphasized*\n\\<br/> not a tag\n\\[not a link](/foo)\n\\`not code`\n1\\. not a list\n\\* not a list\n\\# not a heading\n\\[foo]: /url \"not a reference\"\n\\&ouml; not a character entity", vec![BlockNode::paragraph(vec![InlineNode::text("*not emphasized*"), InlineNode::soft_break(), InlineNode::text("<br/> not a tag"), InlineNode::soft_break(), InlineNode::text("[not a link](/foo)"), InlineNode::soft_break(), InlineNode::text("`not code`"), InlineNode::soft_break(), InlineNode::text("1. not a list"), InlineNode::soft_break(), InlineNode::text("* not a list"), InlineNode::soft_break(), InlineNode::text("# not a heading"), InlineNode::soft_break(), InlineNode::text("[foo]: /url \"not a reference\""), InlineNode::soft_break(), InlineNode::text("&ouml; not a character entity")])])]
+    // Example 18-19: 코드 블록 안에서는 이스케이프 안 됨
+    #[case("    \\[\\]", vec![BlockNode::code_block(None, "\\[\\]")])]
+    #[case("~~~\n\\[\\]\n~~~", vec![BlockNode::code_block(None, "\\[\\]")])]
+    // Example 23: 참조 정의의 destination과 title
+    #[case("[foo]\n\n[foo]: /bar\\* \"ti\\*tle\"", vec![BlockNode::paragraph(vec![InlineNode::link("/bar*", Some("ti*tle"), vec![InlineNode::text("foo")])]), BlockNode::link_reference_definition("foo", "/bar*", Some("ti*tle"))])]
+    // Example 24: fenced code block info string
+    #[case("``` foo\\+bar\nfoo\n```", vec![BlockNode::code_block(Some("foo+bar"), "foo")])]
+    fn test_escape_in_blocks(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse_document(input).without_spans();
+        assert_eq!(doc.children, expected);
+    }
+}
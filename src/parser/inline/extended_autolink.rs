@@ -0,0 +1,290 @@
+//! https://github.github.com/gfm/#autolinks-extension-
+//!
+//! 꺾쇠 없이 쓴 `www.` 주소, `http://`/`https://` URL, email 주소를 autolink로 인식합니다.
+//! 링크 끝의 구두점, 짝이 맞지 않는 `)`, entity처럼 보이는 `&...;`는 링크에서 제외합니다.
+//! 링크 범위는 원문에서 찾고, 링크가 된 원문의 백슬래시 이스케이프와 문자 참조는 해석합니다.
+
+use super::escape::unescape;
+use crate::node::InlineNode;
+
+/// URL 형태 extended autolink 접두사 (`www.`는 scheme 없이 `http://`로 연결)
+const URL_PREFIXES: [&str; 3] = ["www.", "http://", "https://"];
+
+/// 링크 끝에서 제외하는 구두점
+const TRAILING_PUNCTUATION: &[char] = &['?', '!', '.', ',', ':', '*', '_', '~'];
+
+/// pos 위치에서 extended autolink 파싱
+/// 반환: (Autolink 노드, autolink 다음 위치)
+pub(super) fn parse(text: &str, pos: usize) -> Option<(InlineNode, usize)> {
+    let before = text[..pos].chars().next_back();
+    parse_url(text, pos, before).or_else(|| parse_email(text, pos, before))
+}
+
+/// `www.` 또는 `http(s)://`로 시작하는 URL
+/// 줄 시작, 공백, `*` `_` `~` `(` 뒤에서만 시작할 수 있음
+fn parse_url(text: &str, pos: usize, before: Option<char>) -> Option<(InlineNode, usize)> {
+    if before.is_some_and(|c| !c.is_whitespace() && !matches!(c, '*' | '_' | '~' | '(')) {
+        return None;
+    }
+
+    let rest = &text[pos..];
+    let prefix = URL_PREFIXES.iter().find(|prefix| rest.starts_with(*prefix))?;
+    // `www.`는 접두사의 점을 포함해 도메인을 검사
+    let (domain_start, allow_short) = match *prefix {
+        "www." => (0, false),
+        _ => (prefix.len(), true),
+    };
+    let domain_len = domain_len(&rest[domain_start..], allow_short)?;
+
+    let path_end = rest[domain_start + domain_len..]
+        .find(|c: char| c.is_whitespace() || c == '<')
+        .map_or(rest.len(), |offset| domain_start + domain_len + offset);
+    let link = trim_trailing(&rest[..path_end]);
+    if link.len() <= prefix.len() {
+        return None;
+    }
+
+    Some((InlineNode::extended_autolink(&unescape(link), false), pos + link.len()))
+}
+
+/// 유효한 도메인 길이
+/// - 영숫자, `_`, `-`로 된 segment를 `.`으로 구분, 마지막 두 segment에는 `_` 불가
+/// - `allow_short`가 아니면 `.`이 하나 이상 있어야 함
+fn domain_len(s: &str, allow_short: bool) -> Option<usize> {
+    let len = s
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '.' | '_' | '-')))
+        .unwrap_or(s.len());
+    let domain = &s[..len];
+
+    let has_period = domain.contains('.');
+    let underscore_in_last_two = domain
+        .trim_end_matches('.')
+        .rsplit('.')
+        .take(2)
+        .any(|segment| segment.contains('_'));
+    let valid = !domain.is_empty() && !underscore_in_last_two && (allow_short || has_period);
+    valid.then_some(len)
+}
+
+/// 링크 끝의 구두점, 짝이 맞지 않는 `)`, entity 형태 `&...;` 제거 (더 이상 제거할 것이 없을 때까지)
+fn trim_trailing(link: &str) -> &str {
+    let mut link = link;
+    loop {
+        if let Some(trimmed) = link.strip_suffix(TRAILING_PUNCTUATION) {
+            link = trimmed;
+        } else if link.ends_with(')') && link.matches(')').count() > link.matches('(').count() {
+            link = &link[..link.len() - 1];
+        } else if let Some(entity_start) = trailing_entity_start(link) {
+            link = &link[..entity_start];
+        } else {
+            return link;
+        }
+    }
+}
+
+/// 링크가 `&` + 영숫자 + `;`로 끝나면 `&`의 위치
+fn trailing_entity_start(link: &str) -> Option<usize> {
+    let body = link.strip_suffix(';')?;
+    let name = body.trim_end_matches(|c: char| c.is_ascii_alphanumeric());
+    let ampersand = name.strip_suffix('&')?;
+    (name.len() < body.len()).then_some(ampersand.len())
+}
+
+/// email 주소: 영숫자 `.` `+` `-` `_`로 된 local part + `@` + `.`이 하나 이상인 도메인
+/// local part 중간에서는 시작할 수 없음
+fn parse_email(text: &str, pos: usize, before: Option<char>) -> Option<(InlineNode, usize)> {
+    if before.is_some_and(is_email_local_char) {
+        return None;
+    }
+
+    let rest = &text[pos..];
+    let local_len = rest.find(|c: char| !is_email_local_char(c)).unwrap_or(rest.len());
+    if local_len == 0 || !rest[local_len..].starts_with('@') {
+        return None;
+    }
+
+    // 도메인의 `.`은 뒤에 영숫자가 올 때만 포함 (끝의 `.`은 제외)
+    let domain = &rest[local_len + 1..];
+    let bytes = domain.as_bytes();
+    let mut len = 0;
+    let mut periods = 0;
+    while len < bytes.len() {
+        match bytes[len] {
+            b'.' if bytes.get(len + 1).is_some_and(u8::is_ascii_alphanumeric) => periods += 1,
+            c if c.is_ascii_alphanumeric() || matches!(c, b'-' | b'_') => {}
+            _ => break,
+        }
+        len += 1;
+    }
+    if periods == 0 || domain[..len].ends_with(['-', '_']) {
+        return None;
+    }
+
+    let end = local_len + 1 + len;
+    Some((InlineNode::extended_autolink(&rest[..end], true), pos + end))
+}
+
+/// email local part에 쓸 수 있는 문자
+fn is_email_local_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-' | '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
+    use rstest::rstest;
+
+    #[rstest]
+    // GFM Example 621-622: www.
+    #[case("www.commonmark.org", vec![InlineNode::extended_autolink("www.commonmark.org", false)])]
+    #[case(
+        "Visit www.commonmark.org/help for more information.",
+        vec![
+            InlineNode::text("Visit "),
+            InlineNode::extended_autolink("www.commonmark.org/help", false),
+            InlineNode::text(" for more information."),
+        ]
+    )]
+    // GFM Example 623: 끝의 구두점 제외
+    #[case(
+        "Visit www.commonmark.org.",
+        vec![
+            InlineNode::text("Visit "),
+            InlineNode::extended_autolink("www.commonmark.org", false),
+            InlineNode::text("."),
+        ]
+    )]
+    #[case(
+        "Visit www.commonmark.org/a.b.",
+        vec![
+            InlineNode::text("Visit "),
+            InlineNode::extended_autolink("www.commonmark.org/a.b", false),
+            InlineNode::text("."),
+        ]
+    )]
+    // GFM Example 624-627: 짝이 맞지 않는 `)` 제외
+    #[case(
+        "www.google.com/search?q=Markup+(business)",
+        vec![InlineNode::extended_autolink("www.google.com/search?q=Markup+(business)", false)]
+    )]
+    #[case(
+        "www.google.com/search?q=Markup+(business)))",
+        vec![InlineNode::extended_autolink("www.google.com/search?q=Markup+(business)", false), InlineNode::text("))")]
+    )]
+    #[case(
+        "(www.google.com/search?q=Markup+(business))",
+        vec![
+            InlineNode::text("("),
+            InlineNode::extended_autolink("www.google.com/search?q=Markup+(business)", false),
+            InlineNode::text(")"),
+        ]
+    )]
+    #[case(
+        "(www.google.com/search?q=Markup+(business)",
+        vec![InlineNode::text("("), InlineNode::extended_autolink("www.google.com/search?q=Markup+(business)", false)]
+    )]
+    #[case(
+        "www.google.com/search?q=(business))+ok",
+        vec![InlineNode::extended_autolink("www.google.com/search?q=(business))+ok", false)]
+    )]
+    // GFM Example 628: entity 형태의 끝 제외
+    #[case(
+        "www.google.com/search?q=commonmark&hl=en",
+        vec![InlineNode::extended_autolink("www.google.com/search?q=commonmark&hl=en", false)]
+    )]
+    #[case(
+        "www.google.com/search?q=commonmark&hl;",
+        vec![InlineNode::extended_autolink("www.google.com/search?q=commonmark", false), InlineNode::text("&hl;")]
+    )]
+    // 백슬래시 이스케이프와 문자 참조는 해석
+    #[case("https://a.b/?x=1&amp;y=2", vec![InlineNode::extended_autolink("https://a.b/?x=1&y=2", false)])]
+    #[case("https://a.b/x\\_y", vec![InlineNode::extended_autolink("https://a.b/x_y", false)])]
+    #[case(
+        "www.a.b/&#91;x&#93; ok",
+        vec![InlineNode::extended_autolink("www.a.b/[x]", false), InlineNode::text(" ok")]
+    )]
+    // GFM Example 629: `<`에서 끝남
+    #[case(
+        "www.commonmark.org/he<lp",
+        vec![InlineNode::extended_autolink("www.commonmark.org/he", false), InlineNode::text("<lp")]
+    )]
+    // GFM Example 630: http(s)://
+    #[case("http://commonmark.org", vec![InlineNode::extended_autolink("http://commonmark.org", false)])]
+    #[case(
+        "(Visit https://encrypted.google.com/search?q=Markup+(business))",
+        vec![
+            InlineNode::text("(Visit "),
+            InlineNode::extended_autolink("https://encrypted.google.com/search?q=Markup+(business)", false),
+            InlineNode::text(")"),
+        ]
+    )]
+    // scheme이 있으면 `.` 없는 도메인 허용
+    #[case("http://localhost:5001/foo", vec![InlineNode::extended_autolink("http://localhost:5001/foo", false)])]
+    // GFM Example 631-632: email
+    #[case("foo@bar.baz", vec![InlineNode::extended_autolink("foo@bar.baz", true)])]
+    #[case(
+        "hello@mail+xyz.example isn't valid, but hello+xyz@mail.example is.",
+        vec![
+            InlineNode::text("hello@mail+xyz.example isn't valid, but "),
+            InlineNode::extended_autolink("hello+xyz@mail.example", true),
+            InlineNode::text(" is."),
+        ]
+    )]
+    // GFM Example 633: email 끝의 `.`, `-`, `_`
+    #[case("a.b-c_d@a.b", vec![InlineNode::extended_autolink("a.b-c_d@a.b", true)])]
+    #[case("a.b-c_d@a.b.", vec![InlineNode::extended_autolink("a.b-c_d@a.b", true), InlineNode::text(".")])]
+    #[case("a.b-c_d@a.b-", vec![InlineNode::text("a.b-c_d@a.b-")])]
+    #[case("a.b-c_d@a.b_", vec![InlineNode::text("a.b-c_d@a.b_")])]
+    // 마지막 두 segment의 `_`는 불가
+    #[case("www.foo_bar.com", vec![InlineNode::text("www.foo_bar.com")])]
+    #[case("www.foo_bar.baz.com", vec![InlineNode::extended_autolink("www.foo_bar.baz.com", false)])]
+    // 단어 중간에서는 시작하지 않음
+    #[case("xwww.commonmark.org", vec![InlineNode::text("xwww.commonmark.org")])]
+    #[case("xhttp://commonmark.org", vec![InlineNode::text("xhttp://commonmark.org")])]
+    // 강조/취소선 구분자 뒤
+    #[case(
+        "*www.commonmark.org*",
+        vec![InlineNode::emphasis(vec![InlineNode::extended_autolink("www.commonmark.org", false)])]
+    )]
+    #[case(
+        "~~https://a.com~~",
+        vec![InlineNode::strikethrough(vec![InlineNode::extended_autolink("https://a.com", false)])]
+    )]
+    // 도메인이 없으면 링크 아님
+    #[case("www. foo", vec![InlineNode::text("www. foo")])]
+    #[case("http://", vec![InlineNode::text("http://")])]
+    fn test_extended_autolink(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // 링크 텍스트 안에서는 인식하지 않음
+    #[case(
+        "[www.example.com](/url)",
+        vec![InlineNode::link("/url", None, vec![InlineNode::text("www.example.com")])]
+    )]
+    #[case(
+        "[see foo@bar.baz](/url)",
+        vec![InlineNode::link("/url", None, vec![InlineNode::text("see foo@bar.baz")])]
+    )]
+    // code span과 꺾쇠 autolink가 우선
+    #[case("`www.example.com`", vec![InlineNode::code_span("www.example.com")])]
+    #[case("<https://example.com>", vec![InlineNode::autolink("https://example.com", false)])]
+    fn test_extended_autolink_precedence(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[test]
+    fn test_extended_autolink_span() {
+        let doc = parse("see www.a.com, ok");
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        let actual: Vec<LineColumns> = node.children.iter().map(|child| line_columns(child.span())).collect();
+        assert_eq!(actual, vec![((1, 1), (1, 5)), ((1, 5), (1, 14)), ((1, 14), (1, 18))]);
+    }
+}
@@ -0,0 +1,95 @@
+//! https://spec.commonmark.org/0.31.2/#hard-line-breaks
+//! https://spec.commonmark.org/0.31.2/#soft-line-breaks
+//!
+//! Leaf 블록 원문 안의 줄바꿈을 SoftBreak/HardBreak 노드로 변환합니다.
+//! 줄 끝 공백과 다음 줄 앞 공백은 렌더링에 영향을 주지 않으므로 제거합니다.
+
+use crate::node::InlineNode;
+
+/// hard line break가 되는 줄 끝 공백 최소 개수
+const HARD_BREAK_MIN_SPACES: usize = 2;
+
+/// pos 위치(`\n`)의 줄바꿈 해석
+/// - `text_start`: 아직 토큰이 되지 않은 텍스트의 시작 위치
+///
+/// 반환: (줄 끝 공백을 제거한 대기 텍스트, 줄바꿈 노드, 다음 줄 내용 시작 위치)
+pub(super) fn parse(text: &str, text_start: usize, pos: usize) -> (&str, InlineNode, usize) {
+    let line = &text[text_start..pos];
+    let content = line.trim_end_matches(' ');
+
+    let node = if line.len() - content.len() >= HARD_BREAK_MIN_SPACES {
+        InlineNode::hard_break()
+    } else {
+        InlineNode::soft_break()
+    };
+    (content, node, skip_line_indent(text, pos + 1))
+}
+
+/// pos 위치(`\`) 바로 뒤가 줄바꿈인 hard line break 해석 (Example 634)
+/// 반환: (HardBreak 노드, 다음 줄 내용 시작 위치)
+pub(super) fn parse_backslash(text: &str, pos: usize) -> (InlineNode, usize) {
+    (InlineNode::hard_break(), skip_line_indent(text, pos + 2))
+}
+
+/// 줄 앞의 공백/탭 건너뛰기 (Example 636, 649)
+fn skip_line_indent(text: &str, pos: usize) -> usize {
+    pos + text[pos..].len() - text[pos..].trim_start_matches([' ', '\t']).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    // Example 633: 줄 끝 공백 2개
+    #[case("foo  \nbaz", vec![InlineNode::text("foo"), InlineNode::hard_break(), InlineNode::text("baz")])]
+    // Example 634: 줄 끝 백슬래시
+    #[case("foo\\\nbaz", vec![InlineNode::text("foo"), InlineNode::hard_break(), InlineNode::text("baz")])]
+    // Example 635: 줄 끝 공백 여러 개
+    #[case("foo       \nbaz", vec![InlineNode::text("foo"), InlineNode::hard_break(), InlineNode::text("baz")])]
+    // Example 636-637: 다음 줄 앞 공백 제거
+    #[case("foo  \n     bar", vec![InlineNode::text("foo"), InlineNode::hard_break(), InlineNode::text("bar")])]
+    #[case("foo\\\n     bar", vec![InlineNode::text("foo"), InlineNode::hard_break(), InlineNode::text("bar")])]
+    // Example 638-639: 강조 안의 hard line break
+    #[case(
+        "*foo  \nbar*",
+        vec![InlineNode::emphasis(vec![InlineNode::text("foo"), InlineNode::hard_break(), InlineNode::text("bar")])]
+    )]
+    #[case(
+        "*foo\\\nbar*",
+        vec![InlineNode::emphasis(vec![InlineNode::text("foo"), InlineNode::hard_break(), InlineNode::text("bar")])]
+    )]
+    // Example 640-641: code span 안에서는 줄바꿈 아님
+    #[case("`code  \nspan`", vec![InlineNode::code_span("code   span")])]
+    #[case("`code\\\nspan`", vec![InlineNode::code_span("code\\ span")])]
+    // Example 642-643: raw HTML 안에서는 줄바꿈 아님
+    #[case("<a href=\"foo  \nbar\">", vec![InlineNode::raw_html("<a href=\"foo  \nbar\">")])]
+    #[case("<a href=\"foo\\\nbar\">", vec![InlineNode::raw_html("<a href=\"foo\\\nbar\">")])]
+    // Example 644-645: 블록 끝에서는 hard line break 아님
+    #[case("foo\\", vec![InlineNode::text("foo\\")])]
+    #[case("foo  ", vec![InlineNode::text("foo")])]
+    // Example 648: soft line break
+    #[case("foo\nbaz", vec![InlineNode::text("foo"), InlineNode::soft_break(), InlineNode::text("baz")])]
+    // Example 649: 줄 끝 공백 1개와 다음 줄 앞 공백 제거
+    #[case("foo \n baz", vec![InlineNode::text("foo"), InlineNode::soft_break(), InlineNode::text("baz")])]
+    // 추가 케이스: 탭은 hard line break 조건이 아님
+    #[case("foo\t\nbaz", vec![InlineNode::text("foo\t"), InlineNode::soft_break(), InlineNode::text("baz")])]
+    fn test_line_break(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // Example 646-647: heading 끝에서는 hard line break 아님
+    #[case("### foo\\", vec![InlineNode::text("foo\\")])]
+    #[case("### foo  ", vec![InlineNode::text("foo")])]
+    // 추가 케이스: setext heading 안의 줄바꿈
+    #[case("foo  \nbar\n===", vec![InlineNode::text("foo"), InlineNode::hard_break(), InlineNode::text("bar")])]
+    fn test_line_break_in_heading(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        let level = if input.starts_with('#') { 3 } else { 1 };
+        assert_eq!(doc.children, vec![BlockNode::heading(level, expected)]);
+    }
+}
@@ -0,0 +1,534 @@
+//! https://spec.commonmark.org/0.31.2/#links
+//!
+//! 링크 구성 요소(label, destination, title) 파싱과 참조 정의 해석을 담당합니다.
+//! label/destination/title 파서는 블록 단계의 Link Reference Definition 파서와 공유합니다.
+
+use std::collections::HashMap;
+
+use super::escape::{self, unescape};
+use crate::node::BlockNode;
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// 링크 대상 (인라인 링크 또는 참조 정의에서 얻은 destination/title)
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LinkTarget {
+    pub destination: String,
+    pub title: Option<String>,
+}
+
+/// 문서 전체의 링크 참조 정의 (정규화된 label → 대상)
+pub(crate) type ReferenceMap = HashMap<String, LinkTarget>;
+
+/// label 최대 길이 (대괄호 제외)
+const MAX_LABEL_LEN: usize = 999;
+
+/// destination 안 괄호의 최대 중첩 깊이 (더 깊으면 destination 아님)
+/// 명세가 허용하는 제한으로, 닫히지 않은 `(`가 이어질 때 매번 텍스트 끝까지 훑지 않도록 함
+const MAX_DESTINATION_PARENS: usize = 32;
+
+// =============================================================================
+// 참조 정의 수집
+// =============================================================================
+
+/// 블록 트리에서 Link Reference Definition을 모두 수집
+/// 같은 label이 여러 번 정의되면 먼저 나온 정의가 우선 (Example 204)
+pub(crate) fn collect_references(blocks: &[BlockNode]) -> ReferenceMap {
+    let mut refs = ReferenceMap::new();
+    collect_into(blocks, &mut refs);
+    refs
+}
+
+fn collect_into(blocks: &[BlockNode], refs: &mut ReferenceMap) {
+    for block in blocks {
+        match block {
+            BlockNode::LinkReferenceDefinition(def) => {
+                refs.entry(normalize_label(&def.label)).or_insert_with(|| LinkTarget {
+                    destination: def.destination.clone(),
+                    title: def.title.clone(),
+                });
+            }
+            BlockNode::Blockquote(node) => collect_into(&node.children, refs),
+            BlockNode::Callout(node) => collect_into(&node.children, refs),
+            BlockNode::List(node) => {
+                for item in &node.children {
+                    collect_into(&item.children, refs);
+                }
+            }
+            BlockNode::ListItem(node) => collect_into(&node.children, refs),
+            BlockNode::FootnoteDefinition(node) => collect_into(&node.children, refs),
+            _ => {}
+        }
+    }
+}
+
+/// label 정규화: 앞뒤 공백 제거, 연속 공백을 하나로, 대소문자 무시 (Example 205, 206)
+pub(crate) fn normalize_label(label: &str) -> String {
+    let collapsed = label.split_whitespace().collect::<Vec<_>>().join(" ");
+    // lowercase → uppercase → lowercase: 'ẞ'와 "SS"처럼 full case folding이 필요한 경우 처리
+    collapsed.to_lowercase().to_uppercase().to_lowercase()
+}
+
+// =============================================================================
+// 링크 해석 (닫는 대괄호 이후)
+// =============================================================================
+
+/// `]` 다음 위치(pos)에서 링크 대상 해석
+/// 인라인 링크 `(...)`를 먼저 시도하고, 실패하면 참조 링크(full, collapsed, shortcut)를 시도
+///
+/// - `link_text`: 대괄호 사이의 원문 (collapsed/shortcut 참조의 label로 사용)
+/// - 반환: (링크 대상, 링크 다음 위치)
+pub(super) fn parse_link_tail(
+    text: &str,
+    pos: usize,
+    link_text: &str,
+    refs: &ReferenceMap,
+) -> Option<(LinkTarget, usize)> {
+    if let Some(result) = parse_inline_link(text, pos) {
+        return Some(result);
+    }
+
+    let lookup = |label: &str| refs.get(&normalize_label(label)).cloned();
+
+    match parse_link_label(text, pos) {
+        // Full reference: [text][label] - 정의가 없으면 링크 아님 (shortcut으로 대체하지 않음)
+        Some((label, end)) if !label.trim().is_empty() => lookup(label).map(|target| (target, end)),
+        // Collapsed reference: [text][]
+        Some(("", end)) => lookup(link_text).map(|target| (target, end)),
+        // Shortcut reference: [text]
+        _ => lookup(link_text).map(|target| (target, pos)),
+    }
+}
+
+/// 인라인 링크 `(destination "title")` 파싱
+fn parse_inline_link(text: &str, pos: usize) -> Option<(LinkTarget, usize)> {
+    if !text[pos..].starts_with('(') {
+        return None;
+    }
+    let mut cursor = skip_whitespace(text, pos + 1);
+
+    // destination은 생략 가능 (Example 485)
+    let destination = match parse_link_destination(text, cursor) {
+        Some((destination, end)) => {
+            cursor = end;
+            destination
+        }
+        None => String::new(),
+    };
+
+    // title은 destination과 공백으로 구분되어야 함
+    let after_space = skip_whitespace(text, cursor);
+    let title = if after_space > cursor || destination.is_empty() {
+        parse_link_title(text, after_space).map(|(title, end)| {
+            cursor = end;
+            title
+        })
+    } else {
+        None
+    };
+
+    cursor = skip_whitespace(text, cursor);
+    if !text[cursor..].starts_with(')') {
+        return None;
+    }
+
+    Some((LinkTarget { destination, title }, cursor + 1))
+}
+
+// =============================================================================
+// 구성 요소 파서 (블록 단계와 공유)
+// =============================================================================
+
+/// 공백/탭/줄바꿈 건너뛰기
+pub(crate) fn skip_whitespace(text: &str, pos: usize) -> usize {
+    pos + text[pos..].len() - text[pos..].trim_start_matches([' ', '\t', '\n']).len()
+}
+
+/// 백슬래시 이스케이프 길이 (`\` + ASCII 구두점이면 2, 아니면 None)
+fn escape_len(rest: &str) -> Option<usize> {
+    escape::parse(rest, 0).map(|(_, end)| end)
+}
+
+/// Link label `[...]` 파싱
+/// - 이스케이프되지 않은 대괄호 포함 불가
+/// - 최대 999자
+///
+/// 반환: (대괄호 사이 원문, label 다음 위치)
+pub(crate) fn parse_link_label(text: &str, pos: usize) -> Option<(&str, usize)> {
+    if !text[pos..].starts_with('[') {
+        return None;
+    }
+    let start = pos + 1;
+    let mut cursor = start;
+
+    loop {
+        let rest = &text[cursor..];
+        let c = rest.chars().next()?;
+        match c {
+            ']' => break,
+            '[' => return None,
+            _ => cursor += escape_len(rest).unwrap_or(c.len_utf8()),
+        }
+        if text[start..cursor].chars().count() > MAX_LABEL_LEN {
+            return None;
+        }
+    }
+
+    Some((&text[start..cursor], cursor + 1))
+}
+
+/// Link destination 파싱 (백슬래시 이스케이프와 문자 참조 해석)
+/// - `<...>` 형태: 줄바꿈, 이스케이프되지 않은 `<` `>` 포함 불가
+/// - 일반 형태: 공백/제어 문자 불가, 괄호는 균형이 맞아야 함 (`MAX_DESTINATION_PARENS`단계까지)
+///
+/// 반환: (destination, destination 다음 위치)
+pub(crate) fn parse_link_destination(text: &str, pos: usize) -> Option<(String, usize)> {
+    let rest = &text[pos..];
+
+    if let Some(inner) = rest.strip_prefix('<') {
+        let mut offset = 0;
+        loop {
+            let c = inner[offset..].chars().next()?;
+            match c {
+                '>' => return Some((unescape(&inner[..offset]), pos + 1 + offset + 1)),
+                '<' | '\n' => return None,
+                _ => offset += escape_len(&inner[offset..]).unwrap_or(c.len_utf8()),
+            }
+        }
+    }
+
+    let mut depth = 0usize;
+    let mut offset = 0;
+    while let Some(c) = rest[offset..].chars().next() {
+        match c {
+            c if c.is_ascii_control() || c == ' ' => break,
+            '(' if depth == MAX_DESTINATION_PARENS => return None,
+            '(' => depth += 1,
+            ')' if depth == 0 => break,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        offset += escape_len(&rest[offset..]).unwrap_or(c.len_utf8());
+    }
+
+    if offset == 0 || depth > 0 {
+        return None;
+    }
+    Some((unescape(&rest[..offset]), pos + offset))
+}
+
+/// Link title 파싱 (`"..."`, `'...'`, `(...)`)
+/// 백슬래시 이스케이프와 문자 참조 해석
+/// 반환: (따옴표/괄호 제외 title, title 다음 위치)
+pub(crate) fn parse_link_title(text: &str, pos: usize) -> Option<(String, usize)> {
+    let rest = &text[pos..];
+    let close = match rest.chars().next()? {
+        '"' => '"',
+        '\'' => '\'',
+        '(' => ')',
+        _ => return None,
+    };
+
+    let mut offset = 1;
+    loop {
+        let c = rest[offset..].chars().next()?;
+        if c == close {
+            return Some((unescape(&rest[1..offset]), pos + offset + 1));
+        }
+        // 괄호 title에는 이스케이프되지 않은 여는 괄호 불가
+        if close == ')' && c == '(' {
+            return None;
+        }
+        offset += escape_len(&rest[offset..]).unwrap_or(c.len_utf8());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("foo", "foo")]
+    #[case("  Foo  BAR ", "foo bar")]
+    #[case("Foo\n  bar", "foo bar")]
+    #[case("ΑΓΩ", "αγω")]
+    #[case("ẞ", "ss")]
+    #[case("SS", "ss")]
+    fn test_normalize_label(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(normalize_label(input), expected);
+    }
+
+    /// 인라인 링크 (6.3 Links)
+    #[rstest]
+    // Example 482: 기본 인라인 링크
+    #[case("[link](/uri \"title\")", vec![InlineNode::link("/uri", Some("title"), vec![InlineNode::text("link")])])]
+    // Example 483-485: title 또는 destination 생략
+    #[case("[link](/uri)", vec![InlineNode::link("/uri", None, vec![InlineNode::text("link")])])]
+    #[case("[](./target.md)", vec![InlineNode::link("./target.md", None, vec![])])]
+    #[case("[link]()", vec![InlineNode::link("", None, vec![InlineNode::text("link")])])]
+    // Example 486: <>는 빈 destination
+    #[case("[link](<>)", vec![InlineNode::link("", None, vec![InlineNode::text("link")])])]
+    // Example 488: destination에 공백 불가
+    #[case("[link](/my uri)", vec![InlineNode::text("[link](/my uri)")])]
+    // Example 489: <> 안에서는 공백 허용
+    #[case("[link](</my uri>)", vec![InlineNode::link("/my uri", None, vec![InlineNode::text("link")])])]
+    // Example 496: 균형 잡힌 괄호
+    #[case("[link](foo(and(bar)))", vec![InlineNode::link("foo(and(bar))", None, vec![InlineNode::text("link")])])]
+    // Example 497: 균형이 맞지 않는 괄호
+    #[case("[link](foo(and(bar))", vec![InlineNode::text("[link](foo(and(bar))")])]
+    // destination 안 괄호는 MAX_DESTINATION_PARENS단계까지
+    #[case(
+        &format!("[link](a{}b{})", "(".repeat(32), ")".repeat(32)),
+        vec![InlineNode::link(&format!("a{}b{}", "(".repeat(32), ")".repeat(32)), None, vec![InlineNode::text("link")])]
+    )]
+    #[case(
+        &format!("[link](a{}b{})", "(".repeat(33), ")".repeat(33)),
+        vec![InlineNode::text(&format!("[link](a{}b{})", "(".repeat(33), ")".repeat(33)))]
+    )]
+    // Example 505: 세 가지 title 형식
+    #[case("[link](/url 'title')", vec![InlineNode::link("/url", Some("title"), vec![InlineNode::text("link")])])]
+    #[case("[link](/url (title))", vec![InlineNode::link("/url", Some("title"), vec![InlineNode::text("link")])])]
+    // Example 510: title 앞뒤 공백
+    #[case(
+        "[link](   /uri\n  \"title\"  )",
+        vec![InlineNode::link("/uri", Some("title"), vec![InlineNode::text("link")])]
+    )]
+    // Example 511: ]와 ( 사이 공백은 링크 아님
+    #[case("[link] (/uri)", vec![InlineNode::text("[link] (/uri)")])]
+    // Example 512-513: 링크 텍스트의 균형 잡힌 대괄호
+    #[case(
+        "[link [foo [bar]]](/uri)",
+        vec![InlineNode::link("/uri", None, vec![InlineNode::text("link [foo [bar]]")])]
+    )]
+    #[case("[link] bar](/uri)", vec![InlineNode::text("[link] bar](/uri)")])]
+    // Example 516: 링크 텍스트 안의 인라인 요소
+    #[case(
+        "[link *foo **bar** `#`*](/uri)",
+        vec![InlineNode::link(
+            "/uri",
+            None,
+            vec![
+                InlineNode::text("link "),
+                InlineNode::emphasis(vec![
+                    InlineNode::text("foo "),
+                    InlineNode::strong(vec![InlineNode::text("bar")]),
+                    InlineNode::text(" "),
+                    InlineNode::code_span("#"),
+                ]),
+            ],
+        )]
+    )]
+    // Example 517: 이미지가 링크 텍스트로
+    #[case(
+        "[![moon](moon.jpg)](/uri)",
+        vec![InlineNode::link("/uri", None, vec![InlineNode::image("moon.jpg", None, vec![InlineNode::text("moon")])])]
+    )]
+    // Example 518-519: 링크 안에 링크 불가 (바깥쪽이 텍스트)
+    #[case(
+        "[foo [bar](/uri)](/uri)",
+        vec![
+            InlineNode::text("[foo "),
+            InlineNode::link("/uri", None, vec![InlineNode::text("bar")]),
+            InlineNode::text("](/uri)"),
+        ]
+    )]
+    #[case(
+        "[foo *[bar [baz](/uri)](/uri)*](/uri)",
+        vec![
+            InlineNode::text("[foo "),
+            InlineNode::emphasis(vec![
+                InlineNode::text("[bar "),
+                InlineNode::link("/uri", None, vec![InlineNode::text("baz")]),
+                InlineNode::text("](/uri)"),
+            ]),
+            InlineNode::text("](/uri)"),
+        ]
+    )]
+    // Example 521-522: 링크 텍스트 그룹핑이 강조보다 우선
+    #[case(
+        "*[foo*](/uri)",
+        vec![InlineNode::text("*"), InlineNode::link("/uri", None, vec![InlineNode::text("foo*")])]
+    )]
+    #[case("[foo *bar](baz*)", vec![InlineNode::link("baz*", None, vec![InlineNode::text("foo *bar")])])]
+    // Example 523: 링크가 아닌 대괄호는 강조에 영향 없음
+    #[case(
+        "*foo [bar* baz]",
+        vec![InlineNode::emphasis(vec![InlineNode::text("foo [bar")]), InlineNode::text(" baz]")]
+    )]
+    // Example 525: code span이 링크보다 우선
+    #[case("[foo`](/uri)`", vec![InlineNode::text("[foo"), InlineNode::code_span("](/uri)")])]
+    fn test_inline_link(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    /// 참조 링크 (full, collapsed, shortcut)
+    #[rstest]
+    // Example 527: full reference
+    #[case(
+        "[foo][bar]\n\n[bar]: /url \"title\"",
+        vec![InlineNode::link("/url", Some("title"), vec![InlineNode::text("foo")])]
+    )]
+    // Example 528: 링크 텍스트의 균형 잡힌 대괄호
+    #[case(
+        "[link [foo [bar]]][ref]\n\n[ref]: /uri",
+        vec![InlineNode::link("/uri", None, vec![InlineNode::text("link [foo [bar]]")])]
+    )]
+    // Example 539: 대소문자 무시
+    #[case(
+        "[foo][BaR]\n\n[bar]: /url \"title\"",
+        vec![InlineNode::link("/url", Some("title"), vec![InlineNode::text("foo")])]
+    )]
+    // Example 540: Unicode 대소문자 무시
+    #[case("[ẞ]\n\n[SS]: /url", vec![InlineNode::link("/url", None, vec![InlineNode::text("ẞ")])])]
+    // Example 541: 내부 공백 정규화
+    #[case("[Foo\n  bar]: /url\n\n[Baz][Foo bar]", vec![InlineNode::link("/url", None, vec![InlineNode::text("Baz")])])]
+    // Example 569: 정의되지 않은 full reference는 링크 아님
+    #[case(
+        "[foo][bar][baz]\n\n[baz]: /url",
+        vec![InlineNode::text("[foo]"), InlineNode::link("/url", None, vec![InlineNode::text("bar")])]
+    )]
+    // Example 553: collapsed reference
+    #[case(
+        "[foo][]\n\n[foo]: /url \"title\"",
+        vec![InlineNode::link("/url", Some("title"), vec![InlineNode::text("foo")])]
+    )]
+    // Example 554: collapsed reference 링크 텍스트 안의 강조
+    #[case(
+        "[*foo* bar][]\n\n[*foo* bar]: /url \"title\"",
+        vec![InlineNode::link(
+            "/url",
+            Some("title"),
+            vec![InlineNode::emphasis(vec![InlineNode::text("foo")]), InlineNode::text(" bar")],
+        )]
+    )]
+    // Example 557: shortcut reference
+    #[case(
+        "[foo]\n\n[foo]: /url \"title\"",
+        vec![InlineNode::link("/url", Some("title"), vec![InlineNode::text("foo")])]
+    )]
+    // Example 562: shortcut 뒤의 텍스트
+    #[case(
+        "[foo] bar\n\n[foo]: /url",
+        vec![InlineNode::link("/url", None, vec![InlineNode::text("foo")]), InlineNode::text(" bar")]
+    )]
+    // Example 567: 인라인 링크가 우선
+    #[case("[foo]()\n\n[foo]: /url1", vec![InlineNode::link("", None, vec![InlineNode::text("foo")])])]
+    // Example 568: 인라인 링크 파싱 실패 시 shortcut
+    #[case(
+        "[foo](not a link)\n\n[foo]: /url1",
+        vec![InlineNode::link("/url1", None, vec![InlineNode::text("foo")]), InlineNode::text("(not a link)")]
+    )]
+    fn test_reference_link(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        let paragraphs: Vec<_> = doc
+            .children
+            .into_iter()
+            .filter(|b| matches!(b, BlockNode::Paragraph(_)))
+            .collect();
+        assert_eq!(paragraphs, vec![BlockNode::paragraph(expected)]);
+    }
+
+    /// 이미지 (6.4 Images)
+    #[rstest]
+    // Example 572: 기본 이미지
+    #[case("![foo](/url \"title\")", vec![InlineNode::image("/url", Some("title"), vec![InlineNode::text("foo")])])]
+    // Example 574: 이미지 대체 텍스트 안의 이미지
+    #[case(
+        "![foo ![bar](/url)](/url2)",
+        vec![InlineNode::image(
+            "/url2",
+            None,
+            vec![InlineNode::text("foo "), InlineNode::image("/url", None, vec![InlineNode::text("bar")])],
+        )]
+    )]
+    // Example 575: 이미지 대체 텍스트 안의 링크
+    #[case(
+        "![foo [bar](/url)](/url2)",
+        vec![InlineNode::image(
+            "/url2",
+            None,
+            vec![InlineNode::text("foo "), InlineNode::link("/url", None, vec![InlineNode::text("bar")])],
+        )]
+    )]
+    // Example 578: 기본 이미지 (destination만)
+    #[case("![foo](train.jpg)", vec![InlineNode::image("train.jpg", None, vec![InlineNode::text("foo")])])]
+    // Example 580: <> destination
+    #[case("![foo](<url>)", vec![InlineNode::image("url", None, vec![InlineNode::text("foo")])])]
+    // Example 581: 빈 대체 텍스트
+    #[case("![](/url)", vec![InlineNode::image("/url", None, vec![])])]
+    // Example 592: 이스케이프된 [는 이미지 아님
+    #[case("!\\[foo]", vec![InlineNode::text("![foo]")])]
+    // 추가 케이스: ! 단독
+    #[case("hi!", vec![InlineNode::text("hi!")])]
+    fn test_image(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // Example 582: 참조 이미지
+    #[case("![foo][bar]\n\n[bar]: /url", vec![InlineNode::image("/url", None, vec![InlineNode::text("foo")])])]
+    // Example 584: collapsed 참조 이미지
+    #[case(
+        "![foo][]\n\n[foo]: /url \"title\"",
+        vec![InlineNode::image("/url", Some("title"), vec![InlineNode::text("foo")])]
+    )]
+    // Example 588: shortcut 참조 이미지
+    #[case(
+        "![foo]\n\n[foo]: /url \"title\"",
+        vec![InlineNode::image("/url", Some("title"), vec![InlineNode::text("foo")])]
+    )]
+    // Example 593: 이스케이프된 !는 리터럴, 뒤는 링크
+    #[case(
+        "\\![foo]\n\n[foo]: /url \"title\"",
+        vec![InlineNode::text("!"), InlineNode::link("/url", Some("title"), vec![InlineNode::text("foo")])]
+    )]
+    fn test_reference_image(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children[0], BlockNode::paragraph(expected));
+    }
+
+    #[test]
+    fn test_bracket_nesting_limit() {
+        // MAX_NESTING보다 깊게 열린 대괄호는 리터럴
+        let depth = crate::parser::inline::MAX_NESTING;
+        let doc = parse(&("![".repeat(depth + 1) + "a" + &"](u)".repeat(depth + 1))).without_spans();
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        let [InlineNode::Image(image), rest] = node.children.as_slice() else {
+            panic!("Image와 남은 텍스트여야 함");
+        };
+        assert_eq!(rest, &InlineNode::text("](u)"));
+        let mut image = image;
+        for _ in 1..depth {
+            let [InlineNode::Image(inner)] = image.children.as_slice() else {
+                panic!("Image가 중첩되어야 함");
+            };
+            image = inner;
+        }
+        assert_eq!(image.children, vec![InlineNode::text("![a")]);
+    }
+
+    #[test]
+    fn test_unclosed_destinations() {
+        // 닫히지 않은 `(`가 이어져도 매번 텍스트 끝까지 훑지 않음 (이전에는 입력 길이의 제곱에 비례)
+        let input = "[a](".repeat(32000);
+        let doc = parse(&input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(vec![InlineNode::text(&input)])]);
+    }
+
+    #[test]
+    fn test_deep_brackets_no_stack_overflow() {
+        let doc = parse(&("![".repeat(32000) + "a" + &"](u)".repeat(32000)));
+        assert_eq!(doc.children.len(), 1);
+        let doc = parse(&("^[".repeat(32000) + "a" + &"]".repeat(32000)));
+        assert_eq!(doc.children.len(), 1);
+    }
+}
@@ -0,0 +1,125 @@
+//! https://help.obsidian.md/advanced-syntax#Math
+//!
+//! 인라인 수식 `$x^2$`를 파싱합니다 (Pandoc `tex_math_dollars`와 같은 규칙).
+//! - 여는 `$` 바로 뒤와 닫는 `$` 바로 앞은 공백이 아니어야 함
+//! - 닫는 `$` 바로 뒤에 숫자가 오면 닫는 `$`가 아님 (`$5 and $6`, `$20,000 and $30,000`은 리터럴)
+//! - 내용 안의 `\$`는 닫는 `$`가 아니며, 내용은 백슬래시를 포함한 TeX 원문 그대로 남음
+//!
+//! `$$`는 블록 수식 구분자이므로 인라인에서는 리터럴입니다 (math_block.rs).
+//! code span처럼 스캔 중에 노드가 되므로 안쪽의 `*`, `_` 등은 강조가 되지 않습니다.
+
+use crate::node::InlineNode;
+use crate::parser::helpers::count_leading_char;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum InlineMathErr {
+    /// `$`가 두 개 이상 연속 (run 전체가 리터럴)
+    DollarRun { len: usize },
+    /// 여는 `$` 바로 뒤가 공백이거나 텍스트 끝
+    NotOpener,
+    /// 조건에 맞는 닫는 `$` 없음
+    NotClosed,
+}
+
+/// pos 위치(`$`)에서 인라인 수식 파싱
+/// unclosed_from: 그 뒤로 닫는 `$`가 없다고 확인된 위치 (처음에는 `usize::MAX`)
+/// 닫는 `$`인지는 여는 `$`와 무관하므로, 한 번 끝까지 훑어 못 찾으면 뒤의 `$`는 다시 훑지 않음
+/// 반환: (InlineMath 노드, 닫는 `$` 다음 위치)
+pub(super) fn parse(
+    text: &str,
+    pos: usize,
+    unclosed_from: &mut usize,
+) -> Result<(InlineNode, usize), InlineMathErr> {
+    let len = count_leading_char(&text[pos..], '$');
+    if len > 1 {
+        return Err(InlineMathErr::DollarRun { len });
+    }
+    let content_start = pos + 1;
+    if text[content_start..].chars().next().is_none_or(char::is_whitespace) {
+        return Err(InlineMathErr::NotOpener);
+    }
+    if content_start >= *unclosed_from {
+        return Err(InlineMathErr::NotClosed);
+    }
+
+    let mut chars = text[content_start..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            // `\$` 등 이스케이프된 문자는 건너뜀
+            '\\' => {
+                chars.next();
+            }
+            '$' if is_closer(text, content_start + i) => {
+                let close = content_start + i;
+                return Ok((InlineNode::inline_math(&text[content_start..close]), close + 1));
+            }
+            _ => {}
+        }
+    }
+    *unclosed_from = content_start;
+    Err(InlineMathErr::NotClosed)
+}
+
+/// close 위치의 `$`가 닫는 `$`인지 (앞이 공백이 아니고 뒤가 숫자가 아님)
+fn is_closer(text: &str, close: usize) -> bool {
+    let before = text[..close].chars().next_back();
+    let after = text[close + 1..].chars().next();
+    before.is_some_and(|c| !c.is_whitespace()) && !after.is_some_and(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{LineColumns, line_columns};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("$x^2$", vec![InlineNode::inline_math("x^2")])]
+    #[case(
+        "area $\\pi r^2$.",
+        vec![InlineNode::text("area "), InlineNode::inline_math("\\pi r^2"), InlineNode::text(".")]
+    )]
+    #[case("$a$ and $b$", vec![InlineNode::inline_math("a"), InlineNode::text(" and "), InlineNode::inline_math("b")])]
+    // 내용은 해석하지 않음
+    #[case("$a_1 * b_1$", vec![InlineNode::inline_math("a_1 * b_1")])]
+    #[case("$\\$5$", vec![InlineNode::inline_math("\\$5")])]
+    #[case("$a\nb$", vec![InlineNode::inline_math("a\nb")])]
+    // 닫는 `$` 뒤 숫자나 앞 공백이면 다음 `$`를 찾음
+    #[case("$a $b$", vec![InlineNode::inline_math("a $b")])]
+    #[case("$5 or $a$", vec![InlineNode::inline_math("5 or $a")])]
+    #[case("$ $5 and $x$", vec![InlineNode::text("$ "), InlineNode::inline_math("5 and $x")])]
+    // 수식 아님
+    #[case("$5 and $6", vec![InlineNode::text("$5 and $6")])]
+    #[case("$20,000 and $30,000", vec![InlineNode::text("$20,000 and $30,000")])]
+    #[case("$ x$", vec![InlineNode::text("$ x$")])]
+    #[case("$x $", vec![InlineNode::text("$x $")])]
+    #[case("a $$x$$ b", vec![InlineNode::text("a $$x$$ b")])]
+    #[case("\\$x$", vec![InlineNode::text("$x$")])]
+    #[case("`$x$`", vec![InlineNode::code_span("$x$")])]
+    #[case("$", vec![InlineNode::text("$")])]
+    fn test_inline_math(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        assert_eq!(parse(input).without_spans().children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[test]
+    fn test_unclosed_dollars() {
+        // 닫는 `$`가 없는 `$`마다 텍스트 끝까지 다시 훑지 않음 (이전에는 입력 길이의 제곱에 비례)
+        let input = "costs $5 today\n".repeat(40000);
+        let doc = parse(&input);
+        let BlockNode::Paragraph(node) = &doc.children[0] else {
+            panic!("Paragraph여야 함");
+        };
+        assert!(node.children.iter().all(|inline| !matches!(inline, InlineNode::InlineMath(_))));
+    }
+
+    #[test]
+    fn test_inline_math_span() {
+        let doc = parse("a $x$ b");
+        let BlockNode::Paragraph(node) = &doc.children[0] else {
+            panic!("Paragraph여야 함");
+        };
+        let expected: LineColumns = ((1, 3), (1, 6));
+        assert_eq!(line_columns(node.children[1].span()), expected);
+    }
+}
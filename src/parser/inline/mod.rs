@@ -0,0 +1,568 @@
+//! 인라인 파서
+//!
+//! 블록 파싱이 끝난 뒤 Leaf 블록(Paragraph, Heading, Table 셀)의 원문 텍스트를 인라인 노드로 변환합니다.
+//!
+//! ## 단계
+//! 0. 참조 수집: 문서 전체의 Link Reference Definition을 모아 둠 (link.rs)
+//! 1. 스캔: 텍스트를 토큰(완성된 노드, 구분자 run, 링크 경계)으로 분해
+//!    - code span, autolink, raw HTML처럼 우선순위가 높은 요소는 스캔 중에 바로 노드가 됨
+//!    - 인라인 수식 `$…$`도 code span처럼 스캔 중에 노드가 됨 (math.rs)
+//!    - 꺾쇠 없는 GFM extended autolink도 스캔 중에 노드가 됨 (extended_autolink.rs)
+//!    - Obsidian 위키링크, 임베드, 태그, 주석도 스캔 중에 노드가 됨 (wikilink.rs, embed.rs, tag.rs, comment.rs)
+//!    - 백슬래시 이스케이프와 문자 참조는 구분자가 되지 않는 텍스트 토큰이 됨
+//!    - 줄바꿈은 SoftBreak/HardBreak 노드가 됨
+//!    - `]`를 만나면 bracket stack으로 링크/이미지/각주를 확정하고, 그 안쪽 강조를 먼저 처리
+//! 2. 강조 처리: delimiter stack 알고리즘으로 구분자 짝 맞추기 (emphasis.rs)
+//! 3. 트리 구성: 토큰 목록을 중첩된 InlineNode로 변환
+//!
+//! 깊게 중첩된 입력이 재귀적인 트리 순회에서 스택을 넘치지 않도록, 열린 대괄호와 강조는
+//! `MAX_NESTING`까지만 중첩하고 그보다 깊은 것은 리터럴 텍스트로 남깁니다.
+//!
+//! 노드 위치는 파싱한 텍스트 기준으로 채운 뒤, 블록 단계에서 기록한 줄별 원문 위치로 변환합니다.
+//!
+//! CommonMark 명세: https://spec.commonmark.org/0.31.2/#inlines
+
+mod autolink;
+mod code_span;
+mod comment;
+mod embed;
+mod emphasis;
+mod entity;
+mod entity_table;
+pub(crate) mod escape;
+mod extended_autolink;
+pub(crate) mod link;
+mod line_break;
+mod math;
+pub(crate) mod raw_html;
+mod tag;
+mod wikilink;
+
+use super::footnote_definition::is_valid_label;
+use super::source_map::{remap_inlines, SourceMap};
+use super::table::unescape_pipes;
+use crate::node::{
+    BlockNode, BlockquoteNode, CalloutNode, FootnoteDefinitionNode, FootnoteReferenceNode, HeadingNode, ImageNode,
+    InlineNode, LinkNode, ListItemNode, ListNode, ParagraphNode, Span, TableCellNode, TableNode, TableRowNode,
+};
+use emphasis::{DelimiterRun, EmphasisKind};
+use link::{LinkTarget, ReferenceMap};
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// 링크/이미지/인라인 각주와 강조의 최대 중첩 깊이 (더 깊으면 리터럴 텍스트)
+pub(crate) const MAX_NESTING: usize = 64;
+
+/// 인라인 파싱 중간 토큰
+#[derive(Debug)]
+enum Token {
+    /// 완성된 인라인 노드
+    Node(InlineNode),
+    /// 구분자 run (`*`, `_`, `~`, `=`) - 강조 처리 후 여닫는 위치 또는 리터럴 텍스트가 됨
+    Delimiter(DelimiterRun),
+    /// 링크/이미지 시작 (확정된 `[` 또는 `![` 자리)
+    LinkStart { image: bool, target: LinkTarget, start: usize },
+    /// 인라인 각주 시작 (확정된 `^[` 자리)
+    FootnoteStart { start: usize },
+    /// 링크/이미지/인라인 각주 끝 (확정된 `]` 및 대상 자리)
+    LinkEnd { end: usize },
+}
+
+/// 여는 대괄호 종류
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BracketKind {
+    /// `[`
+    Link,
+    /// `![`
+    Image,
+    /// `^[` (Obsidian 인라인 각주)
+    InlineFootnote,
+}
+
+/// 아직 닫히지 않은 여는 대괄호 (`[`, `![` 또는 `^[`)
+#[derive(Debug)]
+struct Bracket {
+    /// 여는 대괄호 텍스트 토큰의 위치
+    token_index: usize,
+    kind: BracketKind,
+    /// 대괄호 시작 위치
+    start: usize,
+    /// 링크 안에 링크를 만들 수 없도록 비활성화된 경우 false
+    active: bool,
+    /// 대괄호 다음 텍스트 위치 (참조 링크의 label 계산용)
+    content_start: usize,
+}
+
+/// 인라인 트리 구성 중 열려 있는 요소 (start: 여는 구분자 위치)
+enum Frame {
+    Emphasis { kind: EmphasisKind, start: usize },
+    Link { image: bool, target: LinkTarget, start: usize },
+    Footnote { start: usize },
+}
+
+// =============================================================================
+// 블록 트리 순회
+// =============================================================================
+
+/// 블록 트리를 순회하며 Leaf 블록의 원문 텍스트를 인라인 파싱
+///
+/// 블록 파싱 단계는 Paragraph/Heading의 원문을 줄마다 Text 노드 하나로 저장해 둡니다.
+/// 참조 링크는 문서 어디에 정의되어도 되므로 먼저 정의를 모두 수집합니다.
+/// 이 함수는 문서 전체에 대해 한 번만 호출되어야 합니다.
+pub(crate) fn resolve(blocks: Vec<BlockNode>) -> Vec<BlockNode> {
+    let refs = link::collect_references(&blocks);
+    resolve_blocks(blocks, &refs)
+}
+
+fn resolve_blocks(blocks: Vec<BlockNode>, refs: &ReferenceMap) -> Vec<BlockNode> {
+    blocks.into_iter().map(|block| resolve_block(block, refs)).collect()
+}
+
+fn resolve_block(block: BlockNode, refs: &ReferenceMap) -> BlockNode {
+    match block {
+        BlockNode::Paragraph(node) => BlockNode::Paragraph(ParagraphNode {
+            children: parse_raw(node.children, refs),
+            ..node
+        }),
+        BlockNode::Heading(node) => BlockNode::Heading(HeadingNode {
+            children: parse_raw(node.children, refs),
+            ..node
+        }),
+        BlockNode::Blockquote(node) => BlockNode::Blockquote(BlockquoteNode {
+            children: resolve_blocks(node.children, refs),
+            ..node
+        }),
+        BlockNode::Callout(node) => BlockNode::Callout(CalloutNode {
+            title: parse_raw(node.title, refs),
+            children: resolve_blocks(node.children, refs),
+            ..node
+        }),
+        BlockNode::List(node) => BlockNode::List(ListNode {
+            children: node.children.into_iter().map(|item| resolve_list_item(item, refs)).collect(),
+            ..node
+        }),
+        BlockNode::ListItem(node) => BlockNode::ListItem(resolve_list_item(node, refs)),
+        BlockNode::FootnoteDefinition(node) => BlockNode::FootnoteDefinition(FootnoteDefinitionNode {
+            children: resolve_blocks(node.children, refs),
+            ..node
+        }),
+        BlockNode::Table(node) => BlockNode::Table(TableNode {
+            header: resolve_table_row(node.header, refs),
+            rows: node.rows.into_iter().map(|row| resolve_table_row(row, refs)).collect(),
+            ..node
+        }),
+        other => other,
+    }
+}
+
+fn resolve_list_item(item: ListItemNode, refs: &ReferenceMap) -> ListItemNode {
+    ListItemNode {
+        children: resolve_blocks(item.children, refs),
+        ..item
+    }
+}
+
+fn resolve_table_row(row: TableRowNode, refs: &ReferenceMap) -> TableRowNode {
+    TableRowNode {
+        cells: row
+            .cells
+            .into_iter()
+            .map(|cell| TableCellNode {
+                children: parse_cell(cell.children, refs),
+                ..cell
+            })
+            .collect(),
+        ..row
+    }
+}
+
+/// 표 셀 원문(Text 노드)에서 `\|`의 백슬래시를 빼고 인라인 노드로 변환
+fn parse_cell(children: Vec<InlineNode>, refs: &ReferenceMap) -> Vec<InlineNode> {
+    let Some(InlineNode::Text(raw)) = children.first() else {
+        return children;
+    };
+    let base = raw.span.start.offset;
+    let (text, map) = SourceMap::concat(
+        unescape_pipes(raw.as_str())
+            .into_iter()
+            .map(|(piece, offset)| (piece, base + offset)),
+    );
+    let mut nodes = parse(&text, refs);
+    remap_inlines(&mut nodes, &|offset| map.to_source(offset));
+    nodes
+}
+
+/// 블록 단계에서 저장한 원문(줄별 Text 노드)을 이어 붙여 인라인 노드로 변환
+fn parse_raw(children: Vec<InlineNode>, refs: &ReferenceMap) -> Vec<InlineNode> {
+    let (text, map) = SourceMap::join(children.iter().filter_map(|child| match child {
+        InlineNode::Text(text) => Some((text.as_str(), text.span.start.offset)),
+        _ => None,
+    }));
+    let mut nodes = parse(&text, refs);
+    remap_inlines(&mut nodes, &|offset| map.to_source(offset));
+    nodes
+}
+
+// =============================================================================
+// 인라인 파싱
+// =============================================================================
+
+/// 텍스트 한 덩어리를 인라인 노드 목록으로 파싱 (위치는 text 기준)
+pub(crate) fn parse(text: &str, refs: &ReferenceMap) -> Vec<InlineNode> {
+    let mut tokens = scan(text, refs);
+    emphasis::process(&mut tokens, 0);
+    build_tree(tokens)
+}
+
+/// 텍스트를 토큰 목록으로 분해
+fn scan(text: &str, refs: &ReferenceMap) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut brackets: Vec<Bracket> = Vec::new();
+    // 닫는 `$`가 더 없는 위치 (math::parse 참고)
+    let mut math_unclosed_from = usize::MAX;
+    // 아직 토큰으로 내보내지 않은 일반 텍스트의 시작 위치
+    let mut text_start = 0;
+    let mut pos = 0;
+
+    while let Some(c) = text[pos..].chars().next() {
+        match c {
+            '*' | '_' | '~' | '=' => {
+                push_source(&mut tokens, text, text_start, pos);
+                let (run, end) = emphasis::scan_delimiter_run(text, pos);
+                tokens.push(Token::Delimiter(run));
+                pos = end;
+                text_start = end;
+            }
+            '`' => match code_span::parse(text, pos) {
+                Ok((node, end)) => {
+                    push_source(&mut tokens, text, text_start, pos);
+                    push_node(&mut tokens, node, pos, end);
+                    pos = end;
+                    text_start = end;
+                }
+                // 닫히지 않은 백틱 문자열은 통째로 리터럴 (Example 347)
+                Err(code_span::CodeSpanErr::NotClosed { opening_len }) => pos += opening_len,
+            },
+            '$' => match math::parse(text, pos, &mut math_unclosed_from) {
+                Ok((node, end)) => {
+                    push_source(&mut tokens, text, text_start, pos);
+                    push_node(&mut tokens, node, pos, end);
+                    pos = end;
+                    text_start = end;
+                }
+                // `$$` 이상은 run 전체가 리터럴 (run 안의 `$`가 여는 `$`가 되지 않음)
+                Err(math::InlineMathErr::DollarRun { len }) => pos += len,
+                Err(_) => pos += 1,
+            },
+            '<' => match autolink::parse(text, pos).or_else(|_| raw_html::parse(text, pos)) {
+                Ok((node, end)) => {
+                    push_source(&mut tokens, text, text_start, pos);
+                    push_node(&mut tokens, node, pos, end);
+                    pos = end;
+                    text_start = end;
+                }
+                Err(_) => pos += 1,
+            },
+            '\\' if text[pos + 1..].starts_with('\n') => {
+                push_source(&mut tokens, text, text_start, pos);
+                let (node, end) = line_break::parse_backslash(text, pos);
+                push_node(&mut tokens, node, pos, pos + 2);
+                pos = end;
+                text_start = end;
+            }
+            '\\' => match escape::parse(text, pos) {
+                Some((escaped, end)) => {
+                    push_source(&mut tokens, text, text_start, pos);
+                    push_text(&mut tokens, &escaped.to_string(), pos, end);
+                    pos = end;
+                    text_start = end;
+                }
+                None => pos += 1,
+            },
+            '&' => match entity::parse(text, pos) {
+                Some((decoded, end)) => {
+                    push_source(&mut tokens, text, text_start, pos);
+                    push_text(&mut tokens, &decoded, pos, end);
+                    pos = end;
+                    text_start = end;
+                }
+                None => pos += 1,
+            },
+            '[' | '!' | '^' if c == '[' || text[pos + 1..].starts_with('[') => {
+                // 위키링크 `[[...]]`와 임베드 `![[...]]`는 일반 링크/이미지보다 먼저 확인
+                let obsidian_link = match c {
+                    '[' => wikilink::parse(text, pos),
+                    '!' => embed::parse(text, pos),
+                    _ => None,
+                };
+                if let Some((node, end)) = obsidian_link {
+                    push_source(&mut tokens, text, text_start, pos);
+                    // 위키링크도 링크 안에 링크 불가: 앞쪽의 여는 `[`를 비활성화
+                    if matches!(node, InlineNode::WikiLink(_)) {
+                        deactivate_link_openers(&mut brackets);
+                    }
+                    push_node(&mut tokens, node, pos, end);
+                    pos = end;
+                    text_start = end;
+                    continue;
+                }
+                let (kind, end) = match c {
+                    '[' => (BracketKind::Link, pos + 1),
+                    '!' => (BracketKind::Image, pos + 2),
+                    _ => (BracketKind::InlineFootnote, pos + 2),
+                };
+                // 너무 깊게 열린 대괄호는 리터럴
+                if brackets.len() >= MAX_NESTING {
+                    pos = end;
+                    continue;
+                }
+                push_source(&mut tokens, text, text_start, pos);
+                brackets.push(Bracket {
+                    token_index: tokens.len(),
+                    kind,
+                    start: pos,
+                    active: true,
+                    content_start: end,
+                });
+                push_source(&mut tokens, text, pos, end);
+                pos = end;
+                text_start = end;
+            }
+            ']' => {
+                let Some(opener) = brackets.pop() else {
+                    pos += 1;
+                    continue;
+                };
+
+                // 인라인 각주 `^[text]`: 안쪽 강조를 먼저 처리하고 내용을 감쌈
+                if opener.kind == BracketKind::InlineFootnote {
+                    push_source(&mut tokens, text, text_start, pos);
+                    emphasis::process(&mut tokens, opener.token_index + 1);
+                    tokens[opener.token_index] = Token::FootnoteStart { start: opener.start };
+                    tokens.push(Token::LinkEnd { end: pos + 1 });
+                    pos += 1;
+                    text_start = pos;
+                    continue;
+                }
+
+                let link_text = &text[opener.content_start..pos];
+                let tail = opener
+                    .active
+                    .then(|| link::parse_link_tail(text, pos + 1, link_text, refs))
+                    .flatten();
+                let Some((target, end)) = tail else {
+                    // 링크가 아닌 `[^label]`은 각주 참조 (정의 여부는 번호 매기기 단계에서 확인)
+                    if let Some(label) = link_text.strip_prefix('^').filter(|label| is_valid_label(label))
+                        && opener.kind == BracketKind::Link
+                    {
+                        tokens.truncate(opener.token_index);
+                        push_node(&mut tokens, InlineNode::footnote_reference(label), opener.start, pos + 1);
+                        pos += 1;
+                        text_start = pos;
+                        continue;
+                    }
+                    // 링크가 아니면 `]`는 리터럴
+                    pos += 1;
+                    continue;
+                };
+
+                push_source(&mut tokens, text, text_start, pos);
+                // 링크 텍스트 안의 강조를 먼저 처리 (강조는 링크 경계를 넘을 수 없음)
+                emphasis::process(&mut tokens, opener.token_index + 1);
+                tokens[opener.token_index] = Token::LinkStart {
+                    image: opener.kind == BracketKind::Image,
+                    target,
+                    start: opener.start,
+                };
+                tokens.push(Token::LinkEnd { end });
+
+                // 링크 안에 링크 불가: 앞쪽의 여는 `[`를 비활성화 (이미지는 해당 없음)
+                if opener.kind == BracketKind::Link {
+                    deactivate_link_openers(&mut brackets);
+                }
+                pos = end;
+                text_start = end;
+            }
+            '\n' => {
+                let (content, node, end) = line_break::parse(text, text_start, pos);
+                let content_end = text_start + content.len();
+                push_text(&mut tokens, content, text_start, content_end);
+                push_node(&mut tokens, node, content_end, pos + 1);
+                pos = end;
+                text_start = end;
+            }
+            // Obsidian 주석 (닫히지 않은 `%%`는 리터럴)
+            '%' => match comment::parse(text, pos) {
+                Some((node, end)) => {
+                    push_source(&mut tokens, text, text_start, pos);
+                    push_node(&mut tokens, node, pos, end);
+                    pos = end;
+                    text_start = end;
+                }
+                None => pos += 1,
+            },
+            // Obsidian 태그 (링크 텍스트 안에서는 인식하지 않음, 인라인 각주 안은 인식)
+            '#' if brackets.iter().all(|bracket| bracket.kind == BracketKind::InlineFootnote) => match tag::parse(text, pos) {
+                Some((node, end)) => {
+                    push_source(&mut tokens, text, text_start, pos);
+                    push_node(&mut tokens, node, pos, end);
+                    pos = end;
+                    text_start = end;
+                }
+                None => pos += 1,
+            },
+            // GFM extended autolink (링크 텍스트 안에서는 인식하지 않음)
+            _ if c.is_ascii_alphanumeric() && brackets.is_empty() => match extended_autolink::parse(text, pos) {
+                Some((node, end)) => {
+                    push_source(&mut tokens, text, text_start, pos);
+                    push_node(&mut tokens, node, pos, end);
+                    pos = end;
+                    text_start = end;
+                }
+                None => pos += 1,
+            },
+            _ => pos += c.len_utf8(),
+        }
+    }
+    push_source(&mut tokens, text, text_start, text.len());
+
+    tokens
+}
+
+/// 링크가 확정되면 바깥 링크가 될 수 있는 여는 `[`를 모두 비활성화
+fn deactivate_link_openers(brackets: &mut [Bracket]) {
+    for bracket in brackets.iter_mut().filter(|b| b.kind == BracketKind::Link) {
+        bracket.active = false;
+    }
+}
+
+/// 원문 [start, end) 그대로인 텍스트 토큰 추가
+fn push_source(tokens: &mut Vec<Token>, text: &str, start: usize, end: usize) {
+    push_text(tokens, &text[start..end], start, end);
+}
+
+/// 원문 [start, end)에서 나온 텍스트 토큰 추가 (빈 문자열은 무시)
+fn push_text(tokens: &mut Vec<Token>, s: &str, start: usize, end: usize) {
+    if !s.is_empty() {
+        push_node(tokens, InlineNode::text(s), start, end);
+    }
+}
+
+/// 원문 [start, end)에서 나온 완성된 노드 토큰 추가
+fn push_node(tokens: &mut Vec<Token>, node: InlineNode, start: usize, end: usize) {
+    tokens.push(Token::Node(node.with_span(Span::from_offsets(start, end))));
+}
+
+// =============================================================================
+// 트리 구성
+// =============================================================================
+
+/// 토큰 목록을 중첩된 인라인 노드로 변환
+///
+/// 구분자 run은 `[닫는 강조들][남은 리터럴][여는 강조들]` 순서로 펼쳐집니다.
+/// 닫는 강조는 run 앞쪽부터, 여는 강조는 run 뒤쪽부터 안쪽 강조 순으로 구분자를 씁니다.
+/// `MAX_NESTING`보다 깊이 열리는 강조는 여는 구분자와 닫는 구분자 모두 리터럴 텍스트가 됩니다.
+fn build_tree(tokens: Vec<Token>) -> Vec<InlineNode> {
+    let mut root: Vec<InlineNode> = Vec::new();
+    // 열려 있는 요소: (종류, 지금까지의 자식들)
+    let mut frames: Vec<(Frame, Vec<InlineNode>)> = Vec::new();
+    // 열려 있는 강조마다 리터럴로 남겼는지 (여는 순서대로)
+    let mut literal_emphasis: Vec<bool> = Vec::new();
+
+    for token in tokens {
+        match token {
+            Token::Node(node) => push_inline(current_children(&mut root, &mut frames), node),
+            Token::Delimiter(run) => {
+                let mut pos = run.start;
+                for kind in &run.closes {
+                    let end = pos + kind.delimiter_len();
+                    if literal_emphasis.pop() == Some(true) {
+                        push_delimiter_text(current_children(&mut root, &mut frames), run.ch, pos, end);
+                        pos = end;
+                        continue;
+                    }
+                    let (frame, children) = frames.pop().expect("닫는 강조에는 여는 강조가 있어야 함");
+                    let Frame::Emphasis { kind: open_kind, start } = frame else {
+                        unreachable!("강조 안의 링크는 강조가 닫히기 전에 모두 처리되어야 함");
+                    };
+                    debug_assert_eq!(open_kind, *kind);
+                    pos = end;
+                    let node = kind.to_node(children).with_span(Span::from_offsets(start, pos));
+                    push_inline(current_children(&mut root, &mut frames), node);
+                }
+                if run.len > 0 {
+                    push_delimiter_text(current_children(&mut root, &mut frames), run.ch, pos, pos + run.len);
+                    pos += run.len;
+                }
+                for kind in run.opens.iter().rev() {
+                    let end = pos + kind.delimiter_len();
+                    let literal = frames.len() >= MAX_NESTING;
+                    literal_emphasis.push(literal);
+                    match literal {
+                        true => push_delimiter_text(current_children(&mut root, &mut frames), run.ch, pos, end),
+                        false => frames.push((Frame::Emphasis { kind: *kind, start: pos }, Vec::new())),
+                    }
+                    pos = end;
+                }
+            }
+            Token::LinkStart { image, target, start } => {
+                frames.push((Frame::Link { image, target, start }, Vec::new()))
+            }
+            Token::FootnoteStart { start } => frames.push((Frame::Footnote { start }, Vec::new())),
+            Token::LinkEnd { end } => {
+                let node = match frames.pop() {
+                    Some((Frame::Link { image: true, target, start }, children)) => InlineNode::Image(ImageNode {
+                        destination: target.destination,
+                        title: target.title,
+                        children,
+                        span: Span::from_offsets(start, end),
+                    }),
+                    Some((Frame::Link { image: false, target, start }, children)) => InlineNode::Link(LinkNode {
+                        destination: target.destination,
+                        title: target.title,
+                        children,
+                        span: Span::from_offsets(start, end),
+                    }),
+                    Some((Frame::Footnote { start }, children)) => {
+                        InlineNode::FootnoteReference(FootnoteReferenceNode::inline(children))
+                            .with_span(Span::from_offsets(start, end))
+                    }
+                    _ => unreachable!("링크 안의 강조는 링크가 닫히기 전에 모두 처리되어야 함"),
+                };
+                push_inline(current_children(&mut root, &mut frames), node);
+            }
+        }
+    }
+
+    debug_assert!(frames.is_empty(), "모든 강조와 링크가 닫혀야 함");
+    root
+}
+
+/// 원문 [start, end)의 구분자 문자를 리터럴 텍스트로 추가
+fn push_delimiter_text(children: &mut Vec<InlineNode>, ch: char, start: usize, end: usize) {
+    let literal = ch.to_string().repeat(end - start);
+    push_inline(children, InlineNode::text(&literal).with_span(Span::from_offsets(start, end)));
+}
+
+/// 현재 노드를 추가할 자식 목록 (가장 안쪽 열린 요소 또는 최상위)
+fn current_children<'a>(
+    root: &'a mut Vec<InlineNode>,
+    frames: &'a mut [(Frame, Vec<InlineNode>)],
+) -> &'a mut Vec<InlineNode> {
+    match frames.last_mut() {
+        Some((_, children)) => children,
+        None => root,
+    }
+}
+
+/// 인라인 노드 추가 (연속된 Text는 범위까지 하나로 합침)
+fn push_inline(children: &mut Vec<InlineNode>, node: InlineNode) {
+    if let (Some(InlineNode::Text(last)), InlineNode::Text(text)) = (children.last_mut(), &node) {
+        last.content.push_str(text.as_str());
+        last.span.end = text.span.end;
+        return;
+    }
+    children.push(node);
+}
@@ -0,0 +1,240 @@
+//! https://spec.commonmark.org/0.31.2/#raw-html
+//!
+//! 인라인 HTML 태그, 주석, processing instruction, 선언, CDATA를 원문 그대로 보존합니다.
+//! 여는/닫는 태그 판별은 HTML 블록(start condition 7)에서도 사용합니다.
+
+use crate::node::InlineNode;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum RawHtmlErr {
+    /// 어떤 HTML 구문 형태에도 맞지 않음
+    NotHtml,
+}
+
+/// pos 위치(`<`)에서 raw HTML 파싱
+/// 반환: (RawHtml 노드, HTML 다음 위치)
+pub(super) fn parse(text: &str, pos: usize) -> Result<(InlineNode, usize), RawHtmlErr> {
+    let end = parse_open_tag(text, pos)
+        .or_else(|| parse_closing_tag(text, pos))
+        .or_else(|| parse_comment(text, pos))
+        .or_else(|| parse_delimited(text, pos, "<?", "?>"))
+        .or_else(|| parse_delimited(text, pos, "<![CDATA[", "]]>"))
+        .or_else(|| parse_declaration(text, pos))
+        .ok_or(RawHtmlErr::NotHtml)?;
+
+    Ok((InlineNode::raw_html(&text[pos..end]), end))
+}
+
+// =============================================================================
+// 태그
+// =============================================================================
+
+/// 여는 태그: `<` tag name, attribute*, 공백?, `/`?, `>`
+/// 반환: 태그 다음 위치
+pub(crate) fn parse_open_tag(text: &str, pos: usize) -> Option<usize> {
+    if !text[pos..].starts_with('<') {
+        return None;
+    }
+    let mut cursor = parse_tag_name(text, pos + 1)?;
+
+    while let Some(end) = parse_attribute(text, cursor) {
+        cursor = end;
+    }
+
+    cursor = skip_whitespace(text, cursor);
+    if text[cursor..].starts_with("/>") {
+        return Some(cursor + 2);
+    }
+    text[cursor..].starts_with('>').then_some(cursor + 1)
+}
+
+/// 닫는 태그: `</` tag name, 공백?, `>`
+/// 반환: 태그 다음 위치
+pub(crate) fn parse_closing_tag(text: &str, pos: usize) -> Option<usize> {
+    if !text[pos..].starts_with("</") {
+        return None;
+    }
+    let cursor = skip_whitespace(text, parse_tag_name(text, pos + 2)?);
+    text[cursor..].starts_with('>').then_some(cursor + 1)
+}
+
+/// tag name: ASCII 영문자로 시작, 이후 영숫자 또는 `-`
+fn parse_tag_name(text: &str, pos: usize) -> Option<usize> {
+    let rest = &text[pos..];
+    if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(rest.len());
+    Some(pos + len)
+}
+
+/// attribute: 공백 + 이름 + (공백? `=` 공백? 값)?
+fn parse_attribute(text: &str, pos: usize) -> Option<usize> {
+    let name_start = skip_whitespace(text, pos);
+    if name_start == pos {
+        return None;
+    }
+
+    // 이름: ASCII 영문자, `_`, `:`로 시작, 이후 영숫자, `_`, `.`, `:`, `-`
+    let rest = &text[name_start..];
+    if !rest.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == ':') {
+        return None;
+    }
+    let name_len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | ':' | '-')))
+        .unwrap_or(rest.len());
+    let name_end = name_start + name_len;
+
+    // 값 지정은 선택 사항
+    let equals = skip_whitespace(text, name_end);
+    if !text[equals..].starts_with('=') {
+        return Some(name_end);
+    }
+    parse_attribute_value(text, skip_whitespace(text, equals + 1)).or(Some(name_end))
+}
+
+/// attribute 값: 따옴표 없는 값, `'...'`, `"..."`
+fn parse_attribute_value(text: &str, pos: usize) -> Option<usize> {
+    let rest = &text[pos..];
+    match rest.chars().next()? {
+        quote @ ('\'' | '"') => rest[1..].find(quote).map(|len| pos + 1 + len + 1),
+        _ => {
+            let len = rest
+                .find([' ', '\t', '\n', '"', '\'', '=', '<', '>', '`'])
+                .unwrap_or(rest.len());
+            (len > 0).then_some(pos + len)
+        }
+    }
+}
+
+// =============================================================================
+// 주석, processing instruction, 선언, CDATA
+// =============================================================================
+
+/// 주석: `<!-->`, `<!--->`, 또는 `<!--` + `-->`를 포함하지 않는 텍스트 + `-->`
+fn parse_comment(text: &str, pos: usize) -> Option<usize> {
+    let rest = &text[pos..];
+    for empty in ["<!-->", "<!--->"] {
+        if rest.starts_with(empty) {
+            return Some(pos + empty.len());
+        }
+    }
+    parse_delimited(text, pos, "<!--", "-->")
+}
+
+/// 선언: `<!` + ASCII 영문자 + `>`를 포함하지 않는 텍스트 + `>`
+fn parse_declaration(text: &str, pos: usize) -> Option<usize> {
+    let body = text[pos..].strip_prefix("<!")?;
+    if !body.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    body.find('>').map(|len| pos + 2 + len + 1)
+}
+
+/// 여는 문자열로 시작해 처음 나오는 닫는 문자열에서 끝나는 구문
+fn parse_delimited(text: &str, pos: usize, open: &str, close: &str) -> Option<usize> {
+    let body = text[pos..].strip_prefix(open)?;
+    body.find(close).map(|len| pos + open.len() + len + close.len())
+}
+
+/// 공백/탭/줄바꿈 건너뛰기
+fn skip_whitespace(text: &str, pos: usize) -> usize {
+    pos + text[pos..].len() - text[pos..].trim_start_matches([' ', '\t', '\n']).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    // Example 613: 여는 태그
+    #[case(
+        "<a><bab><c2c>",
+        vec![InlineNode::raw_html("<a>"), InlineNode::raw_html("<bab>"), InlineNode::raw_html("<c2c>")]
+    )]
+    // Example 614: 빈 요소
+    #[case("<a/><b2/>", vec![InlineNode::raw_html("<a/>"), InlineNode::raw_html("<b2/>")])]
+    // Example 615: 공백 허용
+    #[case(
+        "<a  /><b2\ndata=\"foo\" >",
+        vec![InlineNode::raw_html("<a  />"), InlineNode::raw_html("<b2\ndata=\"foo\" >")]
+    )]
+    // Example 616: attribute
+    #[case(
+        "<a foo=\"bar\" bam = 'baz <em>\"</em>'\n_boolean zoop:33=zoop:33 />",
+        vec![InlineNode::raw_html("<a foo=\"bar\" bam = 'baz <em>\"</em>'\n_boolean zoop:33=zoop:33 />")]
+    )]
+    // Example 617: 사용자 정의 태그
+    #[case(
+        "Foo <responsive-image src=\"foo.jpg\" />",
+        vec![InlineNode::text("Foo "), InlineNode::raw_html("<responsive-image src=\"foo.jpg\" />")]
+    )]
+    // Example 618: 유효하지 않은 태그 이름
+    #[case("<33> <__>", vec![InlineNode::text("<33> <__>")])]
+    // Example 619: 유효하지 않은 attribute 이름
+    #[case("<a h*#ref=\"hi\">", vec![InlineNode::text("<a h*#ref=\"hi\">")])]
+    // Example 620: 유효하지 않은 attribute 값
+    #[case("<a href=\"hi'> <a href=hi'>", vec![InlineNode::text("<a href=\"hi'> <a href=hi'>")])]
+    // Example 622: attribute 사이 공백 필요
+    #[case("<a href='bar'title=title>", vec![InlineNode::text("<a href='bar'title=title>")])]
+    // Example 623: 닫는 태그
+    #[case("</a></foo >", vec![InlineNode::raw_html("</a>"), InlineNode::raw_html("</foo >")])]
+    // Example 624: 닫는 태그에 attribute 불가
+    #[case("</a href=\"foo\">", vec![InlineNode::text("</a href=\"foo\">")])]
+    // Example 625: 주석
+    #[case(
+        "foo <!-- this is a --\ncomment - with hyphens -->",
+        vec![InlineNode::text("foo "), InlineNode::raw_html("<!-- this is a --\ncomment - with hyphens -->")]
+    )]
+    // Example 626: 빈 주석
+    #[case(
+        "foo <!--> foo -->",
+        vec![InlineNode::text("foo "), InlineNode::raw_html("<!-->"), InlineNode::text(" foo -->")]
+    )]
+    #[case(
+        "foo <!---> foo -->",
+        vec![InlineNode::text("foo "), InlineNode::raw_html("<!--->"), InlineNode::text(" foo -->")]
+    )]
+    // Example 627: processing instruction
+    #[case("foo <?php echo $a; ?>", vec![InlineNode::text("foo "), InlineNode::raw_html("<?php echo $a; ?>")])]
+    // Example 628: 선언
+    #[case("foo <!ELEMENT br EMPTY>", vec![InlineNode::text("foo "), InlineNode::raw_html("<!ELEMENT br EMPTY>")])]
+    // Example 629: CDATA
+    #[case("foo <![CDATA[>&<]]>", vec![InlineNode::text("foo "), InlineNode::raw_html("<![CDATA[>&<]]>")])]
+    // Example 630-631: attribute 값 안에서는 참조와 이스케이프를 해석하지 않음
+    #[case("foo <a href=\"&ouml;\">", vec![InlineNode::text("foo "), InlineNode::raw_html("<a href=\"&ouml;\">")])]
+    #[case("foo <a href=\"\\*\">", vec![InlineNode::text("foo "), InlineNode::raw_html("<a href=\"\\*\">")])]
+    // Example 632: 이스케이프는 attribute 값을 닫지 못함
+    #[case("<a href=\"\\\"\">", vec![InlineNode::text("<a href=\"\"\">")])]
+    // Example 344: 태그가 code span보다 우선
+    #[case("<a href=\"`\">`", vec![InlineNode::raw_html("<a href=\"`\">"), InlineNode::text("`")])]
+    // Example 524: 태그가 링크 텍스트보다 우선
+    #[case(
+        "[foo <bar attr=\"](baz)\">",
+        vec![InlineNode::text("[foo "), InlineNode::raw_html("<bar attr=\"](baz)\">")]
+    )]
+    fn test_raw_html(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[test]
+    fn test_not_raw_html() {
+        // Example 621: 태그 이름 앞 공백, 잘못된 위치의 /, 유효하지 않은 attribute
+        let doc = parse("< a><\nfoo><bar/ >\n<foo bar=baz\nbim!bop />").without_spans();
+        let expected = vec![
+            InlineNode::text("< a><"),
+            InlineNode::soft_break(),
+            InlineNode::text("foo><bar/ >"),
+            InlineNode::soft_break(),
+            InlineNode::text("<foo bar=baz"),
+            InlineNode::soft_break(),
+            InlineNode::text("bim!bop />"),
+        ];
+        assert_eq!(doc.children, vec![BlockNode::paragraph(expected)]);
+    }
+}
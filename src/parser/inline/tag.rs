@@ -0,0 +1,121 @@
+//! https://help.obsidian.md/tags
+//!
+//! Obsidian 태그 `#tag`, `#nested/tag`를 파싱합니다.
+//! - 줄 시작이나 공백 뒤의 `#`에서만 시작, 사이에 `*` `_` `~` `=` `(`는 올 수 있음 (`\_#tag`처럼 이스케이프해도 됨)
+//!   (`a#b`, `a*#b`, URL의 `/#anchor`는 태그 아님)
+//! - 이름은 문자, 숫자, `_`, `-`, `/`로 이루어지며 숫자만으로는 태그가 될 수 없음 (`#1984`)
+//! - `/`는 계층 구분자이므로 이름 앞뒤에는 올 수 없음
+//!
+//! `# Heading`처럼 `#` 뒤에 공백이 오면 태그가 아니므로 ATX Heading과 겹치지 않습니다.
+//! code span과 autolink는 스캔 중에 먼저 노드가 되므로 그 안의 `#`는 태그가 되지 않습니다.
+
+use crate::node::InlineNode;
+
+/// pos 위치(`#`)에서 태그 파싱
+/// 반환: (Tag 노드, 태그 다음 위치)
+pub(super) fn parse(text: &str, pos: usize) -> Option<(InlineNode, usize)> {
+    if !is_boundary(&text[..pos]) {
+        return None;
+    }
+
+    let rest = text[pos..].strip_prefix('#')?;
+    let len = rest.find(|c: char| !is_tag_char(c)).unwrap_or(rest.len());
+    let name = rest[..len].trim_end_matches('/');
+    let numeric = name.chars().all(|c| c.is_ascii_digit() || c == '/');
+    if name.is_empty() || name.starts_with('/') || numeric {
+        return None;
+    }
+
+    Some((InlineNode::tag(name), pos + 1 + name.len()))
+}
+
+/// `#` 앞이 줄 시작이나 공백인지 (사이의 `*` `_` `~` `=` `(`는 백슬래시로 이스케이프되었어도 건너뜀)
+fn is_boundary(before: &str) -> bool {
+    let mut before = before;
+    while let Some(rest) = before.strip_suffix(['*', '_', '~', '=', '(']) {
+        let backslashes = rest.len() - rest.trim_end_matches('\\').len();
+        before = &rest[..rest.len() - backslashes % 2];
+    }
+    before.chars().next_back().is_none_or(char::is_whitespace)
+}
+
+fn is_tag_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '/')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{LineColumns, line_columns};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("#tag", vec![InlineNode::tag("tag")])]
+    #[case("see #tag now", vec![InlineNode::text("see "), InlineNode::tag("tag"), InlineNode::text(" now")])]
+    #[case("#nested/tag/deep", vec![InlineNode::tag("nested/tag/deep")])]
+    #[case(
+        "#태그 #y1984 #snake_case-tag",
+        vec![
+            InlineNode::tag("태그"),
+            InlineNode::text(" "),
+            InlineNode::tag("y1984"),
+            InlineNode::text(" "),
+            InlineNode::tag("snake_case-tag"),
+        ]
+    )]
+    // 구두점과 끝의 `/`에서 끝남
+    #[case(
+        "#tag, #other.",
+        vec![InlineNode::tag("tag"), InlineNode::text(", "), InlineNode::tag("other"), InlineNode::text(".")]
+    )]
+    #[case("#tag/", vec![InlineNode::tag("tag"), InlineNode::text("/")])]
+    #[case("(#tag)", vec![InlineNode::text("("), InlineNode::tag("tag"), InlineNode::text(")")])]
+    #[case("**#tag**", vec![InlineNode::strong(vec![InlineNode::tag("tag")])])]
+    #[case("==#tag==", vec![InlineNode::highlight(vec![InlineNode::tag("tag")])])]
+    #[case(
+        "x \\_#tag \\~#b",
+        vec![InlineNode::text("x _"), InlineNode::tag("tag"), InlineNode::text(" ~"), InlineNode::tag("b")]
+    )]
+    // 태그 아님
+    #[case("#1984", vec![InlineNode::text("#1984")])]
+    #[case("#2024/01", vec![InlineNode::text("#2024/01")])]
+    #[case("#/tag", vec![InlineNode::text("#/tag")])]
+    #[case("a#tag", vec![InlineNode::text("a#tag")])]
+    #[case("a*#tag", vec![InlineNode::text("a*#tag")])]
+    #[case("##tag", vec![InlineNode::text("##tag")])]
+    #[case("\\#tag", vec![InlineNode::text("#tag")])]
+    #[case("\\\\_#tag", vec![InlineNode::text("\\_#tag")])]
+    #[case("&#35;tag", vec![InlineNode::text("#tag")])]
+    // code span, URL, 링크 텍스트 안
+    #[case("`#tag`", vec![InlineNode::code_span("#tag")])]
+    #[case("<https://example.com/#tag>", vec![InlineNode::autolink("https://example.com/#tag", false)])]
+    #[case(
+        "https://example.com/#tag",
+        vec![InlineNode::extended_autolink("https://example.com/#tag", false)]
+    )]
+    #[case("example.com/#tag", vec![InlineNode::text("example.com/#tag")])]
+    #[case("[#tag](url)", vec![InlineNode::link("url", None, vec![InlineNode::text("#tag")])])]
+    fn test_tag(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        assert_eq!(parse(input).without_spans().children, vec![BlockNode::paragraph(expected)]);
+    }
+
+    #[rstest]
+    // `#` 뒤 공백은 ATX Heading, heading 안의 태그는 인식
+    #[case("# Title #tag", BlockNode::heading(1, vec![InlineNode::text("Title "), InlineNode::tag("tag")]))]
+    #[case("# Title #", BlockNode::heading(1, vec![InlineNode::text("Title")]))]
+    #[case("#tag heading", BlockNode::paragraph(vec![InlineNode::tag("tag"), InlineNode::text(" heading")]))]
+    fn test_tag_heading(#[case] input: &str, #[case] expected: BlockNode) {
+        assert_eq!(parse(input).without_spans().children, vec![expected]);
+    }
+
+    #[test]
+    fn test_tag_span() {
+        let doc = parse("a #b/c d");
+        let BlockNode::Paragraph(node) = &doc.children[0] else {
+            panic!("Paragraph여야 함");
+        };
+        let expected: LineColumns = ((1, 3), (1, 7));
+        assert_eq!(line_columns(node.children[1].span()), expected);
+    }
+}
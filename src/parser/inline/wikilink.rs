@@ -0,0 +1,141 @@
+//! https://help.obsidian.md/links
+//!
+//! Obsidian 위키링크 `[[Page]]`, `[[Page|alias]]`, `[[Page#Heading]]`, `[[Page#^block]]`를 파싱합니다.
+//! 일반 링크보다 먼저 확인하며, 한 줄 안에서 `]]`로 닫혀야 하고 안쪽에 대괄호가 올 수 없습니다.
+//! 표 셀 안에서는 alias 구분자를 `\|`로 씁니다 (셀 분리 단계에서 `|`로 바뀜, 그 밖에서도 허용).
+//! 일반 링크처럼 링크 안에 들어갈 수 없어서, 링크 텍스트 안의 위키링크가 확정되면 바깥 `[`는 링크가 되지 않습니다.
+
+use crate::node::{InlineNode, WikiLinkNode};
+
+/// pos 위치(`[[`)에서 위키링크 파싱
+/// 반환: (WikiLink 노드, `]]` 다음 위치)
+pub(super) fn parse(text: &str, pos: usize) -> Option<(InlineNode, usize)> {
+    parse_link(text, pos).map(|(node, end)| (InlineNode::WikiLink(node), end))
+}
+
+/// `[[...]]`를 대상, fragment, alias로 나눔 (임베드 `![[...]]`도 같은 규칙)
+pub(super) fn parse_link(text: &str, pos: usize) -> Option<(WikiLinkNode, usize)> {
+    if !text[pos..].starts_with("[[") {
+        return None;
+    }
+    let content_start = pos + "[[".len();
+    let content_len = text[content_start..].find(['[', ']', '\n'])?;
+    let content_end = content_start + content_len;
+    if !text[content_end..].starts_with("]]") {
+        return None;
+    }
+
+    let content = &text[content_start..content_end];
+    let (link, alias) = match content.split_once('|') {
+        Some((link, alias)) => (link.strip_suffix('\\').unwrap_or(link), Some(alias.trim())),
+        None => (content, None),
+    };
+    let (target, fragment) = match link.split_once('#') {
+        Some((target, fragment)) => (target.trim(), Some(fragment.trim())),
+        None => (link.trim(), None),
+    };
+    if target.is_empty() && fragment.is_none_or(str::is_empty) {
+        return None;
+    }
+
+    let mut node = WikiLinkNode::new(target);
+    match fragment {
+        Some(fragment) if fragment.starts_with('^') => node = node.with_block_ref(&fragment[1..]),
+        Some(fragment) if !fragment.is_empty() => node = node.with_heading(fragment),
+        _ => {}
+    }
+    if let Some(alias) = alias.filter(|alias| !alias.is_empty()) {
+        node = node.with_alias(alias);
+    }
+
+    Some((node, content_end + "]]".len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{
+        BlockNode, InlineNode, TableAlignment, TableCellNode, TableRowNode, WikiLinkNode,
+    };
+    use crate::parser::parse;
+    use crate::parser::source_map::{LineColumns, line_columns};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("[[Page]]", vec![InlineNode::wikilink("Page")])]
+    #[case(
+        "see [[My Page]] now",
+        vec![InlineNode::text("see "), InlineNode::wikilink("My Page"), InlineNode::text(" now")]
+    )]
+    // alias
+    #[case("[[Page|shown text]]", vec![InlineNode::WikiLink(WikiLinkNode::new("Page").with_alias("shown text"))])]
+    #[case("[[Page\\|alias]]", vec![InlineNode::WikiLink(WikiLinkNode::new("Page").with_alias("alias"))])]
+    // heading, block id
+    #[case("[[Page#Heading]]", vec![InlineNode::WikiLink(WikiLinkNode::new("Page").with_heading("Heading"))])]
+    #[case("[[Page#^abc123]]", vec![InlineNode::WikiLink(WikiLinkNode::new("Page").with_block_ref("abc123"))])]
+    #[case("[[#Heading]]", vec![InlineNode::WikiLink(WikiLinkNode::new("").with_heading("Heading"))])]
+    #[case(
+        "[[Page#Heading|alias]]",
+        vec![InlineNode::WikiLink(WikiLinkNode::new("Page").with_heading("Heading").with_alias("alias"))]
+    )]
+    // 앞뒤 공백 무시, 빈 alias는 없는 것
+    #[case("[[ Page | ]]", vec![InlineNode::wikilink("Page")])]
+    // 위키링크가 아님
+    #[case("[[]]", vec![InlineNode::text("[[]]")])]
+    #[case("[[|alias]]", vec![InlineNode::text("[[|alias]]")])]
+    #[case("[[Page]", vec![InlineNode::text("[[Page]")])]
+    #[case("[[a [b] c]]", vec![InlineNode::text("[[a [b] c]]")])]
+    // 일반 링크보다 우선
+    #[case("[[foo]]\n\n[foo]: /url", vec![InlineNode::wikilink("foo")])]
+    // 강조 안
+    #[case("*[[Page]]*", vec![InlineNode::emphasis(vec![InlineNode::wikilink("Page")])])]
+    // code span이 우선
+    #[case("`[[Page]]`", vec![InlineNode::code_span("[[Page]]")])]
+    // 링크 안에 링크 불가: 바깥 링크가 텍스트 (이미지 설명 안은 허용)
+    #[case("[see [[a]]](u)", vec![InlineNode::text("[see "), InlineNode::wikilink("a"), InlineNode::text("](u)")])]
+    #[case(
+        "![see [[a]]](u)",
+        vec![InlineNode::image("u", None, vec![InlineNode::text("see "), InlineNode::wikilink("a")])]
+    )]
+    fn test_wikilink(#[case] input: &str, #[case] expected: Vec<InlineNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children.first(), Some(&BlockNode::paragraph(expected)));
+    }
+
+    #[test]
+    fn test_wikilink_in_table() {
+        let doc = parse("| a |\n| - |\n| [[Page\\|alias]] |").without_spans();
+        let cell = |children| TableCellNode::new(children);
+        let expected = BlockNode::table(
+            vec![TableAlignment::None],
+            TableRowNode::new(vec![cell(vec![InlineNode::text("a")])]),
+            vec![TableRowNode::new(vec![cell(vec![InlineNode::WikiLink(
+                WikiLinkNode::new("Page").with_alias("alias"),
+            )])])],
+        );
+        assert_eq!(doc.children, vec![expected]);
+    }
+
+    #[test]
+    fn test_wikilink_span() {
+        let doc = parse("a [[Page|b]]");
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        let actual: Vec<LineColumns> = node
+            .children
+            .iter()
+            .map(|child| line_columns(child.span()))
+            .collect();
+        assert_eq!(actual, vec![((1, 1), (1, 3)), ((1, 3), (1, 13))]);
+    }
+
+    #[rstest]
+    #[case(WikiLinkNode::new("Page"), "Page")]
+    #[case(WikiLinkNode::new("Page").with_heading("Heading"), "Page > Heading")]
+    #[case(WikiLinkNode::new("Page").with_block_ref("abc"), "Page > ^abc")]
+    #[case(WikiLinkNode::new("").with_heading("Heading"), "Heading")]
+    #[case(WikiLinkNode::new("Page").with_heading("Heading").with_alias("x"), "x")]
+    fn test_display_text(#[case] node: WikiLinkNode, #[case] expected: &str) {
+        assert_eq!(node.display_text(), expected);
+    }
+}
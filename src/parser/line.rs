@@ -0,0 +1,147 @@
+//! 줄 커서
+//!
+//! 블록 구조 파싱은 한 줄을 바깥 컨테이너부터 차례로 소비합니다.
+//! 각 컨테이너는 자신의 마커와 들여쓰기를 소비하고, 남은 부분(`rest`)을 안쪽 블록이 이어서 봅니다.
+
+use std::borrow::Cow;
+
+use super::helpers::{calculate_indent, tab_width};
+
+/// 소비 중인 한 줄 (줄 끝 제외)
+/// 들여쓰기는 열 단위로 소비하며, 탭은 일부만 소비될 수 있음 (Example 6, 7)
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Line<'a> {
+    text: &'a str,
+    /// 줄 시작의 원문 offset
+    offset: usize,
+    /// 소비한 byte 수
+    pos: usize,
+    /// 소비한 열 수 (탭은 다음 탭 정지까지)
+    column: usize,
+    /// pos의 탭을 일부만 소비했는지 (남은 칸은 내용의 공백)
+    partial_tab: bool,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(text: &'a str, offset: usize) -> Self {
+        Self {
+            text,
+            offset,
+            pos: 0,
+            column: 0,
+            partial_tab: false,
+        }
+    }
+
+    /// 아직 소비하지 않은 부분 (일부 소비한 탭은 그대로 포함)
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// 남은 부분을 내용으로 (일부 소비한 탭의 남은 칸은 공백)
+    pub fn content(&self) -> Cow<'a, str> {
+        match self.partial_tab {
+            true => Cow::Owned(format!("{}{}", " ".repeat(tab_width(self.column)), &self.rest()[1..])),
+            false => Cow::Borrowed(self.rest()),
+        }
+    }
+
+    /// 남은 부분 시작의 원문 offset
+    pub fn offset(&self) -> usize {
+        self.offset + self.pos
+    }
+
+    /// 남은 부분이 시작하는 열
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// 남은 부분의 들여쓰기 (칸)
+    pub fn indent(&self) -> usize {
+        calculate_indent(self.rest(), self.column)
+    }
+
+    /// 남은 부분이 공백뿐인지
+    pub fn is_blank(&self) -> bool {
+        self.rest().trim().is_empty()
+    }
+
+    /// n byte 소비
+    pub fn advance(&mut self, n: usize) {
+        let end = (self.pos + n).min(self.text.len());
+        for c in self.text[self.pos..end].chars() {
+            self.column += match c {
+                '\t' => tab_width(self.column),
+                _ => 1,
+            };
+        }
+        self.pos = end;
+        self.partial_tab = false;
+    }
+
+    /// 앞 들여쓰기를 최대 n칸 소비 (탭이 n칸을 넘으면 일부만 소비)
+    pub fn skip_indent(&mut self, n: usize) {
+        let mut remaining = n;
+        while remaining > 0 {
+            let width = match self.rest().chars().next() {
+                Some(' ') => 1,
+                Some('\t') => tab_width(self.column),
+                _ => return,
+            };
+            if width > remaining {
+                self.column += remaining;
+                self.partial_tab = true;
+                return;
+            }
+            self.advance(1);
+            remaining -= width;
+        }
+    }
+
+    /// 앞 공백과 탭을 모두 소비
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.advance(rest.len() - rest.trim_start_matches([' ', '\t']).len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("  > foo", 0, "  > foo", 10)]
+    #[case("  > foo", 4, "foo", 14)]
+    #[case("  > foo", 100, "", 17)]
+    fn test_advance(#[case] text: &str, #[case] n: usize, #[case] rest: &str, #[case] offset: usize) {
+        let mut line = Line::new(text, 10);
+        line.advance(n);
+        assert_eq!(line.rest(), rest);
+        assert_eq!(line.offset(), offset);
+    }
+
+    #[rstest]
+    #[case("    code", 2, "  code")]
+    #[case("  code", 4, "code")]
+    // 탭은 열 단위로 소비하고, 일부만 소비하면 남은 칸은 공백
+    #[case("\tcode", 4, "code")]
+    #[case("\tcode", 2, "  code")]
+    #[case("  \t\tcode", 6, "  code")]
+    fn test_skip_indent(#[case] text: &str, #[case] n: usize, #[case] content: &str) {
+        let mut line = Line::new(text, 0);
+        line.skip_indent(n);
+        assert_eq!(line.content(), content);
+    }
+
+    // Example 6: `>` 뒤 탭은 한 칸만 마커의 일부, 남은 칸은 들여쓰기
+    #[test]
+    fn test_partial_tab_after_marker() {
+        let mut line = Line::new(">\t\tfoo", 0);
+        line.advance(1);
+        line.skip_indent(1);
+        assert_eq!((line.column(), line.indent()), (2, 6));
+        line.skip_indent(4);
+        assert_eq!(line.content(), "  foo");
+    }
+}
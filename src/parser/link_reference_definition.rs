@@ -0,0 +1,306 @@
+//! https://spec.commonmark.org/0.31.2/#link-reference-definitions
+//!
+//! Link Reference Definition은 Paragraph 시작 부분에만 올 수 있으므로,
+//! Paragraph가 닫힐 때 앞쪽 줄들에서 정의를 추출합니다.
+
+use super::inline::link::{parse_link_destination, parse_link_label, parse_link_title, skip_whitespace};
+use crate::node::{BlockNode, LinkReferenceDefinitionNode, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkReferenceDefinitionErr {
+    /// 유효한 link label로 시작하지 않음
+    InvalidLabel,
+    /// label 뒤에 `:` 없음
+    NoColon,
+    /// 유효한 destination 없음
+    InvalidDestination,
+    /// 정의 뒤에 다른 내용이 같은 줄에 있음
+    TrailingContent,
+}
+
+/// 텍스트 시작 위치의 정의 하나 파싱
+/// 반환: (LinkReferenceDefinition 노드, 정의가 끝난 줄의 다음 위치)
+pub fn parse(text: &str) -> Result<(BlockNode, usize), LinkReferenceDefinitionErr> {
+    let (label, label_end) = parse_link_label(text, 0).ok_or(LinkReferenceDefinitionErr::InvalidLabel)?;
+    // label은 공백이 아닌 문자를 하나 이상 포함해야 함
+    if label.trim().is_empty() {
+        return Err(LinkReferenceDefinitionErr::InvalidLabel);
+    }
+    if !text[label_end..].starts_with(':') {
+        return Err(LinkReferenceDefinitionErr::NoColon);
+    }
+
+    let dest_start = skip_whitespace(text, label_end + 1);
+    let (destination, dest_end) =
+        parse_link_destination(text, dest_start).ok_or(LinkReferenceDefinitionErr::InvalidDestination)?;
+
+    // title은 destination과 공백으로 구분되어야 하고, 뒤에는 줄 끝까지 공백만 허용
+    let title_start = skip_whitespace(text, dest_end);
+    if title_start > dest_end
+        && let Some((title, title_end)) = parse_link_title(text, title_start)
+        && let Some(end) = line_end_after(text, title_end)
+    {
+        let node = BlockNode::LinkReferenceDefinition(LinkReferenceDefinitionNode::new(label, &destination, Some(&title)));
+        return Ok((node, end));
+    }
+
+    // title이 없거나 유효하지 않으면 destination까지만 정의 (Example 210)
+    let end = line_end_after(text, dest_end).ok_or(LinkReferenceDefinitionErr::TrailingContent)?;
+    let node = BlockNode::LinkReferenceDefinition(LinkReferenceDefinitionNode::new(label, &destination, None));
+    Ok((node, end))
+}
+
+/// 텍스트 앞부분에서 연속된 정의를 모두 추출
+/// 반환: (정의 노드들, 정의가 아닌 나머지 텍스트)
+/// 정의 노드의 위치는 text 기준 (마지막 줄의 끝 공백과 줄바꿈 제외)
+pub fn extract(text: &str) -> (Vec<BlockNode>, &str) {
+    let mut nodes = Vec::new();
+    let mut rest = text;
+
+    while let Ok((node, end)) = parse(rest) {
+        let start = text.len() - rest.len();
+        let span = Span::from_offsets(start, start + rest[..end].trim_end().len());
+        nodes.push(node.with_span(span));
+        rest = &rest[end..];
+    }
+
+    (nodes, rest)
+}
+
+/// pos 이후 줄 끝까지 공백/탭만 있으면 다음 줄 시작 위치 반환
+fn line_end_after(text: &str, pos: usize) -> Option<usize> {
+    let rest = &text[pos..];
+    let line_len = rest.find('\n').unwrap_or(rest.len());
+    if !rest[..line_len].trim_matches([' ', '\t']).is_empty() {
+        return None;
+    }
+    Some((pos + line_len + 1).min(text.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{BlockNode, InlineNode};
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    // Example 192: 기본 정의
+    #[case(
+        "[foo]: /url \"title\"\n\n[foo]",
+        vec![
+            BlockNode::link_reference_definition("foo", "/url", Some("title")),
+            BlockNode::paragraph(vec![InlineNode::link("/url", Some("title"), vec![InlineNode::text("foo")])]),
+        ]
+    )]
+    // Example 193: 여러 줄에 걸친 정의
+    #[case(
+        "   [foo]: \n      /url  \n           'the title'  \n\n[foo]",
+        vec![
+            BlockNode::link_reference_definition("foo", "/url", Some("the title")),
+            BlockNode::paragraph(vec![InlineNode::link("/url", Some("the title"), vec![InlineNode::text("foo")])]),
+        ]
+    )]
+    // Example 195: <> destination과 다음 줄 title
+    #[case(
+        "[Foo bar]:\n<my url>\n'title'\n\n[Foo bar]",
+        vec![
+            BlockNode::link_reference_definition("Foo bar", "my url", Some("title")),
+            BlockNode::paragraph(vec![InlineNode::link("my url", Some("title"), vec![InlineNode::text("Foo bar")])]),
+        ]
+    )]
+    // Example 196: 여러 줄 title
+    #[case(
+        "[foo]: /url '\ntitle\nline1\nline2\n'\n\n[foo]",
+        vec![
+            BlockNode::link_reference_definition("foo", "/url", Some("\ntitle\nline1\nline2\n")),
+            BlockNode::paragraph(vec![InlineNode::link(
+                "/url",
+                Some("\ntitle\nline1\nline2\n"),
+                vec![InlineNode::text("foo")],
+            )]),
+        ]
+    )]
+    // Example 197: title에 빈 줄 불가
+    #[case(
+        "[foo]: /url 'title\n\nwith blank line'\n\n[foo]",
+        vec![
+            BlockNode::paragraph(vec![InlineNode::text("[foo]: /url 'title")]),
+            BlockNode::paragraph(vec![InlineNode::text("with blank line'")]),
+            BlockNode::paragraph(vec![InlineNode::text("[foo]")]),
+        ]
+    )]
+    // Example 198: 다음 줄 destination
+    #[case(
+        "[foo]:\n/url\n\n[foo]",
+        vec![
+            BlockNode::link_reference_definition("foo", "/url", None),
+            BlockNode::paragraph(vec![InlineNode::link("/url", None, vec![InlineNode::text("foo")])]),
+        ]
+    )]
+    // Example 199: destination 없음
+    #[case(
+        "[foo]:\n\n[foo]",
+        vec![
+            BlockNode::paragraph(vec![InlineNode::text("[foo]:")]),
+            BlockNode::paragraph(vec![InlineNode::text("[foo]")]),
+        ]
+    )]
+    // Example 200: 빈 destination
+    #[case(
+        "[foo]: <>\n\n[foo]",
+        vec![
+            BlockNode::link_reference_definition("foo", "", None),
+            BlockNode::paragraph(vec![InlineNode::link("", None, vec![InlineNode::text("foo")])]),
+        ]
+    )]
+    // Example 201: destination과 title 사이 공백 필요
+    #[case(
+        "[foo]: <bar>(baz)\n\n[foo]",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("[foo]: "),
+                InlineNode::raw_html("<bar>"),
+                InlineNode::text("(baz)"),
+            ]),
+            BlockNode::paragraph(vec![InlineNode::text("[foo]")]),
+        ]
+    )]
+    // Example 203: 정의가 사용보다 뒤에 있어도 됨
+    #[case(
+        "[foo]\n\n[foo]: url",
+        vec![
+            BlockNode::paragraph(vec![InlineNode::link("url", None, vec![InlineNode::text("foo")])]),
+            BlockNode::link_reference_definition("foo", "url", None),
+        ]
+    )]
+    // Example 204: 먼저 나온 정의 우선
+    #[case(
+        "[foo]\n\n[foo]: first\n[foo]: second",
+        vec![
+            BlockNode::paragraph(vec![InlineNode::link("first", None, vec![InlineNode::text("foo")])]),
+            BlockNode::link_reference_definition("foo", "first", None),
+            BlockNode::link_reference_definition("foo", "second", None),
+        ]
+    )]
+    // Example 205: 대소문자 무시
+    #[case(
+        "[FOO]: /url\n\n[Foo]",
+        vec![
+            BlockNode::link_reference_definition("FOO", "/url", None),
+            BlockNode::paragraph(vec![InlineNode::link("/url", None, vec![InlineNode::text("Foo")])]),
+        ]
+    )]
+    // Example 206: Unicode 대소문자 무시
+    #[case(
+        "[ΑΓΩ]: /φου\n\n[αγω]",
+        vec![
+            BlockNode::link_reference_definition("ΑΓΩ", "/φου", None),
+            BlockNode::paragraph(vec![InlineNode::link("/φου", None, vec![InlineNode::text("αγω")])]),
+        ]
+    )]
+    // Example 207: 정의만 있는 문서
+    #[case("[foo]: /url", vec![BlockNode::link_reference_definition("foo", "/url", None)])]
+    // Example 208: 여러 줄 label
+    #[case(
+        "[\nfoo\n]: /url\nbar",
+        vec![
+            BlockNode::link_reference_definition("\nfoo\n", "/url", None),
+            BlockNode::paragraph(vec![InlineNode::text("bar")]),
+        ]
+    )]
+    // Example 209: title 뒤에 다른 내용
+    #[case("[foo]: /url \"title\" ok", vec![BlockNode::paragraph(vec![InlineNode::text("[foo]: /url \"title\" ok")])])]
+    // Example 210: 다음 줄 title이 유효하지 않으면 title 없는 정의
+    #[case(
+        "[foo]: /url\n\"title\" ok",
+        vec![
+            BlockNode::link_reference_definition("foo", "/url", None),
+            BlockNode::paragraph(vec![InlineNode::text("\"title\" ok")]),
+        ]
+    )]
+    // Example 211: 들여쓰기 코드 블록은 정의 아님
+    #[case(
+        "    [foo]: /url \"title\"\n\n[foo]",
+        vec![
+            BlockNode::code_block(None, "[foo]: /url \"title\""),
+            BlockNode::paragraph(vec![InlineNode::text("[foo]")]),
+        ]
+    )]
+    // Example 212: 코드 블록 안은 정의 아님
+    #[case(
+        "```\n[foo]: /url\n```\n\n[foo]",
+        vec![BlockNode::code_block(None, "[foo]: /url"), BlockNode::paragraph(vec![InlineNode::text("[foo]")])]
+    )]
+    // Example 213: 정의는 Paragraph를 인터럽트할 수 없음
+    #[case(
+        "Foo\n[bar]: /baz\n\n[bar]",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("Foo"),
+                InlineNode::soft_break(),
+                InlineNode::text("[bar]: /baz"),
+            ]),
+            BlockNode::paragraph(vec![InlineNode::text("[bar]")]),
+        ]
+    )]
+    // Example 214: 다른 블록 사이의 정의
+    #[case(
+        "# [Foo]\n[foo]: /url\n> bar",
+        vec![
+            BlockNode::heading(1, vec![InlineNode::link("/url", None, vec![InlineNode::text("Foo")])]),
+            BlockNode::link_reference_definition("foo", "/url", None),
+            BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar")])]),
+        ]
+    )]
+    // Example 215: 정의 뒤의 Setext Heading
+    #[case(
+        "[foo]: /url\nbar\n===\n[foo]",
+        vec![
+            BlockNode::link_reference_definition("foo", "/url", None),
+            BlockNode::heading(1, vec![InlineNode::text("bar")]),
+            BlockNode::paragraph(vec![InlineNode::link("/url", None, vec![InlineNode::text("foo")])]),
+        ]
+    )]
+    // Example 216: 정의만 있으면 Setext 밑줄이 아님
+    #[case(
+        "[foo]: /url\n===\n[foo]",
+        vec![
+            BlockNode::link_reference_definition("foo", "/url", None),
+            BlockNode::paragraph(vec![
+                InlineNode::text("==="),
+                InlineNode::soft_break(),
+                InlineNode::link("/url", None, vec![InlineNode::text("foo")]),
+            ]),
+        ]
+    )]
+    // Example 217: 연속된 정의
+    #[case(
+        "[foo]: /foo-url \"foo\"\n[bar]: /bar-url\n  \"bar\"\n[baz]: /baz-url\n\n[foo],\n[bar],\n[baz]",
+        vec![
+            BlockNode::link_reference_definition("foo", "/foo-url", Some("foo")),
+            BlockNode::link_reference_definition("bar", "/bar-url", Some("bar")),
+            BlockNode::link_reference_definition("baz", "/baz-url", None),
+            BlockNode::paragraph(vec![
+                InlineNode::link("/foo-url", Some("foo"), vec![InlineNode::text("foo")]),
+                InlineNode::text(","),
+                InlineNode::soft_break(),
+                InlineNode::link("/bar-url", Some("bar"), vec![InlineNode::text("bar")]),
+                InlineNode::text(","),
+                InlineNode::soft_break(),
+                InlineNode::link("/baz-url", None, vec![InlineNode::text("baz")]),
+            ]),
+        ]
+    )]
+    // Example 218: Blockquote 안의 정의도 문서 전체에 적용
+    #[case(
+        "[foo]\n\n> [foo]: /url",
+        vec![
+            BlockNode::paragraph(vec![InlineNode::link("/url", None, vec![InlineNode::text("foo")])]),
+            BlockNode::blockquote(vec![BlockNode::link_reference_definition("foo", "/url", None)]),
+        ]
+    )]
+    fn test_link_reference_definition(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        let doc = parse(input).without_spans();
+        assert_eq!(doc.children, expected);
+    }
+}
@@ -8,6 +8,7 @@
 mod tests {
     use crate::node::{BlockNode, InlineNode, ListItemNode};
     use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
     use rstest::rstest;
 
     #[rstest]
@@ -35,7 +36,7 @@ mod tests {
     // Example 269: 음수는 마커 아님 → paragraph
     #[case("-1. not ok", vec![BlockNode::paragraph(vec![InlineNode::text("-1. not ok")])])]
     // Continuation line
-    #[case("- line1\n  line2\n  line3", vec![BlockNode::bullet_list(true, vec![ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("line1\nline2\nline3")])])])])]
+    #[case("- line1\n  line2\n  line3", vec![BlockNode::bullet_list(true, vec![ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("line1"), InlineNode::soft_break(), InlineNode::text("line2"), InlineNode::soft_break(), InlineNode::text("line3")])])])])]
     // Example 255: 들여쓰기 부족 (1칸) → 리스트 종료
     #[case("- one\n\n two", vec![
         BlockNode::bullet_list(true, vec![ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("one")])])]),
@@ -60,12 +61,11 @@ mod tests {
         ])
     ])]
     // Example 278: 빈 줄로 시작하는 아이템
-    // NOTE: 세 번째 아이템 코드 블록은 명세상 "baz"지만 현재 " baz" (향후 개선)
     #[case("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz", vec![
         BlockNode::bullet_list(true, vec![
             ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("foo")])]),
             ListItemNode::new(vec![BlockNode::code_block(None, "bar")]),
-            ListItemNode::new(vec![BlockNode::code_block(None, " baz")]),
+            ListItemNode::new(vec![BlockNode::code_block(None, "baz")]),
         ])
     ])]
     // Example 281: 중간 빈 아이템 (bullet)
@@ -167,13 +167,12 @@ mod tests {
             ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("g")])]),
         ])
     ])]
-    // Example 297: 3단계 중첩 + 빈 줄 후 추가 단락
-    // NOTE: 명세상 외부는 tight지만 현재 모두 loose (향후 개선)
+    // Example 297: 3단계 중첩 + 빈 줄 후 추가 단락 (빈 줄은 가장 안쪽 리스트만 loose로 만듦)
     #[case("- foo\n  - bar\n    - baz\n\n\n      bim", vec![
-        BlockNode::bullet_list(false, vec![
+        BlockNode::bullet_list(true, vec![
             ListItemNode::new(vec![
                 BlockNode::paragraph(vec![InlineNode::text("foo")]),
-                BlockNode::bullet_list(false, vec![
+                BlockNode::bullet_list(true, vec![
                     ListItemNode::new(vec![
                         BlockNode::paragraph(vec![InlineNode::text("bar")]),
                         BlockNode::bullet_list(false, vec![
@@ -193,11 +192,119 @@ mod tests {
             ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("a")])]),
             ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("b")])]),
             ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("c")])]),
-            ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("d\n- e")])]),
+            ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("d"), InlineNode::soft_break(), InlineNode::text("- e")])]),
+        ])
+    ])]
+    // Example 292: blockquote 안 리스트 안 blockquote + lazy continuation
+    #[case("> 1. > Blockquote\ncontinued here.", vec![
+        BlockNode::blockquote(vec![
+            BlockNode::ordered_list('.', 1, true, vec![
+                ListItemNode::new(vec![BlockNode::blockquote(vec![
+                    BlockNode::paragraph(vec![InlineNode::text("Blockquote"), InlineNode::soft_break(), InlineNode::text("continued here.")]),
+                ])]),
+            ]),
+        ])
+    ])]
+    // Example 304: 1이 아닌 번호는 paragraph를 인터럽트하지 않음
+    #[case("The number of windows in my house is\n14.  The number of doors is 6.", vec![
+        BlockNode::paragraph(vec![InlineNode::text("The number of windows in my house is"), InlineNode::soft_break(), InlineNode::text("14.  The number of doors is 6.")]),
+    ])]
+    // Example 313: 빈 줄 뒤 4칸 들여쓴 마커는 indented code
+    #[case("1. a\n\n  2. b\n\n    3. c", vec![
+        BlockNode::ordered_list('.', 1, false, vec![
+            ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("a")])]),
+            ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("b")])]),
+        ]),
+        BlockNode::code_block(None, "3. c"),
+    ])]
+    // Example 318: fenced code block 안의 빈 줄은 loose로 만들지 않음
+    #[case("- a\n- ```\n  b\n\n\n  ```\n- c", vec![
+        BlockNode::bullet_list(true, vec![
+            ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("a")])]),
+            ListItemNode::new(vec![BlockNode::code_block(None, "b\n\n")]),
+            ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("c")])]),
+        ])
+    ])]
+    // =========================================================================
+    // Task list (GFM)
+    // =========================================================================
+    // GFM Example 279: 마커와 뒤 공백은 내용이 아님
+    #[case("- [ ] foo\n- [x] bar", vec![
+        BlockNode::bullet_list(true, vec![
+            ListItemNode::task(false, vec![BlockNode::paragraph(vec![InlineNode::text("foo")])]),
+            ListItemNode::task(true, vec![BlockNode::paragraph(vec![InlineNode::text("bar")])]),
+        ])
+    ])]
+    // GFM Example 280: 중첩 task list
+    #[case("- [x] foo\n  - [ ] bar\n  - [x] baz\n- [ ] bim", vec![
+        BlockNode::bullet_list(true, vec![
+            ListItemNode::task(true, vec![
+                BlockNode::paragraph(vec![InlineNode::text("foo")]),
+                BlockNode::bullet_list(true, vec![
+                    ListItemNode::task(false, vec![BlockNode::paragraph(vec![InlineNode::text("bar")])]),
+                    ListItemNode::task(true, vec![BlockNode::paragraph(vec![InlineNode::text("baz")])]),
+                ]),
+            ]),
+            ListItemNode::task(false, vec![BlockNode::paragraph(vec![InlineNode::text("bim")])]),
+        ])
+    ])]
+    // 마커 뒤 공백이 길어도 indented code가 아님
+    #[case("1. [X]      done", vec![
+        BlockNode::ordered_list('.', 1, true, vec![
+            ListItemNode::task(true, vec![BlockNode::paragraph(vec![InlineNode::text("done")])]),
+        ])
+    ])]
+    // 첫 줄이 아닌 곳의 `[ ]`는 task 마커가 아님
+    #[case("-\n  [ ] foo", vec![
+        BlockNode::bullet_list(true, vec![
+            ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("[ ] foo")])]),
         ])
     ])]
     fn test_list(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
-        let doc = parse(input);
+        let doc = parse(input).without_spans();
         assert_eq!(doc.children, expected);
     }
+
+    #[rstest]
+    // 아이템은 마커부터 마지막 내용 줄까지, 아이템 내용은 마커와 들여쓰기를 뗀 원문 위치
+    #[case("- a\n  b\n- c", ((1, 1), (3, 4)), vec![
+        (((1, 1), (2, 4)), vec![((1, 3), (2, 4))]),
+        (((3, 1), (3, 4)), vec![((3, 3), (3, 4))]),
+    ])]
+    // 빈 줄 뒤 내용과 중첩 리스트
+    #[case("1. a\n\n   - b\n     c", ((1, 1), (4, 7)), vec![
+        (((1, 1), (4, 7)), vec![((1, 4), (1, 5)), ((3, 4), (4, 7))]),
+    ])]
+    // 아이템 안의 blockquote
+    #[case("- > q\n  > r\n- s", ((1, 1), (3, 4)), vec![
+        (((1, 1), (2, 6)), vec![((1, 3), (2, 6))]),
+        (((3, 1), (3, 4)), vec![((3, 3), (3, 4))]),
+    ])]
+    // Example 312: 4칸 들여쓴 마커는 lazy continuation
+    #[case("- a\n - b\n  - c\n   - d\n    - e", ((1, 1), (5, 8)), vec![
+        (((1, 1), (1, 4)), vec![((1, 3), (1, 4))]),
+        (((2, 2), (2, 5)), vec![((2, 4), (2, 5))]),
+        (((3, 3), (3, 6)), vec![((3, 5), (3, 6))]),
+        (((4, 4), (5, 8)), vec![((4, 6), (5, 8))]),
+    ])]
+    fn test_list_span(
+        #[case] input: &str,
+        #[case] list: LineColumns,
+        #[case] items: Vec<(LineColumns, Vec<LineColumns>)>,
+    ) {
+        let doc = parse(input);
+        let Some(BlockNode::List(node)) = doc.children.first() else {
+            panic!("첫 블록이 List여야 함");
+        };
+        assert_eq!(line_columns(node.span), list);
+        let actual: Vec<(LineColumns, Vec<LineColumns>)> = node
+            .children
+            .iter()
+            .map(|item| {
+                let children = item.children.iter().map(|child| line_columns(child.span())).collect();
+                (line_columns(item.span), children)
+            })
+            .collect();
+        assert_eq!(actual, items);
+    }
 }
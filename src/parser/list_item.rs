@@ -4,9 +4,10 @@
 //! - 마커 인식 규칙 (Example 261, 265-269)
 //! - 들여쓰기 규칙
 //! - Continuation line 판별
+//! - Task list 마커 인식 (GFM `[ ]`, `[x]`)
 
 use crate::node::ListType;
-use super::helpers::count_leading_char;
+use super::helpers::{calculate_indent, remove_indent};
 
 // =============================================================================
 // 타입 정의
@@ -65,17 +66,19 @@ pub struct ListItemStart {
     pub content_indent: usize,
     /// 첫 줄 내용 (마커 이후)
     pub content: String,
+    /// Task list 체크 상태 (첫 줄 내용이 `[ ]`, `[x]`, `[X]`와 공백으로 시작하면 Some)
+    pub checked: Option<bool>,
 }
 
 impl ListItemStart {
-    /// 라인에서 content를 추출하여 새 인스턴스 반환
-    pub fn with_content_from(self, line: &str) -> Self {
-        let content = if self.content_indent >= line.len() {
-            String::new()
-        } else {
-            line[self.content_indent..].to_string()
+    /// Paragraph를 인터럽트할 수 있는지
+    /// 빈 아이템이나 1이 아닌 번호로 시작하는 아이템은 불가 (Example 304)
+    pub fn can_interrupt_paragraph(&self) -> bool {
+        let starts_at_one = match self.marker {
+            ListMarker::Bullet(_) => true,
+            ListMarker::Ordered { start, .. } => start == 1,
         };
-        Self { content, ..self }
+        starts_at_one && !self.content.trim().is_empty()
     }
 
     #[cfg(test)]
@@ -85,6 +88,7 @@ impl ListItemStart {
             indent,
             content_indent,
             content: content.to_string(),
+            checked: None,
         }
     }
 
@@ -101,8 +105,14 @@ impl ListItemStart {
             indent,
             content_indent,
             content: content.to_string(),
+            checked: None,
         }
     }
+
+    #[cfg(test)]
+    pub fn task(self, checked: bool) -> Self {
+        Self { checked: Some(checked), ..self }
+    }
 }
 
 /// List Item 시작 성공 사유
@@ -121,59 +131,22 @@ pub enum ListItemNotStartReason {
     NotListMarker,
 }
 
-/// List 종료 사유
-#[derive(Debug, Clone, PartialEq)]
-pub enum ListEndReason {
-    /// 줄 다시 처리 필요 (다른 블록/새 리스트)
-    Reprocess,
-}
-
-/// 리스트 아이템 내용 줄
+/// List Item 계속 사유
 #[derive(Debug, Clone, PartialEq)]
-pub struct ItemLine {
-    /// 내용
-    pub content: String,
-    /// true면 텍스트 전용 (리스트 마커처럼 보여도 재파싱 시 리스트 아님)
-    /// Example 303: 4칸 들여쓰기된 마커는 텍스트 전용
-    pub text_only: bool,
-}
-
-impl ItemLine {
-    pub fn new(content: String, text_only: bool) -> Self {
-        Self { content, text_only }
-    }
-
-    pub fn text(content: String) -> Self {
-        Self {
-            content,
-            text_only: false,
-        }
-    }
-
-    pub fn text_only(content: String) -> Self {
-        Self {
-            content,
-            text_only: true,
-        }
-    }
-
-    pub fn blank() -> Self {
-        Self {
-            content: String::new(),
-            text_only: false,
-        }
-    }
+pub enum ListItemContinueReason {
+    /// 빈 줄 (내용이 있는 아이템은 빈 줄에서 끝나지 않음)
+    Blank,
+    /// 내용 시작 위치 이상 들여쓴 줄
+    Indented,
 }
 
-/// List 계속 사유
+/// List Item 종료 사유
 #[derive(Debug, Clone, PartialEq)]
-pub enum ListContinueReason {
-    /// 빈 줄 (pending_blank 설정)
-    Blank,
-    /// 새 아이템
-    NewItem(ListItemStart),
-    /// Continuation line (같은 아이템에 내용 추가)
-    ContinuationLine(ItemLine),
+pub enum ListItemEndReason {
+    /// 빈 아이템 다음의 빈 줄 (아이템은 빈 줄 하나로만 시작할 수 있음, Example 280)
+    BlankAfterEmptyItem,
+    /// 내용 시작 위치보다 적게 들여쓴 줄
+    InsufficientIndent,
 }
 
 // =============================================================================
@@ -182,92 +155,55 @@ pub enum ListContinueReason {
 
 /// List Item 시작 줄인지 확인
 /// 성공 시 Ok(Started), 실패 시 Err(사유) 반환
-pub(crate) fn try_start(line: &str) -> Result<ListItemStartReason, ListItemNotStartReason> {
-    let indent = count_leading_char(line, ' ');
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+pub(crate) fn try_start(line: &str, column: usize) -> Result<ListItemStartReason, ListItemNotStartReason> {
+    let indent = calculate_indent(line, column);
 
     // 4칸 이상 들여쓰기는 코드 블록
     if indent > 3 {
         return Err(ListItemNotStartReason::CodeBlockIndented);
     }
 
-    let after_indent = &line[indent..];
+    let after_indent = line.trim_start_matches([' ', '\t']);
 
-    // Bullet 또는 Ordered 마커 시도 → content 추출
-    try_bullet_marker(after_indent, indent)
-        .or_else(|| try_ordered_marker(after_indent, indent))
-        .map(|start| ListItemStartReason::Started(start.with_content_from(line)))
+    // Bullet 또는 Ordered 마커 시도 → Task list 마커 확인
+    try_bullet_marker(after_indent, indent, column)
+        .or_else(|| try_ordered_marker(after_indent, indent, column))
+        .map(|start| ListItemStartReason::Started(ListItemStart { checked: task_marker(&start.content), ..start }))
         .ok_or(ListItemNotStartReason::NotListMarker)
 }
 
-/// List 종료 여부 확인
-/// Ok: 종료 (Reprocess)
-/// Err: 계속 (Blank, NewItem 또는 ContinuationLine)
+/// 열린 List Item이 줄을 이어 받는지 확인
+/// 성공 시 Ok(계속 사유), 실패 시 Err(종료 사유) 반환
 ///
 /// # Arguments
-/// * `first_content_indent` - 첫 아이템의 content_indent (continuation 판단용)
-/// * `current_content_indent` - 현재 아이템의 content_indent (새 아이템 판단용)
+/// * `column` - 줄이 시작하는 열 (탭 너비 계산용)
+/// * `content_indent` - 아이템 내용 시작 위치 (마커 앞 들여쓰기 + 마커 + 뒤 공백)
+/// * `has_content` - 아이템에 내용 블록이 있는지
 ///
-/// Example 301: 새 아이템 판단은 current_content_indent 기준 (0-3칸은 같은 레벨)
-/// Example 303: continuation 판단은 first_content_indent 기준 (4칸 이상은 내용)
-pub(crate) fn try_end(
+/// 새 아이템이나 다른 블록의 시작 여부는 호출자가 이어서 판단
+pub(crate) fn try_continue(
     line: &str,
-    marker: &ListMarker,
-    first_content_indent: usize,
-    current_content_indent: usize,
-) -> Result<ListEndReason, ListContinueReason> {
-    // 빈 줄 처리 (항상 계속, 개수는 호출자가 추적)
+    column: usize,
+    content_indent: usize,
+    has_content: bool,
+) -> Result<ListItemContinueReason, ListItemEndReason> {
     if line.trim().is_empty() {
-        return Err(ListContinueReason::Blank);
-    }
-
-    let indent = count_leading_char(line, ' ');
-
-    // 1. 새 아이템 체크 (Example 301 지원)
-    // current_content_indent 미만 들여쓰기에서만 새 아이템 가능
-    if indent < current_content_indent {
-        // 같은 마커 타입의 List Item이면 새 아이템으로 계속
-        match try_start(line) {
-            Ok(ListItemStartReason::Started(new_start)) => {
-                if marker.is_same_type(&new_start.marker) {
-                    return Err(ListContinueReason::NewItem(new_start));
-                }
-                // 다른 마커 타입이면 리스트 종료
-                return Ok(ListEndReason::Reprocess);
-            }
-            Err(ListItemNotStartReason::CodeBlockIndented) => {
-                // 4칸 이상 들여쓰기 → 리스트 마커로 인식 안 됨
-                // 하지만 first_content_indent 이상이면 텍스트 전용 continuation
-                if indent >= first_content_indent {
-                    let strip_amount = indent.min(current_content_indent);
-                    let content = line[strip_amount..].to_string();
-                    // text_only: 재파싱 시 리스트로 인식 안 됨
-                    return Err(ListContinueReason::ContinuationLine(ItemLine::text_only(content)));
-                }
-            }
-            Err(ListItemNotStartReason::NotListMarker) => {
-                // 리스트 마커가 아니면 continuation으로 넘어감
-            }
+        if has_content {
+            return Ok(ListItemContinueReason::Blank);
         }
+        return Err(ListItemEndReason::BlankAfterEmptyItem);
     }
 
-    // 2. Continuation line (Example 303 지원)
-    // first_content_indent 이상 들여쓰기는 현재 아이템의 내용
-    if indent >= first_content_indent {
-        // 내용 추출: min(indent, current_content_indent)만큼 제거
-        // 예: current_content_indent=5이고 indent=4이면 4칸 제거
-        // 예: current_content_indent=5이고 indent=6이면 5칸 제거
-        let strip_amount = indent.min(current_content_indent);
-        let content = line[strip_amount..].to_string();
-        // 일반 continuation (중첩 리스트 가능)
-        return Err(ListContinueReason::ContinuationLine(ItemLine::text(content)));
+    if calculate_indent(line, column) >= content_indent {
+        return Ok(ListItemContinueReason::Indented);
     }
 
-    // 3. first_content_indent 미만 들여쓰기 + 새 아이템 아님 → 종료
-    Ok(ListEndReason::Reprocess)
+    Err(ListItemEndReason::InsufficientIndent)
 }
 
 /// Bullet 마커 감지 (-*+)
-fn try_bullet_marker(s: &str, indent: usize) -> Option<ListItemStart> {
+fn try_bullet_marker(s: &str, indent: usize, column: usize) -> Option<ListItemStart> {
     let first_char = s.chars().next()?;
 
     // Bullet 마커 문자인지 확인
@@ -275,38 +211,25 @@ fn try_bullet_marker(s: &str, indent: usize) -> Option<ListItemStart> {
         return None;
     }
 
-    // 마커 뒤 공백 확인 (최소 1칸)
+    // 마커 뒤는 줄 끝(빈 아이템)이거나 공백이어야 함
     let rest = &s[1..];
-    if rest.is_empty() {
-        // 마커만 있고 끝 → 빈 아이템으로 허용
-        return Some(ListItemStart {
-            marker: ListMarker::Bullet(first_char),
-            indent,
-            content_indent: indent + 1,
-            content: String::new(), // try_start에서 채워짐
-        });
-    }
-
-    // 마커 뒤 첫 문자가 공백이어야 함
-    let after_marker = rest.chars().next()?;
-    if after_marker != ' ' && after_marker != '\t' {
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
         return None;
     }
 
-    // 내용 시작 위치 계산 (마커 + 공백)
-    let spaces_after_marker = count_leading_char(rest, ' ');
-    let content_indent = indent + 1 + spaces_after_marker.min(4); // 최대 4칸까지만
-
+    let marker_end = column + indent + 1;
+    let padding = marker_padding(rest, marker_end);
     Some(ListItemStart {
         marker: ListMarker::Bullet(first_char),
         indent,
-        content_indent,
-        content: String::new(), // try_start에서 채워짐
+        content_indent: indent + 1 + padding,
+        content: remove_indent(rest, marker_end, padding).into_owned(),
+        checked: None,
     })
 }
 
 /// Ordered 마커 감지 (숫자 + . 또는 ))
-fn try_ordered_marker(s: &str, indent: usize) -> Option<ListItemStart> {
+fn try_ordered_marker(s: &str, indent: usize, column: usize) -> Option<ListItemStart> {
     // 숫자 추출
     let num_str: String = s.chars().take_while(|c| c.is_ascii_digit()).collect();
 
@@ -326,32 +249,15 @@ fn try_ordered_marker(s: &str, indent: usize) -> Option<ListItemStart> {
         return None;
     }
 
-    // 구분자 뒤 공백 확인
+    // 구분자 뒤는 줄 끝(빈 아이템)이거나 공백이어야 함
     let after_delimiter = &rest[1..];
-    if after_delimiter.is_empty() {
-        // 구분자만 있고 끝 → 빈 아이템
-        let marker_len = num_str.len() + 1; // 숫자 + 구분자
-        return Some(ListItemStart {
-            marker: ListMarker::Ordered {
-                start: start_num,
-                delimiter,
-            },
-            indent,
-            content_indent: indent + marker_len,
-            content: String::new(), // try_start에서 채워짐
-        });
-    }
-
-    // 구분자 뒤 첫 문자가 공백이어야 함
-    let after_char = after_delimiter.chars().next()?;
-    if after_char != ' ' && after_char != '\t' {
+    if !after_delimiter.is_empty() && !after_delimiter.starts_with([' ', '\t']) {
         return None;
     }
 
-    // content_indent 계산
-    let spaces_after_delimiter = count_leading_char(after_delimiter, ' ');
     let marker_len = num_str.len() + 1; // 숫자 + 구분자
-    let content_indent = indent + marker_len + spaces_after_delimiter.min(4);
+    let marker_end = column + indent + marker_len;
+    let padding = marker_padding(after_delimiter, marker_end);
 
     Some(ListItemStart {
         marker: ListMarker::Ordered {
@@ -359,11 +265,41 @@ fn try_ordered_marker(s: &str, indent: usize) -> Option<ListItemStart> {
             delimiter,
         },
         indent,
-        content_indent,
-        content: String::new(), // try_start에서 채워짐
+        content_indent: indent + marker_len + padding,
+        content: remove_indent(after_delimiter, marker_end, padding).into_owned(),
+        checked: None,
     })
 }
 
+/// Task list 마커 길이 (`[ ]`, `[x]`, `[X]`)
+pub(crate) const TASK_MARKER_LEN: usize = 3;
+
+/// 첫 줄 내용 앞의 Task list 마커 인식 (GFM)
+/// 마커 뒤에 공백과 내용이 있어야 함 (`[x]foo`, `[x]`만 있는 줄은 아님)
+fn task_marker(content: &str) -> Option<bool> {
+    let checked = match content.get(..TASK_MARKER_LEN)? {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+    let rest = &content[TASK_MARKER_LEN..];
+    if !rest.starts_with([' ', '\t']) || rest.trim().is_empty() {
+        return None;
+    }
+    Some(checked)
+}
+
+/// 마커 뒤 공백 중 아이템 내용 시작 위치에 포함되는 칸 수 (1~4)
+/// 빈 아이템이거나 공백이 5칸 이상이면 (내용이 indented code) 한 칸만 포함 (Example 273, 278)
+/// column: 마커가 끝나는 열 (탭 너비 계산용, Example 7)
+fn marker_padding(after_marker: &str, column: usize) -> usize {
+    let spaces = calculate_indent(after_marker, column);
+    if after_marker.trim().is_empty() || spaces >= 5 {
+        return 1;
+    }
+    spaces
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -375,6 +311,11 @@ mod tests {
     #[case("-item", Err(ListItemNotStartReason::NotListMarker))]
     #[case("--item", Err(ListItemNotStartReason::NotListMarker))]
     #[case("1.item", Err(ListItemNotStartReason::NotListMarker))]
+    // 빈 아이템은 마커 뒤 공백 수와 무관
+    #[case(
+        "-   ",
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "  ")))
+    )]
     // 기본 Bullet 마커
     #[case(
         "- item",
@@ -414,22 +355,23 @@ mod tests {
         "-    item",
         Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 5, "item")))
     )]
+    // 공백 5칸 이상이면 마커 뒤 한 칸만 (내용은 indented code)
     #[case(
         "-     item",
-        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 5, " item")))
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "    item")))
     )]
-    // Bullet 빈 아이템
+    // Bullet 빈 아이템 (내용 시작은 마커 뒤 한 칸)
     #[case(
         "-",
-        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 1, "")))
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "")))
     )]
     #[case(
         "+",
-        Ok(ListItemStartReason::Started(ListItemStart::bullet('+', 0, 1, "")))
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('+', 0, 2, "")))
     )]
     #[case(
         "*",
-        Ok(ListItemStartReason::Started(ListItemStart::bullet('*', 0, 1, "")))
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('*', 0, 2, "")))
     )]
     // 기본 Ordered 마커
     #[case(
@@ -505,11 +447,50 @@ mod tests {
     // Ordered 빈 아이템
     #[case(
         "1.",
-        Ok(ListItemStartReason::Started(ListItemStart::ordered(1, '.', 0, 2, "")))
+        Ok(ListItemStartReason::Started(ListItemStart::ordered(1, '.', 0, 3, "")))
     )]
     #[case(
         "1)",
-        Ok(ListItemStartReason::Started(ListItemStart::ordered(1, ')', 0, 2, "")))
+        Ok(ListItemStartReason::Started(ListItemStart::ordered(1, ')', 0, 3, "")))
+    )]
+    // 마커 뒤 탭은 다음 탭 정지까지의 칸 (Example 7)
+    #[case(
+        "-\tfoo",
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 4, "foo")))
+    )]
+    #[case(
+        "-\t\tfoo",
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "  \tfoo")))
+    )]
+    #[case(
+        " \t- item",
+        Err(ListItemNotStartReason::CodeBlockIndented)
+    )]
+    // Task list 마커 (GFM Example 279, 280)
+    #[case(
+        "- [ ] foo",
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "[ ] foo").task(false)))
+    )]
+    #[case(
+        "1. [X] bar",
+        Ok(ListItemStartReason::Started(ListItemStart::ordered(1, '.', 0, 3, "[X] bar").task(true)))
+    )]
+    #[case(
+        "- [x]\tbar",
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "[x]\tbar").task(true)))
+    )]
+    // 마커 뒤 공백과 내용이 없으면 Task 아님
+    #[case(
+        "- [x]bar",
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "[x]bar")))
+    )]
+    #[case(
+        "- [ ]",
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "[ ]")))
+    )]
+    #[case(
+        "- [y] foo",
+        Ok(ListItemStartReason::Started(ListItemStart::bullet('-', 0, 2, "[y] foo")))
     )]
     // 에러 케이스
     #[case("    - item", Err(ListItemNotStartReason::CodeBlockIndented))]
@@ -526,71 +507,34 @@ mod tests {
         #[case] input: &str,
         #[case] expected: Result<ListItemStartReason, ListItemNotStartReason>,
     ) {
-        assert_eq!(try_start(input), expected);
+        assert_eq!(try_start(input, 0), expected);
     }
 
-    // 5.2 List Items - 종료/계속 판별 (try_end)
-    // 인자: (line, marker, first_content_indent, current_content_indent, expected)
+    // 5.2 List Items - 계속 판별 (try_continue)
+    // 인자: (line, content_indent, has_content, expected)
     #[rstest]
-    // 빈 줄 → Blank
-    #[case("", ListMarker::Bullet('-'), 2, 2, Err(ListContinueReason::Blank))]
-    #[case("  ", ListMarker::Bullet('-'), 2, 2, Err(ListContinueReason::Blank))]
-    #[case("\t", ListMarker::Bullet('-'), 2, 2, Err(ListContinueReason::Blank))]
-    // 같은 마커 → 새 아이템
-    #[case(
-        "- b",
-        ListMarker::Bullet('-'),
-        2, 2,
-        Err(ListContinueReason::NewItem(ListItemStart::bullet('-', 0, 2, "b")))
-    )]
-    #[case(
-        "+ b",
-        ListMarker::Bullet('+'),
-        2, 2,
-        Err(ListContinueReason::NewItem(ListItemStart::bullet('+', 0, 2, "b")))
-    )]
-    #[case(
-        "* b",
-        ListMarker::Bullet('*'),
-        2, 2,
-        Err(ListContinueReason::NewItem(ListItemStart::bullet('*', 0, 2, "b")))
-    )]
-    #[case("2. b", ListMarker::Ordered { start: 1, delimiter: '.' }, 3, 3, Err(ListContinueReason::NewItem(ListItemStart::ordered(2, '.', 0, 3, "b"))))]
-    #[case("2) b", ListMarker::Ordered { start: 1, delimiter: ')' }, 3, 3, Err(ListContinueReason::NewItem(ListItemStart::ordered(2, ')', 0, 3, "b"))))]
-    // Continuation line (text_only=false: 일반 continuation)
-    #[case("  continued", ListMarker::Bullet('-'), 2, 2, Err(ListContinueReason::ContinuationLine(ItemLine::text("continued".to_string()))))]
-    #[case("   continued", ListMarker::Bullet('-'), 2, 2, Err(ListContinueReason::ContinuationLine(ItemLine::text(" continued".to_string()))))]
-    #[case("    continued", ListMarker::Bullet('-'), 2, 2, Err(ListContinueReason::ContinuationLine(ItemLine::text("  continued".to_string()))))]
-    // 리스트 종료 (다른 마커)
-    #[case("+ b", ListMarker::Bullet('-'), 2, 2, Ok(ListEndReason::Reprocess))]
-    #[case("* b", ListMarker::Bullet('-'), 2, 2, Ok(ListEndReason::Reprocess))]
-    #[case("- b", ListMarker::Bullet('+'), 2, 2, Ok(ListEndReason::Reprocess))]
-    #[case("1) b", ListMarker::Ordered { start: 1, delimiter: '.' }, 3, 3, Ok(ListEndReason::Reprocess))]
-    #[case("1. b", ListMarker::Ordered { start: 1, delimiter: ')' }, 3, 3, Ok(ListEndReason::Reprocess))]
-    #[case("1. b", ListMarker::Bullet('-'), 2, 2, Ok(ListEndReason::Reprocess))]
-    #[case("- b", ListMarker::Ordered { start: 1, delimiter: '.' }, 3, 3, Ok(ListEndReason::Reprocess))]
-    // 리스트 종료 (비리스트 내용)
-    #[case("some text", ListMarker::Bullet('-'), 2, 2, Ok(ListEndReason::Reprocess))]
-    #[case("# heading", ListMarker::Bullet('-'), 2, 2, Ok(ListEndReason::Reprocess))]
-    #[case(
-        "> blockquote",
-        ListMarker::Bullet('-'),
-        2, 2,
-        Ok(ListEndReason::Reprocess)
-    )]
-    #[case("```code", ListMarker::Bullet('-'), 2, 2, Ok(ListEndReason::Reprocess))]
-    // Example 303: 4칸 들여쓰기된 마커는 continuation (first=2, current=5)
-    // "   - d"의 content_indent=5, "    - e"(4칸)는 continuation이어야 함
-    // 내용: min(4, 5)=4칸 제거 → "- e", text_only=true (리스트 마커 아님)
-    #[case("    - e", ListMarker::Bullet('-'), 2, 5, Err(ListContinueReason::ContinuationLine(ItemLine::text_only("- e".to_string()))))]
-    fn test_try_end(
+    // 빈 줄: 내용이 있으면 계속, 빈 아이템이면 종료 (Example 280)
+    #[case("", 2, true, Ok(ListItemContinueReason::Blank))]
+    #[case("  ", 2, true, Ok(ListItemContinueReason::Blank))]
+    #[case("\t", 2, true, Ok(ListItemContinueReason::Blank))]
+    #[case("", 2, false, Err(ListItemEndReason::BlankAfterEmptyItem))]
+    // content_indent 이상 들여쓰기 → 계속
+    #[case("  continued", 2, true, Ok(ListItemContinueReason::Indented))]
+    #[case("    continued", 2, true, Ok(ListItemContinueReason::Indented))]
+    #[case("  foo", 2, false, Ok(ListItemContinueReason::Indented))]
+    #[case("   - b", 3, true, Ok(ListItemContinueReason::Indented))]
+    // content_indent 미만 → 종료 (새 아이템, lazy continuation 여부는 호출자가 판단)
+    #[case(" continued", 2, true, Err(ListItemEndReason::InsufficientIndent))]
+    #[case("- b", 2, true, Err(ListItemEndReason::InsufficientIndent))]
+    #[case("2. b", 3, true, Err(ListItemEndReason::InsufficientIndent))]
+    #[case("    - e", 5, true, Err(ListItemEndReason::InsufficientIndent))]
+    fn test_try_continue(
         #[case] line: &str,
-        #[case] marker: ListMarker,
-        #[case] first_content_indent: usize,
-        #[case] current_content_indent: usize,
-        #[case] expected: Result<ListEndReason, ListContinueReason>,
+        #[case] content_indent: usize,
+        #[case] has_content: bool,
+        #[case] expected: Result<ListItemContinueReason, ListItemEndReason>,
     ) {
-        assert_eq!(try_end(line, &marker, first_content_indent, current_content_indent), expected);
+        assert_eq!(try_continue(line, 0, content_indent, has_content), expected);
     }
 
     // === ListMarker::to_list_type 테스트 ===
@@ -0,0 +1,164 @@
+//! https://help.obsidian.md/advanced-syntax#Math
+//!
+//! 수식 블록 파서
+//!
+//! `$$` 줄부터 `$$`로 끝나는 줄까지를 TeX 원문 그대로 모읍니다 (Fenced Code Block과 같은 방식).
+//! - 여는 줄은 `$$`만 있거나 같은 줄에서 닫혀야 함 (`$$x^2$$` 한 줄도 수식 블록, `$$5 each`는 텍스트)
+//! - 닫는 `$$` 앞의 내용도 수식의 일부
+//! - 여는 줄의 들여쓰기만큼 내용 줄의 들여쓰기를 제거
+//! - 빈 줄이 있어도 이어지며, 닫는 줄이 없으면 컨테이너 끝(또는 문서 끝)까지 수식
+//! - Fenced Code Block처럼 Paragraph를 인터럽트할 수 있음
+
+use super::helpers::{calculate_indent, count_leading_char};
+use crate::node::BlockNode;
+
+/// 수식 블록 구분자
+const FENCE: &str = "$$";
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// 수식 블록 시작 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct MathBlockStart {
+    /// 여는 `$$`의 들여쓰기
+    pub indent: usize,
+}
+
+/// 수식 블록 시작 성공 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathBlockStartReason {
+    /// 여는 줄 (다음 줄부터 내용)
+    Started(MathBlockStart),
+    /// 같은 줄에서 닫힌 수식 (`$$x^2$$`, 내용)
+    Closed(String),
+}
+
+/// 수식 블록 시작 아님 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathBlockNotStartReason {
+    /// 4칸 이상 들여쓰기 (Indented Code Block)
+    CodeBlockIndented,
+    /// `$$`로 시작하지 않음 (`$` 하나 또는 셋 이상 포함)
+    NoFence,
+    /// 닫는 `$$` 뒤에 다른 내용이 있음 (인라인 텍스트)
+    TrailingText,
+    /// 여는 `$$` 뒤에 닫는 `$$` 없이 내용이 있음 (`$$5 each` 같은 텍스트)
+    OpenerText,
+}
+
+// =============================================================================
+// 함수
+// =============================================================================
+
+/// 수식 블록 시작 줄인지 확인
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+pub(crate) fn try_start(line: &str, column: usize) -> Result<MathBlockStartReason, MathBlockNotStartReason> {
+    let indent = calculate_indent(line, column);
+    if indent > 3 {
+        return Err(MathBlockNotStartReason::CodeBlockIndented);
+    }
+    let after_indent = line.trim_start_matches([' ', '\t']);
+    if count_leading_char(after_indent, '$') != FENCE.len() {
+        return Err(MathBlockNotStartReason::NoFence);
+    }
+
+    let rest = after_indent[FENCE.len()..].trim();
+    if let Some(content) = try_end(rest) {
+        return Ok(MathBlockStartReason::Closed(content.trim_start().to_string()));
+    }
+    if rest.contains(FENCE) {
+        return Err(MathBlockNotStartReason::TrailingText);
+    }
+    if !rest.is_empty() {
+        return Err(MathBlockNotStartReason::OpenerText);
+    }
+    Ok(MathBlockStartReason::Started(MathBlockStart { indent }))
+}
+
+/// 닫는 줄이면 닫는 `$$` 앞의 내용 (뒤 공백 제거)
+pub(crate) fn try_end(line: &str) -> Option<&str> {
+    line.trim_end().strip_suffix(FENCE).map(str::trim_end)
+}
+
+pub fn finalize(lines: Vec<String>) -> BlockNode {
+    BlockNode::math_block(&lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{InlineNode, ListItemNode};
+    use crate::parser::parse;
+    use crate::parser::source_map::{LineColumns, line_columns};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("$$", MathBlockStartReason::Started(MathBlockStart { indent: 0 }))]
+    #[case("  $$  ", MathBlockStartReason::Started(MathBlockStart { indent: 2 }))]
+    #[case("$$x^2$$", MathBlockStartReason::Closed("x^2".to_string()))]
+    #[case("$$ x $$  ", MathBlockStartReason::Closed("x".to_string()))]
+    fn test_try_start_ok(#[case] line: &str, #[case] expected: MathBlockStartReason) {
+        assert_eq!(try_start(line, 0), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("    $$", MathBlockNotStartReason::CodeBlockIndented)]
+    #[case("$x$", MathBlockNotStartReason::NoFence)]
+    #[case("$$$", MathBlockNotStartReason::NoFence)]
+    #[case("a $$", MathBlockNotStartReason::NoFence)]
+    #[case("$$x$$ text", MathBlockNotStartReason::TrailingText)]
+    #[case("$$5 each", MathBlockNotStartReason::OpenerText)]
+    #[case("  $$ a + b", MathBlockNotStartReason::OpenerText)]
+    fn test_try_start_err(#[case] line: &str, #[case] expected: MathBlockNotStartReason) {
+        assert_eq!(try_start(line, 0), Err(expected));
+    }
+
+    #[rstest]
+    #[case("$$\n\\frac{a}{b}\n$$", vec![BlockNode::math_block("\\frac{a}{b}")])]
+    #[case(
+        "$$\na\n\n  b $$\nafter",
+        vec![BlockNode::math_block("a\n\n  b"), BlockNode::paragraph(vec![InlineNode::text("after")])]
+    )]
+    #[case("$$x^2$$", vec![BlockNode::math_block("x^2")])]
+    // 내용은 해석하지 않음
+    #[case("$$\n# a * b\n$$", vec![BlockNode::math_block("# a * b")])]
+    // 여는 줄의 들여쓰기만큼 제거
+    #[case("  $$\n    x\n $$", vec![BlockNode::math_block("  x")])]
+    // Paragraph 인터럽트
+    #[case("text\n$$\nx\n$$", vec![BlockNode::paragraph(vec![InlineNode::text("text")]), BlockNode::math_block("x")])]
+    // 닫는 줄이 없으면 문서 끝까지
+    #[case("$$\nx\n\ny", vec![BlockNode::math_block("x\n\ny")])]
+    // 컨테이너 안의 수식
+    #[case("> $$\n> x\n> $$", vec![BlockNode::blockquote(vec![BlockNode::math_block("x")])])]
+    #[case(
+        "- $$\n  x\n  $$",
+        vec![BlockNode::bullet_list(true, vec![ListItemNode::new(vec![BlockNode::math_block("x")])])]
+    )]
+    // 수식 블록 아님
+    #[case("$$x$$ text", vec![BlockNode::paragraph(vec![InlineNode::text("$$x$$ text")])])]
+    // 닫히지 않은 `$$` 뒤에 내용이 있으면 텍스트 (Paragraph 인터럽트도 하지 않음)
+    #[case(
+        "Costs:\n$$5 each\n\n# Next",
+        vec![
+            BlockNode::paragraph(vec![
+                InlineNode::text("Costs:"),
+                InlineNode::soft_break(),
+                InlineNode::text("$$5 each"),
+            ]),
+            BlockNode::heading(1, vec![InlineNode::text("Next")]),
+        ]
+    )]
+    #[case("    $$\n    x", vec![BlockNode::code_block(None, "$$\nx")])]
+    fn test_math_block(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        assert_eq!(parse(input).without_spans().children, expected);
+    }
+
+    #[test]
+    fn test_math_block_span() {
+        let doc = parse("$$\nx\n\ny\n$$\n\nafter");
+        let expected: LineColumns = ((1, 1), (5, 3));
+        assert_eq!(line_columns(doc.children[0].span()), expected);
+    }
+}
@@ -2,14 +2,51 @@
 //!
 //! CommonMark 명세: https://spec.commonmark.org/0.31.2/#paragraphs
 
-use crate::node::{BlockNode, InlineNode, ParagraphNode, TextNode};
+use super::link_reference_definition;
+use super::source_map::{cover, lines_with_offsets, remap_blocks, SourceLine, SourceMap};
+use crate::node::{BlockNode, InlineNode, Span};
 
 /// Paragraph 파싱 (기본 fallback)
 /// 다른 블록 요소가 아닌 경우 항상 Paragraph로 처리
-pub fn parse(trimmed: &str) -> BlockNode {
-    BlockNode::Paragraph(ParagraphNode::new(vec![InlineNode::Text(
-        TextNode::new(trimmed),
-    )]))
+/// 앞부분의 Link Reference Definition은 별도 노드로 분리 (남은 내용이 없으면 Paragraph 없음)
+pub fn parse(lines: &[SourceLine]) -> Vec<BlockNode> {
+    let (mut nodes, raw) = extract_definitions(lines);
+    if let Some(span) = raw_span(&raw) {
+        nodes.push(BlockNode::paragraph(raw).with_span(span));
+    }
+    nodes
+}
+
+/// 앞부분의 Link Reference Definition을 분리하고 나머지를 원문 Text 노드로 반환
+/// 마지막 줄 끝 공백은 제거 (Example 645), 중간 줄 끝 공백은 hard line break 판단을 위해 보존
+pub(crate) fn extract_definitions(lines: &[SourceLine]) -> (Vec<BlockNode>, Vec<InlineNode>) {
+    let (text, map) = SourceMap::join(lines.iter().map(|line| (line.text.as_str(), line.offset)));
+    let text = text.trim_end_matches([' ', '\t']);
+    let (mut definitions, rest) = link_reference_definition::extract(text);
+    let to_source = |offset| map.to_source(offset);
+    remap_blocks(&mut definitions, &to_source);
+
+    let rest_start = text.len() - rest.len();
+    let rest_lines: Vec<SourceLine> = lines_with_offsets(rest)
+        .map(|(line, offset)| SourceLine::new(line, to_source(rest_start + offset)))
+        .collect();
+    (definitions, raw_text(&rest_lines))
+}
+
+/// 원문 줄마다 Text 노드 하나 (인라인 파싱 단계에서 다시 이어 붙여 파싱)
+pub(crate) fn raw_text(lines: &[SourceLine]) -> Vec<InlineNode> {
+    lines
+        .iter()
+        .map(|line| {
+            let span = Span::from_offsets(line.offset, line.offset + line.text.len());
+            InlineNode::text(&line.text).with_span(span)
+        })
+        .collect()
+}
+
+/// 원문 Text 노드들이 차지하는 범위 (내용이 없으면 None)
+pub(crate) fn raw_span(raw: &[InlineNode]) -> Option<Span> {
+    Some(cover(raw.first()?.span(), raw.last()?.span()))
 }
 
 #[cfg(test)]
@@ -22,15 +59,15 @@ mod tests {
     // Example 219: 빈 줄로 분리된 두 Paragraph
     #[case("aaa\n\nbbb", vec![BlockNode::paragraph(vec![InlineNode::text("aaa")]), BlockNode::paragraph(vec![InlineNode::text("bbb")])])]
     // Example 220: 여러 줄 Paragraph (soft line break)
-    #[case("aaa\nbbb\n\nccc\nddd", vec![BlockNode::paragraph(vec![InlineNode::text("aaa\nbbb")]), BlockNode::paragraph(vec![InlineNode::text("ccc\nddd")])])]
+    #[case("aaa\nbbb\n\nccc\nddd", vec![BlockNode::paragraph(vec![InlineNode::text("aaa"), InlineNode::soft_break(), InlineNode::text("bbb")]), BlockNode::paragraph(vec![InlineNode::text("ccc"), InlineNode::soft_break(), InlineNode::text("ddd")])])]
     // Example 221: 여러 빈 줄로 분리
     #[case("aaa\n\n\nbbb", vec![BlockNode::paragraph(vec![InlineNode::text("aaa")]), BlockNode::paragraph(vec![InlineNode::text("bbb")])])]
     // Example 222: 선행 공백 제거
-    #[case("  aaa\n bbb", vec![BlockNode::paragraph(vec![InlineNode::text("aaa\nbbb")])])]
+    #[case("  aaa\n bbb", vec![BlockNode::paragraph(vec![InlineNode::text("aaa"), InlineNode::soft_break(), InlineNode::text("bbb")])])]
     // Example 223: 들여쓰기된 continuation lines
-    #[case("aaa\n         bbb\n                                       ccc", vec![BlockNode::paragraph(vec![InlineNode::text("aaa\nbbb\nccc")])])]
+    #[case("aaa\n         bbb\n                                       ccc", vec![BlockNode::paragraph(vec![InlineNode::text("aaa"), InlineNode::soft_break(), InlineNode::text("bbb"), InlineNode::soft_break(), InlineNode::text("ccc")])])]
     // Example 224: 3칸 들여쓰기 허용
-    #[case("   aaa\nbbb", vec![BlockNode::paragraph(vec![InlineNode::text("aaa\nbbb")])])]
+    #[case("   aaa\nbbb", vec![BlockNode::paragraph(vec![InlineNode::text("aaa"), InlineNode::soft_break(), InlineNode::text("bbb")])])]
     // Example 225: 4칸 들여쓰기 → code block + paragraph
     #[case("    aaa\nbbb", vec![BlockNode::code_block(None, "aaa"), BlockNode::paragraph(vec![InlineNode::text("bbb")])])]
     // 추가 케이스
@@ -38,7 +75,7 @@ mod tests {
     #[case("\n\nparagraph", vec![BlockNode::paragraph(vec![InlineNode::text("paragraph")])])]
     #[case("paragraph\n\n", vec![BlockNode::paragraph(vec![InlineNode::text("paragraph")])])]
     fn test_paragraph(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
-        let doc = parse(input);
+        let doc = parse(input).without_spans();
         assert_eq!(doc.children, expected);
     }
 }
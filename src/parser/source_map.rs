@@ -0,0 +1,233 @@
+//! 원문 위치 추적
+//!
+//! 블록 파서는 원문 줄을 그대로 스캔하지만, Paragraph와 인라인 파서는 줄들을 이어 붙여 파싱하므로
+//! 하위 파서가 만든 노드의 위치는 이어 붙인 텍스트 기준입니다.
+//! 이 모듈은 그 위치를 원문 기준으로 되돌리는 도구를 제공합니다.
+//!
+//! ## 단계
+//! 1. 각 파서는 자신이 받은 텍스트 기준 byte offset으로 `Span`을 채움
+//! 2. 한 줄 결과는 `shift`로, 줄을 이어 붙인 결과는 `SourceMap`으로 원문 기준으로 변환
+//! 3. 문서 전체 파싱이 끝나면 `locate`가 offset으로 줄/열을 계산
+
+use crate::node::visit::{visit_block, visit_document, visit_inline};
+use crate::node::{BlockNode, DocumentNode, InlineNode, Position, Span};
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// 원문 위치가 붙은 줄 (컨테이너 마커나 들여쓰기를 뗀 내용)
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SourceLine {
+    pub text: String,
+    /// 내용 첫 글자의 offset
+    pub offset: usize,
+}
+
+impl SourceLine {
+    pub fn new(text: &str, offset: usize) -> Self {
+        Self {
+            text: text.to_string(),
+            offset,
+        }
+    }
+}
+
+/// 이어 붙인 텍스트의 offset → 바깥 텍스트 offset 대응표
+/// 줄마다 (이어 붙인 텍스트에서의 시작, 바깥 텍스트에서의 시작)을 기록
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SourceMap {
+    segments: Vec<(usize, usize)>,
+}
+
+impl SourceMap {
+    /// 줄들을 `\n`으로 이어 붙이며 대응표 생성
+    pub fn join<'a>(lines: impl IntoIterator<Item = (&'a str, usize)>) -> (String, SourceMap) {
+        Self::join_with(lines, "\n")
+    }
+
+    /// 조각들을 구분자 없이 이어 붙이며 대응표 생성
+    pub fn concat<'a>(pieces: impl IntoIterator<Item = (&'a str, usize)>) -> (String, SourceMap) {
+        Self::join_with(pieces, "")
+    }
+
+    fn join_with<'a>(lines: impl IntoIterator<Item = (&'a str, usize)>, separator: &str) -> (String, SourceMap) {
+        let mut text = String::new();
+        let mut map = SourceMap::default();
+        for (i, (line, offset)) in lines.into_iter().enumerate() {
+            if i > 0 {
+                text.push_str(separator);
+            }
+            map.segments.push((text.len(), offset));
+            text.push_str(line);
+        }
+        (text, map)
+    }
+
+    /// 이어 붙인 텍스트의 offset을 바깥 텍스트 offset으로 변환
+    pub fn to_source(&self, offset: usize) -> usize {
+        let index = self.segments.partition_point(|&(start, _)| start <= offset);
+        match index.checked_sub(1).map(|i| self.segments[i]) {
+            Some((start, source)) => source + offset - start,
+            None => offset,
+        }
+    }
+}
+
+// =============================================================================
+// 위치 계산
+// =============================================================================
+
+/// 텍스트에서 앞뒤 공백을 뺀 범위 (빈 텍스트면 시작 위치의 빈 범위)
+pub(crate) fn trimmed_span(text: &str) -> Span {
+    line_span(text, 0)
+}
+
+/// offset에서 시작하는 줄의 앞뒤 공백을 뺀 범위
+pub(crate) fn line_span(line: &str, offset: usize) -> Span {
+    let start = line.len() - line.trim_start().len();
+    let end = line.trim_end().len().max(start);
+    Span::from_offsets(offset + start, offset + end)
+}
+
+/// 줄과 줄 시작 offset (`str::lines`와 같은 규칙으로 줄 끝 제거)
+pub(crate) fn lines_with_offsets(text: &str) -> impl Iterator<Item = (&str, usize)> {
+    text.split_inclusive('\n').scan(0, |offset, raw| {
+        let start = *offset;
+        *offset += raw.len();
+        let line = match raw.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => raw,
+        };
+        Some((line, start))
+    })
+}
+
+/// 두 범위를 모두 덮는 범위
+pub(crate) fn cover(first: Span, last: Span) -> Span {
+    Span::from_offsets(first.start.offset, last.end.offset)
+}
+
+/// 범위 끝을 늘린 범위
+pub(crate) fn extend_to(span: Span, end: usize) -> Span {
+    Span::from_offsets(span.start.offset, end)
+}
+
+// =============================================================================
+// 위치 변환
+// =============================================================================
+
+/// 블록 트리 전체(인라인 포함)의 offset 변환
+pub(crate) fn remap_blocks(blocks: &mut [BlockNode], map: &impl Fn(usize) -> usize) {
+    for block in blocks {
+        remap_block(block, map);
+    }
+}
+
+/// 블록 하나(하위 노드 포함)의 offset 변환
+pub(crate) fn remap_block(block: &mut BlockNode, map: &impl Fn(usize) -> usize) {
+    visit_block(block, &mut |span| remap_span(span, map));
+}
+
+/// 인라인 노드들(하위 노드 포함)의 offset 변환
+pub(crate) fn remap_inlines(nodes: &mut [InlineNode], map: &impl Fn(usize) -> usize) {
+    for node in nodes {
+        visit_inline(node, &mut |span| remap_span(span, map));
+    }
+}
+
+/// 블록 하나의 offset을 base만큼 이동 (줄 단위 파서 결과를 바깥 기준으로)
+pub(crate) fn shift(mut block: BlockNode, base: usize) -> BlockNode {
+    remap_block(&mut block, &|offset| base + offset);
+    block
+}
+
+fn remap_span(span: &mut Span, map: &impl Fn(usize) -> usize) {
+    *span = Span::from_offsets(map(span.start.offset), map(span.end.offset));
+}
+
+/// 문서 전체의 줄/열 계산
+/// span의 offset들을 정렬해 원문을 한 번만 훑음 (offset마다 줄 시작부터 글자를 세지 않음)
+pub(crate) fn locate(doc: &mut DocumentNode, input: &str) {
+    let mut offsets = Vec::new();
+    visit_document(doc, &mut |span| offsets.extend([span.start.offset, span.end.offset]));
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    let (mut line, mut column, mut cursor) = (1, 1, 0);
+    let positions: Vec<Position> = offsets
+        .iter()
+        .map(|&offset| {
+            for c in input[cursor..offset].chars() {
+                match c {
+                    '\n' => (line, column) = (line + 1, 1),
+                    _ => column += 1,
+                }
+            }
+            cursor = offset;
+            Position::new(offset, line, column)
+        })
+        .collect();
+    let position = |offset: usize| positions[offsets.partition_point(|&other| other < offset)];
+    visit_document(doc, &mut |span| *span = Span::new(position(span.start.offset), position(span.end.offset)));
+}
+
+/// 범위의 ((시작 줄, 열), (끝 줄, 열))
+#[cfg(test)]
+pub(crate) type LineColumns = ((usize, usize), (usize, usize));
+
+#[cfg(test)]
+pub(crate) fn line_columns(span: Span) -> LineColumns {
+    ((span.start.line, span.start.column), (span.end.line, span.end.column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    // 첫 줄 안
+    #[case(0, 10)]
+    #[case(2, 12)]
+    // 첫 줄 끝 (줄바꿈 자리)
+    #[case(3, 13)]
+    // 둘째 줄
+    #[case(4, 22)]
+    #[case(6, 24)]
+    fn test_to_source(#[case] offset: usize, #[case] expected: usize) {
+        let (text, map) = SourceMap::join([("foo", 10), ("bar", 22)]);
+        assert_eq!(text, "foo\nbar");
+        assert_eq!(map.to_source(offset), expected);
+    }
+
+    #[rstest]
+    // 열은 문자 단위, offset은 byte 단위
+    #[case("# 한글", (1, 1), (1, 5), 0, 8)]
+    // CRLF의 \r은 줄 내용에 포함되지 않음
+    #[case("a\r\n\r\n# b\r\n", (3, 1), (3, 4), 5, 8)]
+    fn test_locate(
+        #[case] input: &str,
+        #[case] start: (usize, usize),
+        #[case] end: (usize, usize),
+        #[case] start_offset: usize,
+        #[case] end_offset: usize,
+    ) {
+        let doc = crate::parser::parse(input);
+        let span = doc.children.last().unwrap().span();
+        assert_eq!(line_columns(span), (start, end));
+        assert_eq!((span.start.offset, span.end.offset), (start_offset, end_offset));
+    }
+
+    #[test]
+    fn test_locate_long_line() {
+        // 긴 줄의 노드마다 줄 시작부터 글자를 다시 세지 않음 (이전에는 노드 수 x 줄 길이에 비례)
+        let input = "#가 ".repeat(40000);
+        let doc = crate::parser::parse(&input);
+        let BlockNode::Paragraph(node) = &doc.children[0] else {
+            panic!("Paragraph여야 함");
+        };
+        let last = node.children.last().unwrap().span();
+        assert_eq!(line_columns(last), ((1, 119998), (1, 120000)));
+    }
+}
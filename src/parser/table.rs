@@ -0,0 +1,341 @@
+//! https://github.github.com/gfm/#tables-extension-
+//!
+//! Table 파서 (GFM)
+//!
+//! Paragraph의 마지막 줄이 헤더 행, 다음 줄이 구분 행이면 Table이 시작됩니다.
+//! 이후 빈 줄이나 다른 블록이 시작될 때까지의 줄이 본문 행입니다.
+//! 셀 내용은 원문 Text 노드로 저장해 두고, 인라인 파싱 단계에서 `\|`를 `|`로 바꿔 파싱합니다.
+
+use super::source_map::SourceLine;
+use crate::node::{BlockNode, InlineNode, Span, TableAlignment, TableCellNode, TableNode, TableRowNode};
+
+// =============================================================================
+// 타입 정의
+// =============================================================================
+
+/// Table 시작 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableStart {
+    /// 열마다 정렬 (열 수는 헤더 행의 셀 수)
+    pub alignments: Vec<TableAlignment>,
+    /// 헤더 행 (셀 내용은 원문 Text 노드)
+    pub header: TableRowNode,
+}
+
+/// Table 시작 성공 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableStartReason {
+    /// 헤더 행과 구분 행의 셀 수가 같음
+    Started(TableStart),
+}
+
+/// Table 시작 아님 사유
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableNotStartReason {
+    /// 구분 행이 4칸 이상 들여쓰기
+    CodeBlockIndented,
+    /// 구분 행이 아님 (셀마다 `:?-+:?` 형태여야 함)
+    NotDelimiterRow,
+    /// 헤더 행과 구분 행의 셀 수가 다름
+    CellCountMismatch,
+}
+
+// =============================================================================
+// 함수
+// =============================================================================
+
+/// 헤더 행과 구분 행으로 Table 시작 확인
+/// indent: 구분 행의 들여쓰기 (칸)
+pub(crate) fn try_start(
+    header: &SourceLine,
+    delimiter: &str,
+    indent: usize,
+) -> Result<TableStartReason, TableNotStartReason> {
+    if indent > 3 {
+        return Err(TableNotStartReason::CodeBlockIndented);
+    }
+
+    let alignments = split_cells(delimiter)
+        .into_iter()
+        .map(|(cell, _)| parse_alignment(cell))
+        .collect::<Option<Vec<_>>>()
+        .filter(|alignments| !alignments.is_empty())
+        .ok_or(TableNotStartReason::NotDelimiterRow)?;
+
+    let header = parse_cells(&header.text, header.offset);
+    if header.cells.len() != alignments.len() {
+        return Err(TableNotStartReason::CellCountMismatch);
+    }
+
+    Ok(TableStartReason::Started(TableStart { alignments, header }))
+}
+
+/// 본문 행 파싱 (offset: 행 시작의 원문 위치)
+/// 셀이 columns개보다 적으면 빈 셀을 채우고, 많으면 나머지는 버림
+pub(crate) fn parse_row(row: &str, offset: usize, columns: usize) -> TableRowNode {
+    let mut node = parse_cells(row, offset);
+    let end = Span::from_offsets(node.span.end.offset, node.span.end.offset);
+    node.cells.truncate(columns);
+    node.cells.resize(columns, TableCellNode { span: end, ..TableCellNode::new(Vec::new()) });
+    node
+}
+
+pub fn finalize(start: TableStart, rows: Vec<TableRowNode>) -> BlockNode {
+    BlockNode::Table(TableNode::new(start.alignments, start.header, rows))
+}
+
+/// 셀 원문을 `\|` 앞의 백슬래시를 뺀 조각들로 분리 (조각마다 셀 안에서의 시작 byte)
+/// code span 안의 `\|`도 `|`가 됨 (GFM Example 200)
+pub(crate) fn unescape_pipes(cell: &str) -> Vec<(&str, usize)> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for (i, _) in cell.match_indices("\\|") {
+        pieces.push((&cell[start..i], start));
+        start = i + 1;
+    }
+    pieces.push((&cell[start..], start));
+    pieces
+}
+
+/// 행의 셀을 모두 파싱 (셀 내용은 원문 Text 노드)
+fn parse_cells(row: &str, offset: usize) -> TableRowNode {
+    let cells = split_cells(row)
+        .into_iter()
+        .map(|(content, start)| {
+            let span = Span::from_offsets(offset + start, offset + start + content.len());
+            let children = match content.is_empty() {
+                true => Vec::new(),
+                false => vec![InlineNode::text(content).with_span(span)],
+            };
+            TableCellNode { span, ..TableCellNode::new(children) }
+        })
+        .collect();
+    let start = row.len() - row.trim_start().len();
+    let end = row.trim_end().len().max(start);
+    TableRowNode {
+        span: Span::from_offsets(offset + start, offset + end),
+        ..TableRowNode::new(cells)
+    }
+}
+
+/// 행을 셀로 분리 (앞뒤 `|`는 테두리, `\|`는 셀 구분이 아님)
+/// 반환: 셀마다 (앞뒤 공백을 뺀 내용, 행 안에서의 시작 byte)
+fn split_cells(row: &str) -> Vec<(&str, usize)> {
+    let body_start = row.len() - row.trim_start().len();
+    let body = row.trim();
+
+    let mut pipes = Vec::new();
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '|' => pipes.push(i),
+            _ => {}
+        }
+    }
+
+    let mut bounds = Vec::new();
+    let mut start = 0;
+    for &pipe in pipes.iter().chain(std::iter::once(&body.len())) {
+        bounds.push((start, pipe));
+        start = pipe + 1;
+    }
+    if body.starts_with('|') {
+        bounds.remove(0);
+    }
+    if pipes.last().is_some_and(|&pipe| pipe + 1 == body.len()) {
+        bounds.pop();
+    }
+
+    bounds
+        .into_iter()
+        .map(|(start, end)| {
+            let cell = &body[start..end];
+            let content = cell.trim();
+            let leading = cell.len() - cell.trim_start().len();
+            (content, body_start + start + leading)
+        })
+        .collect()
+}
+
+/// 구분 행의 셀 하나를 정렬로 변환 (`:?-+:?`가 아니면 None)
+fn parse_alignment(cell: &str) -> Option<TableAlignment> {
+    let left = cell.starts_with(':');
+    let right = cell.len() > 1 && cell.ends_with(':');
+    let dashes = &cell[usize::from(left)..cell.len() - usize::from(right)];
+    if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+        return None;
+    }
+    Some(match (left, right) {
+        (true, true) => TableAlignment::Center,
+        (true, false) => TableAlignment::Left,
+        (false, true) => TableAlignment::Right,
+        (false, false) => TableAlignment::None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::TableAlignment::{Center, Left, None as NoAlign, Right};
+    use crate::parser::parse;
+    use crate::parser::source_map::{line_columns, LineColumns};
+    use rstest::rstest;
+
+    fn row(cells: &[&str]) -> TableRowNode {
+        TableRowNode::new(
+            cells
+                .iter()
+                .map(|cell| match cell.is_empty() {
+                    true => TableCellNode::new(vec![]),
+                    false => TableCellNode::new(vec![InlineNode::text(cell)]),
+                })
+                .collect(),
+        )
+    }
+
+    fn table(alignments: Vec<TableAlignment>, header: &[&str], rows: &[&[&str]]) -> BlockNode {
+        BlockNode::table(alignments, row(header), rows.iter().map(|cells| row(cells)).collect())
+    }
+
+    #[rstest]
+    #[case("| a | b |", vec![("a", 2), ("b", 6)])]
+    #[case("a | b", vec![("a", 0), ("b", 4)])]
+    #[case("| a |", vec![("a", 2)])]
+    #[case("|a|", vec![("a", 1)])]
+    #[case("| |", vec![("", 2)])]
+    #[case("|", vec![])]
+    // `\|`는 셀 구분이 아님
+    #[case("| a \\| b | c |", vec![("a \\| b", 2), ("c", 11)])]
+    #[case("a \\\\| b", vec![("a \\\\", 0), ("b", 6)])]
+    // 끝의 `\|`는 테두리가 아님
+    #[case("a | b \\|", vec![("a", 0), ("b \\|", 4)])]
+    fn test_split_cells(#[case] input: &str, #[case] expected: Vec<(&str, usize)>) {
+        assert_eq!(split_cells(input), expected);
+    }
+
+    #[rstest]
+    #[case("---", Some(NoAlign))]
+    #[case("-", Some(NoAlign))]
+    #[case(":---", Some(Left))]
+    #[case(":-:", Some(Center))]
+    #[case("---:", Some(Right))]
+    #[case(":", None)]
+    #[case("::", None)]
+    #[case("- -", None)]
+    #[case("-=-", None)]
+    #[case("", None)]
+    fn test_parse_alignment(#[case] input: &str, #[case] expected: Option<TableAlignment>) {
+        assert_eq!(parse_alignment(input), expected);
+    }
+
+    #[rstest]
+    #[case("| a | b |", "|---|:-:|", 0, Ok(vec![NoAlign, Center]))]
+    #[case("a", "-:", 0, Ok(vec![Right]))]
+    #[case("| a | b |", "   | --- | --- |", 3, Ok(vec![NoAlign, NoAlign]))]
+    #[case("| a | b |", "    | --- | --- |", 4, Err(TableNotStartReason::CodeBlockIndented))]
+    #[case("| a | b |", "| --- | - - |", 0, Err(TableNotStartReason::NotDelimiterRow))]
+    #[case("| a | b |", "|", 0, Err(TableNotStartReason::NotDelimiterRow))]
+    #[case("| a | b |", "| --- |", 0, Err(TableNotStartReason::CellCountMismatch))]
+    fn test_try_start(
+        #[case] header: &str,
+        #[case] delimiter: &str,
+        #[case] indent: usize,
+        #[case] expected: Result<Vec<TableAlignment>, TableNotStartReason>,
+    ) {
+        let result = try_start(&SourceLine::new(header, 0), delimiter, indent)
+            .map(|TableStartReason::Started(start)| start.alignments);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    // GFM Example 198: 기본 표
+    #[case(
+        "| foo | bar |\n| --- | --- |\n| baz | bim |",
+        vec![table(vec![NoAlign, NoAlign], &["foo", "bar"], &[&["baz", "bim"]])]
+    )]
+    // GFM Example 199: 정렬, 셀 너비는 달라도 됨
+    #[case(
+        "| abc | defghi |\n:-: | -----------:\nbar | baz",
+        vec![table(vec![Center, Right], &["abc", "defghi"], &[&["bar", "baz"]])]
+    )]
+    // GFM Example 201: 다른 블록이 시작되면 표가 끝남
+    #[case("| abc | def |\n| --- | --- |\n| bar | baz |\n> bar", vec![
+        table(vec![NoAlign, NoAlign], &["abc", "def"], &[&["bar", "baz"]]),
+        BlockNode::blockquote(vec![BlockNode::paragraph(vec![InlineNode::text("bar")])]),
+    ])]
+    // GFM Example 202: 빈 줄까지 본문 행 (셀이 모자라면 빈 셀)
+    #[case("| abc | def |\n| --- | --- |\n| bar | baz |\nbar\n\nbar", vec![
+        table(vec![NoAlign, NoAlign], &["abc", "def"], &[&["bar", "baz"], &["bar", ""]]),
+        BlockNode::paragraph(vec![InlineNode::text("bar")]),
+    ])]
+    // GFM Example 203: 헤더 행과 구분 행의 셀 수가 다르면 표가 아님
+    #[case("| abc | def |\n| --- |\n| bar |", vec![BlockNode::paragraph(vec![
+        InlineNode::text("| abc | def |"),
+        InlineNode::soft_break(),
+        InlineNode::text("| --- |"),
+        InlineNode::soft_break(),
+        InlineNode::text("| bar |"),
+    ])])]
+    // GFM Example 204: 셀이 많으면 나머지는 버림
+    #[case("| abc | def |\n| --- | --- |\n| bar |\n| bar | baz | boo |", vec![
+        table(vec![NoAlign, NoAlign], &["abc", "def"], &[&["bar", ""], &["bar", "baz"]]),
+    ])]
+    // GFM Example 205: 본문 행 없음
+    #[case("| abc | def |\n| --- | --- |", vec![table(vec![NoAlign, NoAlign], &["abc", "def"], &[])])]
+    // Paragraph 인터럽트: 마지막 줄만 헤더 행
+    #[case("intro\n| a | b |\n|---|---|\n| 1 | 2 |", vec![
+        BlockNode::paragraph(vec![InlineNode::text("intro")]),
+        table(vec![NoAlign, NoAlign], &["a", "b"], &[&["1", "2"]]),
+    ])]
+    // Setext 밑줄이 우선
+    #[case("| a |\n---", vec![BlockNode::heading(2, vec![InlineNode::text("| a |")])])]
+    // 컨테이너 안의 표
+    #[case("> | a |\n> | - |\n> | 1 |", vec![BlockNode::blockquote(vec![table(vec![NoAlign], &["a"], &[&["1"]])])])]
+    fn test_table(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
+        assert_eq!(parse(input).without_spans().children, expected);
+    }
+
+    // GFM Example 200: `\|`는 code span과 강조 안에서도 `|`
+    #[test]
+    fn test_escaped_pipe() {
+        let doc = parse("| f\\|oo  |\n| ------ |\n| b `\\|` az |\n| b **\\|** im |").without_spans();
+        let expected = BlockNode::table(
+            vec![NoAlign],
+            row(&["f|oo"]),
+            vec![
+                TableRowNode::new(vec![TableCellNode::new(vec![
+                    InlineNode::text("b "),
+                    InlineNode::code_span("|"),
+                    InlineNode::text(" az"),
+                ])]),
+                TableRowNode::new(vec![TableCellNode::new(vec![
+                    InlineNode::text("b "),
+                    InlineNode::strong(vec![InlineNode::text("|")]),
+                    InlineNode::text(" im"),
+                ])]),
+            ],
+        );
+        assert_eq!(doc.children, vec![expected]);
+    }
+
+    #[rstest]
+    // 표: 헤더 행부터 마지막 행까지
+    #[case("| a | b |\n|---|---|\n| 1 | 2 |", |doc: &BlockNode| doc.span(), ((1, 1), (3, 10)))]
+    // 셀: 앞뒤 공백을 뺀 내용
+    #[case("| a | b |\n|---|---|\n| 1 | 2 |", |doc: &BlockNode| match doc {
+        BlockNode::Table(node) => node.rows[0].cells[1].span,
+        _ => unreachable!(),
+    }, ((3, 7), (3, 8)))]
+    // `\|` 뒤 인라인 위치도 원문 기준
+    #[case("| a \\| *b* |\n|---|", |doc: &BlockNode| match doc {
+        BlockNode::Table(node) => node.header.cells[0].children[1].span(),
+        _ => unreachable!(),
+    }, ((1, 8), (1, 11)))]
+    fn test_table_span(#[case] input: &str, #[case] select: fn(&BlockNode) -> Span, #[case] expected: LineColumns) {
+        let doc = parse(input);
+        assert_eq!(line_columns(select(&doc.children[0])), expected);
+    }
+}
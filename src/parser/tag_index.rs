@@ -0,0 +1,98 @@
+//! 문서의 태그 목록
+//!
+//! 본문의 태그(`InlineNode::Tag`)를 블록 순서대로 모읍니다.
+//! frontmatter의 `tags`는 본문 태그보다 앞에 둡니다 (기본으로 켜지는 `properties` feature 필요).
+//! Obsidian처럼 대소문자를 무시하고 중복을 없애며, 처음 나온 표기를 남깁니다.
+//! 트랜스클루전으로 불러온 내용은 다른 노트의 태그이므로 포함하지 않습니다.
+
+use crate::node::{BlockNode, DocumentNode, InlineNode};
+
+/// 문서의 모든 태그 (`#` 제외, frontmatter 태그 먼저, 중복 제거)
+pub fn collect_tags(doc: &DocumentNode) -> Vec<String> {
+    let mut tags = Vec::new();
+    #[cfg(feature = "properties")]
+    if let Some(frontmatter) = &doc.frontmatter {
+        frontmatter.properties.tags().into_iter().for_each(|tag| push_tag(&mut tags, tag));
+    }
+    collect_blocks(&doc.children, &mut tags);
+    tags
+}
+
+fn push_tag(tags: &mut Vec<String>, tag: String) {
+    let lower = tag.to_lowercase();
+    if !tags.iter().any(|existing| existing.to_lowercase() == lower) {
+        tags.push(tag);
+    }
+}
+
+fn collect_blocks(blocks: &[BlockNode], tags: &mut Vec<String>) {
+    for block in blocks {
+        match block {
+            BlockNode::Paragraph(node) => collect_inlines(&node.children, tags),
+            BlockNode::Heading(node) => collect_inlines(&node.children, tags),
+            BlockNode::Blockquote(node) => collect_blocks(&node.children, tags),
+            BlockNode::Callout(node) => {
+                collect_inlines(&node.title, tags);
+                collect_blocks(&node.children, tags);
+            }
+            BlockNode::List(node) => {
+                for item in &node.children {
+                    collect_blocks(&item.children, tags);
+                }
+            }
+            BlockNode::ListItem(node) => collect_blocks(&node.children, tags),
+            BlockNode::FootnoteDefinition(node) => collect_blocks(&node.children, tags),
+            BlockNode::Table(node) => {
+                for row in std::iter::once(&node.header).chain(&node.rows) {
+                    for cell in &row.cells {
+                        collect_inlines(&cell.children, tags);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_inlines(inlines: &[InlineNode], tags: &mut Vec<String>) {
+    for inline in inlines {
+        match inline {
+            InlineNode::Tag(node) => push_tag(tags, node.name.clone()),
+            InlineNode::Emphasis(node) => collect_inlines(&node.children, tags),
+            InlineNode::Strong(node) => collect_inlines(&node.children, tags),
+            InlineNode::Strikethrough(node) => collect_inlines(&node.children, tags),
+            InlineNode::Highlight(node) => collect_inlines(&node.children, tags),
+            InlineNode::FootnoteReference(node) => {
+                if let Some(children) = &node.inline {
+                    collect_inlines(children, tags);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("#a text #b/c\n\n- item #a\n- *#d*", &["a", "b/c", "d"])]
+    #[case("# Title #h\n\n> [!note] #callout\n> #quote", &["h", "callout", "quote"])]
+    #[case("| #x |\n| --- |\n| #y |", &["x", "y"])]
+    #[case("#Tag and #tag", &["Tag"])]
+    #[case("text^[see #inline] and [^1]\n\n[^1]: #note", &["inline", "note"])]
+    #[case("`#code` #1984", &[])]
+    fn test_collect_tags(#[case] input: &str, #[case] expected: &[&str]) {
+        assert_eq!(collect_tags(&parse(input)), expected);
+    }
+
+    #[cfg(feature = "properties")]
+    #[test]
+    fn test_collect_tags_frontmatter() {
+        let doc = parse("---\ntags: [project, '#Idea']\n---\n#idea #todo");
+        assert_eq!(collect_tags(&doc), ["project", "Idea", "todo"]);
+    }
+}
@@ -1,7 +1,8 @@
 //! https://spec.commonmark.org/0.31.2/#thematic-breaks
 
 use super::helpers::calculate_indent;
-use crate::node::{BlockNode, ThematicBreakNode};
+use super::source_map::trimmed_span;
+use crate::node::BlockNode;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ThematicBreakErr {
@@ -17,8 +18,10 @@ pub enum ThematicBreakErr {
     MixedCharacters,
 }
 
-pub fn parse(line: &str) -> Result<BlockNode, ThematicBreakErr> {
-    let indent = calculate_indent(line);
+/// Thematic Break 줄 파싱
+/// column: 줄이 시작하는 열 (탭 너비 계산용)
+pub fn parse(line: &str, column: usize) -> Result<BlockNode, ThematicBreakErr> {
+    let indent = calculate_indent(line, column);
     let trimmed = line.trim();
 
     // 들여쓰기 3칸 초과면 코드 블록
@@ -52,7 +55,7 @@ pub fn parse(line: &str) -> Result<BlockNode, ThematicBreakErr> {
         return Err(ThematicBreakErr::InsufficientMarkers);
     }
 
-    Ok(BlockNode::ThematicBreak(ThematicBreakNode))
+    Ok(BlockNode::thematic_break().with_span(trimmed_span(line)))
 }
 
 #[cfg(test)]
@@ -80,7 +83,7 @@ mod tests {
     // Example 48: 4칸 들여쓰기는 코드 블록
     #[case("    ***", vec![BlockNode::code_block(None, "***")])]
     // Example 49: Paragraph 내 4칸 들여쓰기는 continuation
-    #[case("Foo\n    ***", vec![BlockNode::paragraph(vec![InlineNode::text("Foo\n***")])])]
+    #[case("Foo\n    ***", vec![BlockNode::paragraph(vec![InlineNode::text("Foo"), InlineNode::soft_break(), InlineNode::text("***")])])]
     // Example 50: 많은 문자
     #[case("_____________________________________", vec![BlockNode::thematic_break()])]
     // Example 51: 공백 사이
@@ -95,8 +98,8 @@ mod tests {
     #[case("_ _ _ _ a", vec![BlockNode::paragraph(vec![InlineNode::text("_ _ _ _ a")])])]
     #[case("a------", vec![BlockNode::paragraph(vec![InlineNode::text("a------")])])]
     #[case("---a---", vec![BlockNode::paragraph(vec![InlineNode::text("---a---")])])]
-    // Example 56: 혼합 문자는 무효 → Paragraph
-    #[case("*-*", vec![BlockNode::paragraph(vec![InlineNode::text("*-*")])])]
+    // Example 56: 혼합 문자는 무효 → Paragraph (인라인 강조로 파싱)
+    #[case("*-*", vec![BlockNode::paragraph(vec![InlineNode::emphasis(vec![InlineNode::text("-")])])])]
     // 추가 케이스
     #[case("*****", vec![BlockNode::thematic_break()])]
     #[case("----------", vec![BlockNode::thematic_break()])]
@@ -108,7 +111,7 @@ mod tests {
     #[case("***   ", vec![BlockNode::thematic_break()])]
     #[case("***a", vec![BlockNode::paragraph(vec![InlineNode::text("***a")])])]
     fn test_thematic_break(#[case] input: &str, #[case] expected: Vec<BlockNode>) {
-        let doc = parse(input);
+        let doc = parse(input).without_spans();
         assert_eq!(doc.children, expected);
     }
 }
@@ -0,0 +1,292 @@
+//! Obsidian 임베드 트랜스클루전
+//!
+//! 파싱이 끝난 문서에서 노트 임베드(`![[note]]`)를 찾아, loader가 돌려준 노트 원문을 `parse`로 파싱한 뒤
+//! 노트 전체, heading 아래 구역(`#Heading`), 또는 블록 하나(`#^id`)를 `EmbedNode.content`에 채웁니다.
+//! 불러온 노트 안의 임베드도 같은 방식으로 채우며, 이미 불러오는 중인 노트(순환)와
+//! 최대 깊이를 넘는 임베드는 content 없이 남깁니다. 이미지 임베드와 같은 노트 안의 임베드는 건너뜁니다.
+
+use super::footnote::{inline_children, visit_blocks};
+use super::parse;
+use crate::node::{BlockNode, DocumentNode, EmbedNode, InlineNode, ListNode};
+
+/// 기본 최대 깊이 (문서 → 임베드 → 임베드 안의 임베드 …)
+const DEFAULT_MAX_DEPTH: usize = 8;
+
+/// 노트 임베드를 불러온 블록으로 채움 (최대 깊이 8)
+/// loader: 임베드 대상 이름을 받아 노트 원문을 돌려줌 (없으면 None, 그 임베드는 그대로 남음)
+pub fn transclude(doc: DocumentNode, loader: &dyn Fn(&str) -> Option<String>) -> DocumentNode {
+    transclude_with_depth(doc, loader, DEFAULT_MAX_DEPTH)
+}
+
+/// 노트 임베드를 불러온 블록으로 채움 (max_depth: 임베드를 따라 들어갈 최대 깊이)
+pub fn transclude_with_depth(
+    mut doc: DocumentNode,
+    loader: &dyn Fn(&str) -> Option<String>,
+    max_depth: usize,
+) -> DocumentNode {
+    let mut transcluder = Transcluder {
+        loader,
+        max_depth,
+        loading: Vec::new(),
+    };
+    transcluder.blocks(&mut doc.children);
+    doc
+}
+
+/// 트랜스클루전 상태
+struct Transcluder<'a> {
+    loader: &'a dyn Fn(&str) -> Option<String>,
+    max_depth: usize,
+    /// 불러오는 중인 노트 이름 (바깥 → 안쪽, 소문자)
+    loading: Vec<String>,
+}
+
+impl Transcluder<'_> {
+    fn blocks(&mut self, blocks: &mut [BlockNode]) {
+        visit_blocks(blocks, &mut |block| {
+            for inlines in inline_children(block) {
+                self.inlines(inlines);
+            }
+        });
+    }
+
+    fn inlines(&mut self, inlines: &mut [InlineNode]) {
+        for inline in inlines {
+            match inline {
+                InlineNode::Embed(node) => self.embed(node),
+                InlineNode::Emphasis(node) => self.inlines(&mut node.children),
+                InlineNode::Strong(node) => self.inlines(&mut node.children),
+                InlineNode::Strikethrough(node) => self.inlines(&mut node.children),
+                InlineNode::Highlight(node) => self.inlines(&mut node.children),
+                InlineNode::Link(node) => self.inlines(&mut node.children),
+                InlineNode::FootnoteReference(node) => {
+                    if let Some(children) = &mut node.inline {
+                        self.inlines(children);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn embed(&mut self, node: &mut EmbedNode) {
+        let name = node.target.to_lowercase();
+        if node.target.is_empty()
+            || node.is_image()
+            || self.loading.len() >= self.max_depth
+            || self.loading.contains(&name)
+        {
+            return;
+        }
+        let Some(source) = (self.loader)(&node.target) else {
+            return;
+        };
+        let Some(mut content) = select(parse(&source).children, node) else {
+            return;
+        };
+
+        self.loading.push(name);
+        self.blocks(&mut content);
+        self.loading.pop();
+        node.content = Some(content);
+    }
+}
+
+/// 임베드가 가리키는 부분 (노트 전체, heading 구역, 블록 하나), 찾지 못하면 None
+fn select(blocks: Vec<BlockNode>, node: &EmbedNode) -> Option<Vec<BlockNode>> {
+    match (&node.heading, &node.block_ref) {
+        (_, Some(id)) => find_block(&blocks, id).map(|block| vec![block]),
+        (Some(heading), None) => section(blocks, heading),
+        (None, None) => Some(blocks),
+    }
+}
+
+/// heading과 그 아래 블록들 (같거나 높은 레벨의 다음 heading 전까지)
+/// heading 텍스트는 앞뒤 공백과 대소문자를 무시하고 비교
+fn section(blocks: Vec<BlockNode>, heading: &str) -> Option<Vec<BlockNode>> {
+    let heading = heading.to_lowercase();
+    let start = blocks.iter().position(|block| {
+        matches!(block, BlockNode::Heading(node) if plain_text(&node.children).trim().to_lowercase() == heading)
+    })?;
+    let BlockNode::Heading(node) = &blocks[start] else {
+        return None;
+    };
+    let level = node.level;
+    let end = blocks[start + 1..]
+        .iter()
+        .position(|block| matches!(block, BlockNode::Heading(node) if node.level <= level))
+        .map_or(blocks.len(), |offset| start + 1 + offset);
+    Some(blocks.into_iter().take(end).skip(start).collect())
+}
+
+/// block id로 블록 찾기 (컨테이너 안까지)
+/// List Item의 id면 그 아이템 하나만 담은 List
+fn find_block(blocks: &[BlockNode], id: &str) -> Option<BlockNode> {
+    blocks.iter().find_map(|block| {
+        if block_id(block) == Some(id) {
+            return Some(block.clone());
+        }
+        match block {
+            BlockNode::Blockquote(node) => find_block(&node.children, id),
+            BlockNode::Callout(node) => find_block(&node.children, id),
+            BlockNode::List(node) => node.children.iter().find_map(|item| match item.block_id.as_deref() == Some(id) {
+                true => Some(BlockNode::List(ListNode {
+                    children: vec![item.clone()],
+                    ..node.clone()
+                })),
+                false => find_block(&item.children, id),
+            }),
+            BlockNode::ListItem(node) => find_block(&node.children, id),
+            _ => None,
+        }
+    })
+}
+
+/// 파싱 단계에서 블록에 붙인 block id
+fn block_id(block: &BlockNode) -> Option<&str> {
+    match block {
+        BlockNode::Paragraph(node) => node.block_id.as_deref(),
+        BlockNode::Blockquote(node) => node.block_id.as_deref(),
+        BlockNode::Callout(node) => node.block_id.as_deref(),
+        BlockNode::ListItem(node) => node.block_id.as_deref(),
+        BlockNode::Table(node) => node.block_id.as_deref(),
+        _ => None,
+    }
+}
+
+/// heading 비교용 평문
+fn plain_text(inlines: &[InlineNode]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            InlineNode::Text(node) => node.as_str().to_string(),
+            InlineNode::CodeSpan(node) => node.content.clone(),
+            InlineNode::InlineMath(node) => node.content.clone(),
+            InlineNode::Emphasis(node) => plain_text(&node.children),
+            InlineNode::Strong(node) => plain_text(&node.children),
+            InlineNode::Strikethrough(node) => plain_text(&node.children),
+            InlineNode::Highlight(node) => plain_text(&node.children),
+            InlineNode::Link(node) => plain_text(&node.children),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{BlockquoteNode, EmbedNode, ListItemNode, ListType, ParagraphNode};
+    use rstest::rstest;
+
+    /// 노트 이름 → 원문
+    fn loader(name: &str) -> Option<String> {
+        let source = match name {
+            "note" => "# Title\n\nintro\n\n## Usage\n\nuse it\n\n### Detail\n\nmore\n\n## Other\n\nother",
+            "ids" => "first ^one\n\n- item\n\n  second ^two\n- third ^three\n\n> quote\n\n^four",
+            "outer" => "outer ![[inner]]",
+            "inner" => "inner",
+            "a" => "a ![[b]]",
+            "b" => "b ![[a]]",
+            "self" => "self ![[self]]",
+            _ => return None,
+        };
+        Some(source.to_string())
+    }
+
+    /// 첫 Paragraph의 첫 Embed
+    fn first_embed(doc: &DocumentNode) -> &EmbedNode {
+        let Some(BlockNode::Paragraph(node)) = doc.children.first() else {
+            panic!("첫 블록이 Paragraph여야 함");
+        };
+        node.children
+            .iter()
+            .find_map(|inline| match inline {
+                InlineNode::Embed(node) => Some(node),
+                _ => None,
+            })
+            .expect("Embed가 있어야 함")
+    }
+
+    #[rstest]
+    // 노트 전체
+    #[case("![[inner]]", Some(vec![BlockNode::paragraph(vec![InlineNode::text("inner")])]))]
+    // heading 구역: 하위 heading은 포함, 같은 레벨에서 끝
+    #[case(
+        "![[note#usage]]",
+        Some(vec![
+            BlockNode::heading(2, vec![InlineNode::text("Usage")]),
+            BlockNode::paragraph(vec![InlineNode::text("use it")]),
+            BlockNode::heading(3, vec![InlineNode::text("Detail")]),
+            BlockNode::paragraph(vec![InlineNode::text("more")]),
+        ])
+    )]
+    #[case(
+        "![[note#Other]]",
+        Some(vec![
+            BlockNode::heading(2, vec![InlineNode::text("Other")]),
+            BlockNode::paragraph(vec![InlineNode::text("other")]),
+        ])
+    )]
+    // 블록 하나 (리스트 안까지)
+    #[case(
+        "![[ids#^one]]",
+        Some(vec![BlockNode::Paragraph(ParagraphNode::new(vec![InlineNode::text("first")]).with_block_id("one"))])
+    )]
+    #[case(
+        "![[ids#^two]]",
+        Some(vec![BlockNode::Paragraph(ParagraphNode::new(vec![InlineNode::text("second")]).with_block_id("two"))])
+    )]
+    // List Item은 그 아이템만 담은 List, 따로 쓴 id는 앞 블록
+    #[case(
+        "![[ids#^three]]",
+        Some(vec![BlockNode::List(ListNode::new(
+            ListType::Bullet,
+            1,
+            false,
+            vec![ListItemNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("third")])]).with_block_id("three")],
+        ))])
+    )]
+    #[case(
+        "![[ids#^four]]",
+        Some(vec![BlockNode::Blockquote(
+            BlockquoteNode::new(vec![BlockNode::paragraph(vec![InlineNode::text("quote")])]).with_block_id("four"),
+        )])
+    )]
+    // 찾지 못하면 그대로
+    #[case("![[note#Missing]]", None)]
+    #[case("![[ids#^five]]", None)]
+    #[case("![[unknown]]", None)]
+    // 이미지와 같은 노트 안의 임베드는 불러오지 않음
+    #[case("![[inner.png]]", None)]
+    #[case("![[#Usage]]", None)]
+    fn test_transclude(#[case] input: &str, #[case] expected: Option<Vec<BlockNode>>) {
+        let doc = transclude(parse(input), &loader).without_spans();
+        assert_eq!(first_embed(&doc).content, expected);
+    }
+
+    #[test]
+    fn test_transclude_nested() {
+        let doc = transclude(parse("![[outer]]"), &loader).without_spans();
+        let inner = EmbedNode::new("inner").with_content(vec![BlockNode::paragraph(vec![InlineNode::text("inner")])]);
+        let expected = vec![BlockNode::paragraph(vec![InlineNode::text("outer "), InlineNode::Embed(inner)])];
+        assert_eq!(first_embed(&doc).content, Some(expected));
+    }
+
+    #[rstest]
+    // 순환: a → b → a에서 멈춤 (a는 한 번만 펼침)
+    #[case("![[a]]", 8, 2)]
+    #[case("![[self]]", 8, 1)]
+    // 최대 깊이
+    #[case("![[outer]]", 1, 1)]
+    #[case("![[a]]", 0, 0)]
+    fn test_transclude_limit(#[case] input: &str, #[case] max_depth: usize, #[case] expected: usize) {
+        let doc = transclude_with_depth(parse(input), &loader, max_depth);
+        let mut depth = 0;
+        let mut content = first_embed(&doc).content.clone();
+        while let Some(blocks) = content {
+            depth += 1;
+            content = first_embed(&DocumentNode::new(blocks)).content.clone();
+        }
+        assert_eq!(depth, expected);
+    }
+}
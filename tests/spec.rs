@@ -0,0 +1,219 @@
+//! CommonMark 명세 적합성 테스트
+//!
+//! `tests/spec/spec.txt`(0.31.2)의 모든 예제를 `parse` + `render_html`로 변환해 기대 HTML과 비교합니다.
+//!
+//! - 섹션별 통과 수와 전체 통과율을 출력 (`cargo test --test spec -- --nocapture`)
+//! - `tests/spec/known_failures.txt`에 없는 예제가 실패하면 테스트 실패 (회귀)
+//! - 목록에 있는 예제가 통과하면 역시 테스트 실패 (목록에서 지워 통과 수를 고정)
+//! - `SPEC_VERBOSE=1`이면 실패한 예제의 입력, 기대값, 실제 출력을 함께 출력
+//!
+//! 같은 예제로 `render_commonmark` 출력을 다시 파싱하면 같은 트리가 나오는지도 확인합니다
+//! (Blockquote, List Item 안에 넣은 경우 포함, 다시 렌더링해도 출력이 같아야 함).
+
+use std::collections::BTreeSet;
+use std::panic;
+
+use madang_compiler::{parse, render_commonmark, render_html, FormatOptions};
+
+const SPEC: &str = include_str!("spec/spec.txt");
+const KNOWN_FAILURES: &str = include_str!("spec/known_failures.txt");
+
+/// 예제 블록의 펜스 (백틱 32개)
+const EXAMPLE_FENCE: &str = "````````````````````````````````";
+
+/// 명세 예제 하나
+struct Example {
+    number: usize,
+    section: String,
+    markdown: String,
+    html: String,
+}
+
+/// 섹션별 결과
+struct SectionResult {
+    section: String,
+    passed: usize,
+    total: usize,
+}
+
+/// spec.txt에서 예제 추출
+/// 예제 안의 `→`는 탭을 나타냄
+fn extract_examples(spec: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut section = String::new();
+    // None: 예제 밖, Some(false): markdown 부분, Some(true): html 부분
+    let mut in_example: Option<bool> = None;
+    let mut markdown = String::new();
+    let mut html = String::new();
+
+    for line in spec.lines() {
+        match in_example {
+            None if line == format!("{} example", EXAMPLE_FENCE) => {
+                in_example = Some(false);
+                markdown.clear();
+                html.clear();
+            }
+            None => {
+                if let Some(heading) = line.strip_prefix('#') {
+                    section = heading.trim_start_matches('#').trim().to_string();
+                }
+            }
+            Some(_) if line == EXAMPLE_FENCE => {
+                examples.push(Example {
+                    number: examples.len() + 1,
+                    section: section.clone(),
+                    markdown: markdown.replace('→', "\t"),
+                    html: html.replace('→', "\t"),
+                });
+                in_example = None;
+            }
+            Some(false) if line == "." => in_example = Some(true),
+            Some(false) => {
+                markdown.push_str(line);
+                markdown.push('\n');
+            }
+            Some(true) => {
+                html.push_str(line);
+                html.push('\n');
+            }
+        }
+    }
+
+    examples
+}
+
+/// 알려진 실패 목록 파싱 (`#` 뒤는 주석, 번호는 공백으로 구분)
+fn parse_known_failures(text: &str) -> BTreeSet<usize> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .flat_map(str::split_whitespace)
+        .map(|number| number.parse().expect("known_failures.txt에는 예제 번호만 올 수 있습니다"))
+        .collect()
+}
+
+/// 예제 실행 결과 HTML (파서가 panic하면 None)
+fn run_example(example: &Example) -> Option<String> {
+    panic::catch_unwind(|| render_html(&parse(&example.markdown))).ok()
+}
+
+/// 섹션 순서를 유지하며 결과 집계
+fn summarize(examples: &[Example], failed: &BTreeSet<usize>) -> Vec<SectionResult> {
+    let mut results: Vec<SectionResult> = Vec::new();
+    for example in examples {
+        let passed = !failed.contains(&example.number) as usize;
+        match results.last_mut() {
+            Some(result) if result.section == example.section => {
+                result.passed += passed;
+                result.total += 1;
+            }
+            _ => results.push(SectionResult {
+                section: example.section.clone(),
+                passed,
+                total: 1,
+            }),
+        }
+    }
+    results
+}
+
+#[test]
+fn spec_conformance() {
+    let examples = extract_examples(SPEC);
+    let known_failures = parse_known_failures(KNOWN_FAILURES);
+
+    // panic 메시지가 결과 표를 가리지 않도록 실행 중에는 hook 비활성화
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let failures: Vec<(&Example, Option<String>)> = examples
+        .iter()
+        .map(|example| (example, run_example(example)))
+        .filter(|(example, actual)| actual.as_deref() != Some(example.html.as_str()))
+        .collect();
+    panic::set_hook(default_hook);
+
+    if std::env::var_os("SPEC_VERBOSE").is_some() {
+        for (example, actual) in &failures {
+            println!("Example {} ({})", example.number, example.section);
+            println!("  markdown: {:?}", example.markdown);
+            println!("  expected: {:?}", example.html);
+            match actual {
+                Some(actual) => println!("  actual:   {:?}", actual),
+                None => println!("  actual:   (panic)"),
+            }
+        }
+    }
+
+    let failed: BTreeSet<usize> = failures.iter().map(|(example, _)| example.number).collect();
+
+    println!("CommonMark spec 0.31.2");
+    for result in summarize(&examples, &failed) {
+        println!("  {:<45} {:>3}/{:<3}", result.section, result.passed, result.total);
+    }
+    let passed = examples.len() - failed.len();
+    println!(
+        "  {:<45} {:>3}/{:<3} ({:.1}%)",
+        "Total",
+        passed,
+        examples.len(),
+        passed as f64 * 100.0 / examples.len() as f64
+    );
+
+    let regressions: Vec<_> = failed.difference(&known_failures).collect();
+    let fixed: Vec<_> = known_failures.difference(&failed).collect();
+    assert!(regressions.is_empty(), "목록에 없는 예제가 실패했습니다: {:?}", regressions);
+    assert!(fixed.is_empty(), "통과하는 예제를 known_failures.txt에서 지워 주세요: {:?}", fixed);
+}
+
+#[test]
+fn extract_all_examples() {
+    let examples = extract_examples(SPEC);
+    assert_eq!(examples.len(), 652);
+    assert_eq!(examples[0].section, "Tabs");
+    assert_eq!(examples[0].markdown, "\tfoo\tbaz\t\tbim\n");
+    assert_eq!(examples[651].section, "Textual content");
+}
+
+/// 예제를 컨테이너 안에 넣은 입력 (첫 줄 앞에 first, 나머지 줄 앞에 rest)
+fn nested(markdown: &str, first: &str, rest: &str) -> String {
+    markdown
+        .lines()
+        .enumerate()
+        .map(|(i, line)| format!("{}{}\n", if i == 0 { first } else { rest }, line))
+        .collect()
+}
+
+#[test]
+fn spec_round_trip() {
+    let examples = extract_examples(SPEC);
+    let options = FormatOptions::default();
+
+    // 예제 그대로, Blockquote 안, List Item 안
+    let inputs: Vec<(usize, String)> = examples
+        .iter()
+        .flat_map(|example| {
+            [
+                example.markdown.clone(),
+                nested(&example.markdown, "> ", "> "),
+                nested(&example.markdown, "- ", "  "),
+            ]
+            .map(|markdown| (example.number, markdown))
+        })
+        .collect();
+
+    let failures: Vec<_> = inputs
+        .iter()
+        .filter_map(|(number, markdown)| {
+            // 원문 위치는 출력과 다르므로 위치를 지운 트리끼리 비교
+            let doc = parse(markdown).without_spans();
+            let output = render_commonmark(&doc, &options);
+            let reparsed = parse(&output).without_spans();
+            let formatted = render_commonmark(&reparsed, &options);
+            (reparsed != doc || formatted != output).then_some((number, markdown, output))
+        })
+        .collect();
+
+    for (number, markdown, output) in &failures {
+        println!("Example {}\n  markdown: {:?}\n  output:   {:?}", number, markdown, output);
+    }
+    assert!(failures.is_empty(), "{}개 입력이 같은 트리로 다시 파싱되지 않습니다", failures.len());
+}